@@ -2,9 +2,11 @@ use error_chain::bail;
 
 use crate::util::{build_signed_request, is_start_time_valid};
 use crate::model::{
-    AccountInformation, Balance, Empty, Order, OrderCanceled, TradeHistory, Transaction,
+    AccountInformation, AmendKeepPriorityResponse, Balance, CancelReplaceResponse, Empty,
+    IntoNumber, Number, Order, OrderCanceled, OrderList, TradeHistory, Transaction,
 };
 use crate::client::Client;
+use crate::validation::OrderValidator;
 use crate::errors::Result;
 use std::collections::BTreeMap;
 use std::fmt::Display;
@@ -15,29 +17,103 @@ use crate::api::Spot;
 pub struct Account {
     pub client: Client,
     pub recv_window: u64,
+    /// Checks custom orders against the exchange filters before they are sent.
+    pub validator: Option<OrderValidator>,
 }
 
-struct OrderRequest {
+pub(crate) struct OrderRequest {
     pub symbol: String,
-    pub qty: f64,
-    pub price: f64,
-    pub stop_price: Option<f64>,
+    pub qty: Number,
+    pub price: Number,
+    pub stop_price: Option<Number>,
     pub order_side: OrderSide,
     pub order_type: OrderType,
     pub time_in_force: TimeInForce,
     pub new_client_order_id: Option<String>,
 }
 
-struct OrderQuoteQuantityRequest {
+impl OrderRequest {
+    pub(crate) fn validate(&mut self, validator: &OrderValidator) -> Result<()> {
+        let market = matches!(self.order_type, OrderType::Market);
+        let price = if self.price != Number::default() {
+            Some(self.price)
+        } else {
+            None
+        };
+        let checked =
+            validator.check_order(&self.symbol, market, price, self.stop_price, Some(self.qty))?;
+
+        if let Some(price) = checked.price {
+            self.price = price;
+        }
+        if let Some(qty) = checked.qty {
+            self.qty = qty;
+        }
+        self.stop_price = checked.stop_price;
+        Ok(())
+    }
+}
+
+pub(crate) struct OrderQuoteQuantityRequest {
     pub symbol: String,
-    pub quote_order_qty: f64,
-    pub price: f64,
+    pub quote_order_qty: Number,
+    pub price: Number,
     pub order_side: OrderSide,
     pub order_type: OrderType,
     pub time_in_force: TimeInForce,
     pub new_client_order_id: Option<String>,
 }
 
+/// OCO order: a limit maker leg paired with a stop-loss or stop-limit leg.
+///
+/// The stop leg becomes a `STOP_LOSS_LIMIT` order when `stop_limit_price` is set,
+/// using `stop_limit_time_in_force` (GTC when omitted).
+pub struct OcoOrderRequest {
+    pub symbol: String,
+    pub side: OrderSide,
+    pub qty: Number,
+    pub price: Number,
+    pub stop_price: Number,
+    pub stop_limit_price: Option<Number>,
+    pub stop_limit_time_in_force: Option<TimeInForce>,
+    pub list_client_order_id: Option<String>,
+    pub limit_client_order_id: Option<String>,
+    pub stop_client_order_id: Option<String>,
+}
+
+/// Cancels an order and places a new one on the same symbol in a single request.
+///
+/// The order to cancel is given by `cancel_order_id` or `cancel_orig_client_order_id`. The new
+/// order gets `time_in_force` (GTC when omitted) when it has a price.
+pub struct CancelReplaceRequest {
+    pub symbol: String,
+    pub cancel_replace_mode: CancelReplaceMode,
+    pub cancel_order_id: Option<u64>,
+    pub cancel_orig_client_order_id: Option<String>,
+    pub side: OrderSide,
+    pub order_type: OrderType,
+    pub time_in_force: Option<TimeInForce>,
+    pub qty: Number,
+    pub price: Option<Number>,
+    pub stop_price: Option<Number>,
+    pub new_client_order_id: Option<String>,
+}
+
+/// Whether the new order of a cancel-replace is placed when the cancel fails.
+pub enum CancelReplaceMode {
+    StopOnFailure,
+    AllowFailure,
+}
+
+impl Display for CancelReplaceMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::StopOnFailure => write!(f, "STOP_ON_FAILURE"),
+            Self::AllowFailure => write!(f, "ALLOW_FAILURE"),
+        }
+    }
+}
+
 pub enum OrderType {
     Limit,
     Market,
@@ -118,6 +194,12 @@ impl Display for TimeInForce {
 }
 
 impl Account {
+    /// Validate (and optionally round) custom orders before sending them.
+    pub fn with_validator(mut self, validator: OrderValidator) -> Self {
+        self.validator = Some(validator);
+        self
+    }
+
     // Account Information
     pub fn get_account(&self) -> Result<AccountInformation> {
         let request = build_signed_request(BTreeMap::new(), self.recv_window)?;
@@ -210,45 +292,46 @@ impl Account {
     }
 
     // Place a LIMIT order - BUY
-    pub fn limit_buy<S, F>(&self, symbol: S, qty: F, price: f64) -> Result<Transaction>
+    pub fn limit_buy<S, F>(&self, symbol: S, qty: F, price: impl IntoNumber) -> Result<Transaction>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: IntoNumber,
     {
         let buy = OrderRequest {
             symbol: symbol.into(),
-            qty: qty.into(),
-            price,
+            qty: qty.try_into_number()?,
+            price: price.try_into_number()?,
             stop_price: None,
             order_side: OrderSide::Buy,
             order_type: OrderType::Limit,
             time_in_force: TimeInForce::GTC,
             new_client_order_id: None,
         };
-        let order = self.build_order(buy);
+        let order = build_order(buy);
         let request = build_signed_request(order, self.recv_window)?;
-        self.client.post_signed(API::Spot(Spot::Order), request)
+        self.client
+            .post_signed_order(API::Spot(Spot::Order), request)
     }
 
     /// Place a test limit order - BUY
     ///
     /// This order is sandboxed: it is validated, but not sent to the matching engine.
-    pub fn test_limit_buy<S, F>(&self, symbol: S, qty: F, price: f64) -> Result<()>
+    pub fn test_limit_buy<S, F>(&self, symbol: S, qty: F, price: impl IntoNumber) -> Result<()>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: IntoNumber,
     {
         let buy = OrderRequest {
             symbol: symbol.into(),
-            qty: qty.into(),
-            price,
+            qty: qty.try_into_number()?,
+            price: price.try_into_number()?,
             stop_price: None,
             order_side: OrderSide::Buy,
             order_type: OrderType::Limit,
             time_in_force: TimeInForce::GTC,
             new_client_order_id: None,
         };
-        let order = self.build_order(buy);
+        let order = build_order(buy);
         let request = build_signed_request(order, self.recv_window)?;
         self.client
             .post_signed::<Empty>(API::Spot(Spot::OrderTest), request)
@@ -256,45 +339,46 @@ impl Account {
     }
 
     // Place a LIMIT order - SELL
-    pub fn limit_sell<S, F>(&self, symbol: S, qty: F, price: f64) -> Result<Transaction>
+    pub fn limit_sell<S, F>(&self, symbol: S, qty: F, price: impl IntoNumber) -> Result<Transaction>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: IntoNumber,
     {
         let sell = OrderRequest {
             symbol: symbol.into(),
-            qty: qty.into(),
-            price,
+            qty: qty.try_into_number()?,
+            price: price.try_into_number()?,
             stop_price: None,
             order_side: OrderSide::Sell,
             order_type: OrderType::Limit,
             time_in_force: TimeInForce::GTC,
             new_client_order_id: None,
         };
-        let order = self.build_order(sell);
+        let order = build_order(sell);
         let request = build_signed_request(order, self.recv_window)?;
-        self.client.post_signed(API::Spot(Spot::Order), request)
+        self.client
+            .post_signed_order(API::Spot(Spot::Order), request)
     }
 
     /// Place a test LIMIT order - SELL
     ///
     /// This order is sandboxed: it is validated, but not sent to the matching engine.
-    pub fn test_limit_sell<S, F>(&self, symbol: S, qty: F, price: f64) -> Result<()>
+    pub fn test_limit_sell<S, F>(&self, symbol: S, qty: F, price: impl IntoNumber) -> Result<()>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: IntoNumber,
     {
         let sell = OrderRequest {
             symbol: symbol.into(),
-            qty: qty.into(),
-            price,
+            qty: qty.try_into_number()?,
+            price: price.try_into_number()?,
             stop_price: None,
             order_side: OrderSide::Sell,
             order_type: OrderType::Limit,
             time_in_force: TimeInForce::GTC,
             new_client_order_id: None,
         };
-        let order = self.build_order(sell);
+        let order = build_order(sell);
         let request = build_signed_request(order, self.recv_window)?;
         self.client
             .post_signed::<Empty>(API::Spot(Spot::OrderTest), request)
@@ -305,21 +389,22 @@ impl Account {
     pub fn market_buy<S, F>(&self, symbol: S, qty: F) -> Result<Transaction>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: IntoNumber,
     {
         let buy = OrderRequest {
             symbol: symbol.into(),
-            qty: qty.into(),
-            price: 0.0,
+            qty: qty.try_into_number()?,
+            price: Number::default(),
             stop_price: None,
             order_side: OrderSide::Buy,
             order_type: OrderType::Market,
             time_in_force: TimeInForce::GTC,
             new_client_order_id: None,
         };
-        let order = self.build_order(buy);
+        let order = build_order(buy);
         let request = build_signed_request(order, self.recv_window)?;
-        self.client.post_signed(API::Spot(Spot::Order), request)
+        self.client
+            .post_signed_order(API::Spot(Spot::Order), request)
     }
 
     /// Place a test MARKET order - BUY
@@ -328,19 +413,19 @@ impl Account {
     pub fn test_market_buy<S, F>(&self, symbol: S, qty: F) -> Result<()>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: IntoNumber,
     {
         let buy = OrderRequest {
             symbol: symbol.into(),
-            qty: qty.into(),
-            price: 0.0,
+            qty: qty.try_into_number()?,
+            price: Number::default(),
             stop_price: None,
             order_side: OrderSide::Buy,
             order_type: OrderType::Market,
             time_in_force: TimeInForce::GTC,
             new_client_order_id: None,
         };
-        let order = self.build_order(buy);
+        let order = build_order(buy);
         let request = build_signed_request(order, self.recv_window)?;
         self.client
             .post_signed::<Empty>(API::Spot(Spot::OrderTest), request)
@@ -353,20 +438,21 @@ impl Account {
     ) -> Result<Transaction>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: IntoNumber,
     {
         let buy = OrderQuoteQuantityRequest {
             symbol: symbol.into(),
-            quote_order_qty: quote_order_qty.into(),
-            price: 0.0,
+            quote_order_qty: quote_order_qty.try_into_number()?,
+            price: Number::default(),
             order_side: OrderSide::Buy,
             order_type: OrderType::Market,
             time_in_force: TimeInForce::GTC,
             new_client_order_id: None,
         };
-        let order = self.build_quote_quantity_order(buy);
+        let order = build_quote_quantity_order(buy);
         let request = build_signed_request(order, self.recv_window)?;
-        self.client.post_signed(API::Spot(Spot::Order), request)
+        self.client
+            .post_signed_order(API::Spot(Spot::Order), request)
     }
 
     /// Place a test MARKET order with quote quantity - BUY
@@ -377,18 +463,18 @@ impl Account {
     ) -> Result<()>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: IntoNumber,
     {
         let buy = OrderQuoteQuantityRequest {
             symbol: symbol.into(),
-            quote_order_qty: quote_order_qty.into(),
-            price: 0.0,
+            quote_order_qty: quote_order_qty.try_into_number()?,
+            price: Number::default(),
             order_side: OrderSide::Buy,
             order_type: OrderType::Market,
             time_in_force: TimeInForce::GTC,
             new_client_order_id: None,
         };
-        let order = self.build_quote_quantity_order(buy);
+        let order = build_quote_quantity_order(buy);
         let request = build_signed_request(order, self.recv_window)?;
         self.client
             .post_signed::<Empty>(API::Spot(Spot::OrderTest), request)
@@ -399,21 +485,22 @@ impl Account {
     pub fn market_sell<S, F>(&self, symbol: S, qty: F) -> Result<Transaction>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: IntoNumber,
     {
         let sell = OrderRequest {
             symbol: symbol.into(),
-            qty: qty.into(),
-            price: 0.0,
+            qty: qty.try_into_number()?,
+            price: Number::default(),
             stop_price: None,
             order_side: OrderSide::Sell,
             order_type: OrderType::Market,
             time_in_force: TimeInForce::GTC,
             new_client_order_id: None,
         };
-        let order = self.build_order(sell);
+        let order = build_order(sell);
         let request = build_signed_request(order, self.recv_window)?;
-        self.client.post_signed(API::Spot(Spot::Order), request)
+        self.client
+            .post_signed_order(API::Spot(Spot::Order), request)
     }
 
     /// Place a test MARKET order - SELL
@@ -422,19 +509,19 @@ impl Account {
     pub fn test_market_sell<S, F>(&self, symbol: S, qty: F) -> Result<()>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: IntoNumber,
     {
         let sell = OrderRequest {
             symbol: symbol.into(),
-            qty: qty.into(),
-            price: 0.0,
+            qty: qty.try_into_number()?,
+            price: Number::default(),
             stop_price: None,
             order_side: OrderSide::Sell,
             order_type: OrderType::Market,
             time_in_force: TimeInForce::GTC,
             new_client_order_id: None,
         };
-        let order = self.build_order(sell);
+        let order = build_order(sell);
         let request = build_signed_request(order, self.recv_window)?;
         self.client
             .post_signed::<Empty>(API::Spot(Spot::OrderTest), request)
@@ -447,20 +534,21 @@ impl Account {
     ) -> Result<Transaction>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: IntoNumber,
     {
         let sell = OrderQuoteQuantityRequest {
             symbol: symbol.into(),
-            quote_order_qty: quote_order_qty.into(),
-            price: 0.0,
+            quote_order_qty: quote_order_qty.try_into_number()?,
+            price: Number::default(),
             order_side: OrderSide::Sell,
             order_type: OrderType::Market,
             time_in_force: TimeInForce::GTC,
             new_client_order_id: None,
         };
-        let order = self.build_quote_quantity_order(sell);
+        let order = build_quote_quantity_order(sell);
         let request = build_signed_request(order, self.recv_window)?;
-        self.client.post_signed(API::Spot(Spot::Order), request)
+        self.client
+            .post_signed_order(API::Spot(Spot::Order), request)
     }
 
     /// Place a test MARKET order with quote quantity - SELL
@@ -471,18 +559,18 @@ impl Account {
     ) -> Result<()>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: IntoNumber,
     {
         let sell = OrderQuoteQuantityRequest {
             symbol: symbol.into(),
-            quote_order_qty: quote_order_qty.into(),
-            price: 0.0,
+            quote_order_qty: quote_order_qty.try_into_number()?,
+            price: Number::default(),
             order_side: OrderSide::Sell,
             order_type: OrderType::Market,
             time_in_force: TimeInForce::GTC,
             new_client_order_id: None,
         };
-        let order = self.build_quote_quantity_order(sell);
+        let order = build_quote_quantity_order(sell);
         let request = build_signed_request(order, self.recv_window)?;
         self.client
             .post_signed::<Empty>(API::Spot(Spot::OrderTest), request)
@@ -504,25 +592,27 @@ impl Account {
     /// }
     /// ```
     pub fn stop_limit_buy_order<S, F>(
-        &self, symbol: S, qty: F, price: f64, stop_price: f64, time_in_force: TimeInForce,
+        &self, symbol: S, qty: F, price: impl IntoNumber, stop_price: impl IntoNumber,
+        time_in_force: TimeInForce,
     ) -> Result<Transaction>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: IntoNumber,
     {
         let sell = OrderRequest {
             symbol: symbol.into(),
-            qty: qty.into(),
-            price,
-            stop_price: Some(stop_price),
+            qty: qty.try_into_number()?,
+            price: price.try_into_number()?,
+            stop_price: Some(stop_price.try_into_number()?),
             order_side: OrderSide::Buy,
             order_type: OrderType::StopLossLimit,
             time_in_force,
             new_client_order_id: None,
         };
-        let order = self.build_order(sell);
+        let order = build_order(sell);
         let request = build_signed_request(order, self.recv_window)?;
-        self.client.post_signed(API::Spot(Spot::Order), request)
+        self.client
+            .post_signed_order(API::Spot(Spot::Order), request)
     }
 
     /// Create a stop limit buy test order for the given symbol, price and stop price.
@@ -542,23 +632,24 @@ impl Account {
     /// }
     /// ```
     pub fn test_stop_limit_buy_order<S, F>(
-        &self, symbol: S, qty: F, price: f64, stop_price: f64, time_in_force: TimeInForce,
+        &self, symbol: S, qty: F, price: impl IntoNumber, stop_price: impl IntoNumber,
+        time_in_force: TimeInForce,
     ) -> Result<()>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: IntoNumber,
     {
         let sell = OrderRequest {
             symbol: symbol.into(),
-            qty: qty.into(),
-            price,
-            stop_price: Some(stop_price),
+            qty: qty.try_into_number()?,
+            price: price.try_into_number()?,
+            stop_price: Some(stop_price.try_into_number()?),
             order_side: OrderSide::Buy,
             order_type: OrderType::StopLossLimit,
             time_in_force,
             new_client_order_id: None,
         };
-        let order = self.build_order(sell);
+        let order = build_order(sell);
         let request = build_signed_request(order, self.recv_window)?;
         self.client
             .post_signed::<Empty>(API::Spot(Spot::OrderTest), request)
@@ -580,25 +671,27 @@ impl Account {
     /// }
     /// ```
     pub fn stop_limit_sell_order<S, F>(
-        &self, symbol: S, qty: F, price: f64, stop_price: f64, time_in_force: TimeInForce,
+        &self, symbol: S, qty: F, price: impl IntoNumber, stop_price: impl IntoNumber,
+        time_in_force: TimeInForce,
     ) -> Result<Transaction>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: IntoNumber,
     {
         let sell = OrderRequest {
             symbol: symbol.into(),
-            qty: qty.into(),
-            price,
-            stop_price: Some(stop_price),
+            qty: qty.try_into_number()?,
+            price: price.try_into_number()?,
+            stop_price: Some(stop_price.try_into_number()?),
             order_side: OrderSide::Sell,
             order_type: OrderType::StopLossLimit,
             time_in_force,
             new_client_order_id: None,
         };
-        let order = self.build_order(sell);
+        let order = build_order(sell);
         let request = build_signed_request(order, self.recv_window)?;
-        self.client.post_signed(API::Spot(Spot::Order), request)
+        self.client
+            .post_signed_order(API::Spot(Spot::Order), request)
     }
 
     /// Create a stop limit sell order for the given symbol, price and stop price.
@@ -618,23 +711,24 @@ impl Account {
     /// }
     /// ```
     pub fn test_stop_limit_sell_order<S, F>(
-        &self, symbol: S, qty: F, price: f64, stop_price: f64, time_in_force: TimeInForce,
+        &self, symbol: S, qty: F, price: impl IntoNumber, stop_price: impl IntoNumber,
+        time_in_force: TimeInForce,
     ) -> Result<()>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: IntoNumber,
     {
         let sell = OrderRequest {
             symbol: symbol.into(),
-            qty: qty.into(),
-            price,
-            stop_price: Some(stop_price),
+            qty: qty.try_into_number()?,
+            price: price.try_into_number()?,
+            stop_price: Some(stop_price.try_into_number()?),
             order_side: OrderSide::Sell,
             order_type: OrderType::StopLossLimit,
             time_in_force,
             new_client_order_id: None,
         };
-        let order = self.build_order(sell);
+        let order = build_order(sell);
         let request = build_signed_request(order, self.recv_window)?;
         self.client
             .post_signed::<Empty>(API::Spot(Spot::OrderTest), request)
@@ -644,26 +738,31 @@ impl Account {
     /// Place a custom order
     #[allow(clippy::too_many_arguments)]
     pub fn custom_order<S, F>(
-        &self, symbol: S, qty: F, price: f64, stop_price: Option<f64>, order_side: OrderSide,
-        order_type: OrderType, time_in_force: TimeInForce, new_client_order_id: Option<String>,
+        &self, symbol: S, qty: F, price: impl IntoNumber, stop_price: Option<Number>,
+        order_side: OrderSide, order_type: OrderType, time_in_force: TimeInForce,
+        new_client_order_id: Option<String>,
     ) -> Result<Transaction>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: IntoNumber,
     {
-        let sell = OrderRequest {
+        let mut sell = OrderRequest {
             symbol: symbol.into(),
-            qty: qty.into(),
-            price,
+            qty: qty.try_into_number()?,
+            price: price.try_into_number()?,
             stop_price,
             order_side,
             order_type,
             time_in_force,
             new_client_order_id,
         };
-        let order = self.build_order(sell);
+        if let Some(validator) = &self.validator {
+            sell.validate(validator)?;
+        }
+        let order = build_order(sell);
         let request = build_signed_request(order, self.recv_window)?;
-        self.client.post_signed(API::Spot(Spot::Order), request)
+        self.client
+            .post_signed_order(API::Spot(Spot::Order), request)
     }
 
     /// Place a test custom order
@@ -671,24 +770,28 @@ impl Account {
     /// This order is sandboxed: it is validated, but not sent to the matching engine.
     #[allow(clippy::too_many_arguments)]
     pub fn test_custom_order<S, F>(
-        &self, symbol: S, qty: F, price: f64, stop_price: Option<f64>, order_side: OrderSide,
-        order_type: OrderType, time_in_force: TimeInForce, new_client_order_id: Option<String>,
+        &self, symbol: S, qty: F, price: impl IntoNumber, stop_price: Option<Number>,
+        order_side: OrderSide, order_type: OrderType, time_in_force: TimeInForce,
+        new_client_order_id: Option<String>,
     ) -> Result<()>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: IntoNumber,
     {
-        let sell = OrderRequest {
+        let mut sell = OrderRequest {
             symbol: symbol.into(),
-            qty: qty.into(),
-            price,
+            qty: qty.try_into_number()?,
+            price: price.try_into_number()?,
             stop_price,
             order_side,
             order_type,
             time_in_force,
             new_client_order_id,
         };
-        let order = self.build_order(sell);
+        if let Some(validator) = &self.validator {
+            sell.validate(validator)?;
+        }
+        let order = build_order(sell);
         let request = build_signed_request(order, self.recv_window)?;
         self.client
             .post_signed::<Empty>(API::Spot(Spot::OrderTest), request)
@@ -725,6 +828,141 @@ impl Account {
     }
 
     pub fn cancel_order_with_client_id_rs<S>() {}
+
+    /// Cancel an order and place a new one in a single request.
+    ///
+    /// Unless both succeed the error is `ErrorKind::CancelReplaceFailed`, which tells what
+    /// happened to either order.
+    pub fn cancel_replace_order(
+        &self, order: CancelReplaceRequest,
+    ) -> Result<CancelReplaceResponse> {
+        let parameters = build_cancel_replace_order(order);
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed_order(API::Spot(Spot::OrderCancelReplace), request)
+    }
+
+    /// Reduce the quantity of an open order to `new_qty`, keeping its priority in the order book
+    pub fn amend_order_keep_priority<S>(
+        &self, symbol: S, order_id: u64, new_qty: impl IntoNumber,
+    ) -> Result<AmendKeepPriorityResponse>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("orderId".into(), order_id.to_string());
+        parameters.insert("newQty".into(), new_qty.try_into_number()?.to_string());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .put_signed(API::Spot(Spot::OrderAmendKeepPriority), request)
+    }
+
+    pub fn amend_order_keep_priority_with_client_id<S>(
+        &self, symbol: S, orig_client_order_id: String, new_qty: impl IntoNumber,
+    ) -> Result<AmendKeepPriorityResponse>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("origClientOrderId".into(), orig_client_order_id);
+        parameters.insert("newQty".into(), new_qty.try_into_number()?.to_string());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .put_signed(API::Spot(Spot::OrderAmendKeepPriority), request)
+    }
+
+    /// Place an OCO order
+    pub fn oco_order(&self, order: OcoOrderRequest) -> Result<OrderList> {
+        let order = build_oco_order(order);
+        let request = build_signed_request(order, self.recv_window)?;
+        self.client.post_signed_order(API::Spot(Spot::Oco), request)
+    }
+
+    /// Cancel an entire order list
+    pub fn cancel_order_list<S>(&self, symbol: S, order_list_id: u64) -> Result<OrderList>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("orderListId".into(), order_list_id.to_string());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .delete_signed(API::Spot(Spot::OrderList), Some(request))
+    }
+
+    pub fn cancel_order_list_with_client_id<S>(
+        &self, symbol: S, list_client_order_id: String,
+    ) -> Result<OrderList>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("listClientOrderId".into(), list_client_order_id);
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .delete_signed(API::Spot(Spot::OrderList), Some(request))
+    }
+
+    // Check an order list's status
+    pub fn order_list_status(&self, order_list_id: u64) -> Result<OrderList> {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("orderListId".into(), order_list_id.to_string());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Spot(Spot::OrderList), Some(request))
+    }
+
+    pub fn order_list_status_with_client_id(
+        &self, list_client_order_id: String,
+    ) -> Result<OrderList> {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("origClientOrderId".into(), list_client_order_id);
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Spot(Spot::OrderList), Some(request))
+    }
+
+    /// All order lists, optionally starting from `from_id` or bounded by time
+    pub fn get_all_order_lists(
+        &self, from_id: Option<u64>, start_time: Option<u64>, end_time: Option<u64>,
+        limit: Option<u16>,
+    ) -> Result<Vec<OrderList>> {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        if let Some(from_id) = from_id {
+            parameters.insert("fromId".into(), from_id.to_string());
+        }
+        if let Some(start_time) = start_time {
+            parameters.insert("startTime".into(), start_time.to_string());
+        }
+        if let Some(end_time) = end_time {
+            parameters.insert("endTime".into(), end_time.to_string());
+        }
+        if let Some(limit) = limit {
+            parameters.insert("limit".into(), limit.to_string());
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Spot(Spot::AllOrderList), Some(request))
+    }
+
+    // Current open order lists
+    pub fn get_open_order_lists(&self) -> Result<Vec<OrderList>> {
+        let request = build_signed_request(BTreeMap::new(), self.recv_window)?;
+        self.client
+            .get_signed(API::Spot(Spot::OpenOrderList), Some(request))
+    }
+
     /// Place a test cancel order
     ///
     /// This order is sandboxed: it is validated, but not sent to the matching engine.
@@ -756,8 +994,8 @@ impl Account {
 
     // Trade history starting from selected date
     pub fn trade_history_from<S>(&self, symbol: S, start_time: u64) -> Result<Vec<TradeHistory>>
-        where
-            S: Into<String>,
+    where
+        S: Into<String>,
     {
         if !is_start_time_valid(&start_time) {
             return bail!("Start time should be less than the current time");
@@ -772,9 +1010,11 @@ impl Account {
     }
 
     // Trade history starting from selected time to some time
-    pub fn trade_history_from_to<S>(&self, symbol: S, start_time: u64, end_time: u64) -> Result<Vec<TradeHistory>>
-        where
-            S: Into<String>,
+    pub fn trade_history_from_to<S>(
+        &self, symbol: S, start_time: u64, end_time: u64,
+    ) -> Result<Vec<TradeHistory>>
+    where
+        S: Into<String>,
     {
         if end_time <= start_time {
             return bail!("End time should be greater than start time");
@@ -786,8 +1026,8 @@ impl Account {
     }
 
     fn get_trades<S>(&self, symbol: S, start_time: u64, end_time: u64) -> Result<Vec<TradeHistory>>
-        where
-            S: Into<String>,
+    where
+        S: Into<String>,
     {
         let mut trades = match self.trade_history_from(symbol, start_time) {
             Ok(trades) => trades,
@@ -796,50 +1036,116 @@ impl Account {
         trades.retain(|trade| trade.time <= end_time);
         Ok(trades)
     }
+}
 
-    fn build_order(&self, order: OrderRequest) -> BTreeMap<String, String> {
-        let mut order_parameters: BTreeMap<String, String> = BTreeMap::new();
+pub(crate) fn build_order(order: OrderRequest) -> BTreeMap<String, String> {
+    let mut order_parameters: BTreeMap<String, String> = BTreeMap::new();
 
-        order_parameters.insert("symbol".into(), order.symbol);
-        order_parameters.insert("side".into(), order.order_side.to_string());
-        order_parameters.insert("type".into(), order.order_type.to_string());
-        order_parameters.insert("quantity".into(), order.qty.to_string());
+    order_parameters.insert("symbol".into(), order.symbol);
+    order_parameters.insert("side".into(), order.order_side.to_string());
+    order_parameters.insert("type".into(), order.order_type.to_string());
+    order_parameters.insert("quantity".into(), order.qty.to_string());
 
-        if let Some(stop_price) = order.stop_price {
-            order_parameters.insert("stopPrice".into(), stop_price.to_string());
-        }
+    if let Some(stop_price) = order.stop_price {
+        order_parameters.insert("stopPrice".into(), stop_price.to_string());
+    }
 
-        if order.price != 0.0 {
-            order_parameters.insert("price".into(), order.price.to_string());
-            order_parameters.insert("timeInForce".into(), order.time_in_force.to_string());
-        }
+    if order.price != Number::default() {
+        order_parameters.insert("price".into(), order.price.to_string());
+        order_parameters.insert("timeInForce".into(), order.time_in_force.to_string());
+    }
 
-        if let Some(client_order_id) = order.new_client_order_id {
-            order_parameters.insert("newClientOrderId".into(), client_order_id);
-        }
+    if let Some(client_order_id) = order.new_client_order_id {
+        order_parameters.insert("newClientOrderId".into(), client_order_id);
+    }
+
+    order_parameters
+}
+
+pub(crate) fn build_quote_quantity_order(
+    order: OrderQuoteQuantityRequest,
+) -> BTreeMap<String, String> {
+    let mut order_parameters: BTreeMap<String, String> = BTreeMap::new();
+
+    order_parameters.insert("symbol".into(), order.symbol);
+    order_parameters.insert("side".into(), order.order_side.to_string());
+    order_parameters.insert("type".into(), order.order_type.to_string());
+    order_parameters.insert("quoteOrderQty".into(), order.quote_order_qty.to_string());
+
+    if order.price != Number::default() {
+        order_parameters.insert("price".into(), order.price.to_string());
+        order_parameters.insert("timeInForce".into(), order.time_in_force.to_string());
+    }
 
-        order_parameters
+    if let Some(client_order_id) = order.new_client_order_id {
+        order_parameters.insert("newClientOrderId".into(), client_order_id);
     }
 
-    fn build_quote_quantity_order(
-        &self, order: OrderQuoteQuantityRequest,
-    ) -> BTreeMap<String, String> {
-        let mut order_parameters: BTreeMap<String, String> = BTreeMap::new();
+    order_parameters
+}
 
-        order_parameters.insert("symbol".into(), order.symbol);
-        order_parameters.insert("side".into(), order.order_side.to_string());
-        order_parameters.insert("type".into(), order.order_type.to_string());
-        order_parameters.insert("quoteOrderQty".into(), order.quote_order_qty.to_string());
+pub(crate) fn build_oco_order(order: OcoOrderRequest) -> BTreeMap<String, String> {
+    let mut order_parameters: BTreeMap<String, String> = BTreeMap::new();
 
-        if order.price != 0.0 {
-            order_parameters.insert("price".into(), order.price.to_string());
-            order_parameters.insert("timeInForce".into(), order.time_in_force.to_string());
-        }
+    order_parameters.insert("symbol".into(), order.symbol);
+    order_parameters.insert("side".into(), order.side.to_string());
+    order_parameters.insert("quantity".into(), order.qty.to_string());
+    order_parameters.insert("price".into(), order.price.to_string());
+    order_parameters.insert("stopPrice".into(), order.stop_price.to_string());
 
-        if let Some(client_order_id) = order.new_client_order_id {
-            order_parameters.insert("newClientOrderId".into(), client_order_id);
-        }
+    if let Some(stop_limit_price) = order.stop_limit_price {
+        let time_in_force = order.stop_limit_time_in_force.unwrap_or(TimeInForce::GTC);
+        order_parameters.insert("stopLimitPrice".into(), stop_limit_price.to_string());
+        order_parameters.insert("stopLimitTimeInForce".into(), time_in_force.to_string());
+    }
 
-        order_parameters
+    if let Some(list_client_order_id) = order.list_client_order_id {
+        order_parameters.insert("listClientOrderId".into(), list_client_order_id);
     }
+    if let Some(limit_client_order_id) = order.limit_client_order_id {
+        order_parameters.insert("limitClientOrderId".into(), limit_client_order_id);
+    }
+    if let Some(stop_client_order_id) = order.stop_client_order_id {
+        order_parameters.insert("stopClientOrderId".into(), stop_client_order_id);
+    }
+
+    order_parameters
+}
+
+pub(crate) fn build_cancel_replace_order(order: CancelReplaceRequest) -> BTreeMap<String, String> {
+    let mut order_parameters: BTreeMap<String, String> = BTreeMap::new();
+
+    order_parameters.insert("symbol".into(), order.symbol);
+    order_parameters.insert(
+        "cancelReplaceMode".into(),
+        order.cancel_replace_mode.to_string(),
+    );
+    if let Some(cancel_order_id) = order.cancel_order_id {
+        order_parameters.insert("cancelOrderId".into(), cancel_order_id.to_string());
+    }
+    if let Some(cancel_orig_client_order_id) = order.cancel_orig_client_order_id {
+        order_parameters.insert(
+            "cancelOrigClientOrderId".into(),
+            cancel_orig_client_order_id,
+        );
+    }
+
+    order_parameters.insert("side".into(), order.side.to_string());
+    order_parameters.insert("type".into(), order.order_type.to_string());
+    order_parameters.insert("quantity".into(), order.qty.to_string());
+    order_parameters.insert("newOrderRespType".into(), "FULL".into());
+
+    if let Some(price) = order.price {
+        let time_in_force = order.time_in_force.unwrap_or(TimeInForce::GTC);
+        order_parameters.insert("price".into(), price.to_string());
+        order_parameters.insert("timeInForce".into(), time_in_force.to_string());
+    }
+    if let Some(stop_price) = order.stop_price {
+        order_parameters.insert("stopPrice".into(), stop_price.to_string());
+    }
+    if let Some(client_order_id) = order.new_client_order_id {
+        order_parameters.insert("newClientOrderId".into(), client_order_id);
+    }
+
+    order_parameters
 }
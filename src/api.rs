@@ -1,20 +1,34 @@
+use std::marker::PhantomData;
+use error_chain::bail;
 use crate::account::Account;
 use crate::client::Client;
 use crate::config::Config;
+use crate::errors::{Error, Result};
 use crate::futures::account::FuturesAccount;
 use crate::futures::general::FuturesGeneral;
 use crate::futures::market::FuturesMarket;
+use crate::futures::model::CoinM;
 use crate::futures::userstream::FuturesUserStream;
 use crate::general::General;
+use crate::margin::account::MarginAccount;
+use crate::margin::userstream::MarginUserStream;
 use crate::market::Market;
+use crate::options::account::OptionsAccount;
+use crate::options::general::OptionsGeneral;
+use crate::options::market::OptionsMarket;
+use crate::options::userstream::OptionsUserStream;
 use crate::userstream::UserStream;
 use crate::savings::Savings;
+use crate::subaccount::SubAccount;
 
 #[allow(clippy::all)]
 pub enum API {
     Spot(Spot),
     Savings(Sapi),
     Futures(Futures),
+    CoinFutures(Futures),
+    Options(Options),
+    Margin(Margin),
 }
 
 /// Endpoint for production and test orders.
@@ -35,6 +49,8 @@ pub enum Spot {
     BookTicker,
     Order,
     OrderTest,
+    OrderCancelReplace,
+    OrderAmendKeepPriority,
     OpenOrders,
     AllOrders,
     Oco,
@@ -51,6 +67,17 @@ pub enum Sapi {
     AssetDetail,
     DepositAddress,
     SpotFuturesTransfer,
+    Withdraw,
+    DepositHistory,
+    WithdrawHistory,
+    SystemStatus,
+    AccountStatus,
+    ApiTradingStatus,
+    AccountSnapshot,
+    UniversalTransfer,
+    SubAccountList,
+    SubAccountAssets,
+    SubAccountUniversalTransfer,
 }
 
 pub enum Futures {
@@ -65,8 +92,10 @@ pub enum Futures {
     ContinuousKlines,
     IndexPriceKlines,
     MarkPriceKlines,
+    PremiumIndexKlines,
     PremiumIndex,
     FundingRate,
+    FundingInfo,
     Ticker24hr,
     TickerPrice,
     BookTicker,
@@ -75,6 +104,8 @@ pub enum Futures {
     AllOrders,
     UserTrades,
     Order,
+    OrderAmendment,
+    CountdownCancelAll,
     PositionRisk,
     Balance,
     PositionSide,
@@ -87,17 +118,59 @@ pub enum Futures {
     LvtKlines,
     IndexInfo,
     ChangeInitialLeverage,
+    LeverageBracket,
     MarginType,
     PositionMargin,
     Account,
     OpenOrders,
     UserDataStream,
     Income,
+    BatchOrders,
 }
 
-impl From<API> for String {
-    fn from(item: API) -> Self {
-        String::from(match item {
+impl Futures {
+    // Routes without a COIN-M counterpart
+    pub(crate) fn usdm_only(&self) -> bool {
+        matches!(
+            self,
+            Futures::FundingInfo | Futures::LvtKlines | Futures::IndexInfo
+        )
+    }
+}
+
+pub enum Options {
+    Ping,
+    Time,
+    ExchangeInfo,
+    Depth,
+    Klines,
+    Mark,
+    Order,
+    OpenOrders,
+    AllOpenOrders,
+    Position,
+    MarginAccount,
+    UserDataStream,
+}
+
+pub enum Margin {
+    BorrowRepay,
+    Order,
+    OpenOrders,
+    Oco,
+    MaxBorrowable,
+    MaxTransferable,
+    InterestHistory,
+    IsolatedAccount,
+    UserDataStream,
+    IsolatedUserDataStream,
+}
+
+impl TryFrom<API> for String {
+    type Error = Error;
+
+    fn try_from(item: API) -> Result<Self> {
+        Ok(String::from(match item {
             API::Spot(route) => match route {
                 Spot::Ping => "/api/v3/ping",
                 Spot::Time => "/api/v3/time",
@@ -113,6 +186,8 @@ impl From<API> for String {
                 Spot::BookTicker => "/api/v3/ticker/bookTicker",
                 Spot::Order => "/api/v3/order",
                 Spot::OrderTest => "/api/v3/order/test",
+                Spot::OrderCancelReplace => "/api/v3/order/cancelReplace",
+                Spot::OrderAmendKeepPriority => "/api/v3/order/amend/keepPriority",
                 Spot::OpenOrders => "/api/v3/openOrders",
                 Spot::AllOrders => "/api/v3/allOrders",
                 Spot::Oco => "/api/v3/order/oco",
@@ -128,6 +203,17 @@ impl From<API> for String {
                 Sapi::AssetDetail => "/sapi/v1/asset/assetDetail",
                 Sapi::DepositAddress => "/sapi/v1/capital/deposit/address",
                 Sapi::SpotFuturesTransfer => "/sapi/v1/futures/transfer",
+                Sapi::Withdraw => "/sapi/v1/capital/withdraw/apply",
+                Sapi::DepositHistory => "/sapi/v1/capital/deposit/hisrec",
+                Sapi::WithdrawHistory => "/sapi/v1/capital/withdraw/history",
+                Sapi::SystemStatus => "/sapi/v1/system/status",
+                Sapi::AccountStatus => "/sapi/v1/account/status",
+                Sapi::ApiTradingStatus => "/sapi/v1/account/apiTradingStatus",
+                Sapi::AccountSnapshot => "/sapi/v1/accountSnapshot",
+                Sapi::UniversalTransfer => "/sapi/v1/asset/transfer",
+                Sapi::SubAccountList => "/sapi/v1/sub-account/list",
+                Sapi::SubAccountAssets => "/sapi/v3/sub-account/assets",
+                Sapi::SubAccountUniversalTransfer => "/sapi/v1/sub-account/universalTransfer",
             },
             API::Futures(route) => match route {
                 Futures::Ping => "/fapi/v1/ping",
@@ -141,8 +227,10 @@ impl From<API> for String {
                 Futures::ContinuousKlines => "/fapi/v1/continuousKlines",
                 Futures::IndexPriceKlines => "/fapi/v1/indexPriceKlines",
                 Futures::MarkPriceKlines => "/fapi/v1/markPriceKlines",
+                Futures::PremiumIndexKlines => "/fapi/v1/premiumIndexKlines",
                 Futures::PremiumIndex => "/fapi/v1/premiumIndex",
                 Futures::FundingRate => "/fapi/v1/fundingRate",
+                Futures::FundingInfo => "/fapi/v1/fundingInfo",
                 Futures::Ticker24hr => "/fapi/v1/ticker/24hr",
                 Futures::TickerPrice => "/fapi/v1/ticker/price",
                 Futures::BookTicker => "/fapi/v1/ticker/bookTicker",
@@ -152,6 +240,8 @@ impl From<API> for String {
                 Futures::UserTrades => "/fapi/v1/userTrades",
                 Futures::PositionSide => "/fapi/v1/positionSide/dual",
                 Futures::Order => "/fapi/v1/order",
+                Futures::OrderAmendment => "/fapi/v1/orderAmendment",
+                Futures::CountdownCancelAll => "/fapi/v1/countdownCancelAll",
                 Futures::PositionRisk => "/fapi/v2/positionRisk",
                 Futures::Balance => "/fapi/v2/balance",
                 Futures::OpenInterest => "/fapi/v1/openInterest",
@@ -163,14 +253,90 @@ impl From<API> for String {
                 Futures::LvtKlines => "/fapi/v1/lvtKlines",
                 Futures::IndexInfo => "/fapi/v1/indexInfo",
                 Futures::ChangeInitialLeverage => "/fapi/v1/leverage",
+                Futures::LeverageBracket => "/fapi/v1/leverageBracket",
                 Futures::MarginType => "/fapi/v1/marginType",
                 Futures::PositionMargin => "/fapi/v1/positionMargin",
                 Futures::Account => "/fapi/v2/account",
                 Futures::OpenOrders => "/fapi/v1/openOrders",
                 Futures::UserDataStream => "/fapi/v1/listenKey",
                 Futures::Income => "/fapi/v1/income",
+                Futures::BatchOrders => "/fapi/v1/batchOrders",
+            },
+            API::CoinFutures(route) => match route {
+                Futures::Ping => "/dapi/v1/ping",
+                Futures::Time => "/dapi/v1/time",
+                Futures::ExchangeInfo => "/dapi/v1/exchangeInfo",
+                Futures::Depth => "/dapi/v1/depth",
+                Futures::Trades => "/dapi/v1/trades",
+                Futures::HistoricalTrades => "/dapi/v1/historicalTrades",
+                Futures::AggTrades => "/dapi/v1/aggTrades",
+                Futures::Klines => "/dapi/v1/klines",
+                Futures::ContinuousKlines => "/dapi/v1/continuousKlines",
+                Futures::IndexPriceKlines => "/dapi/v1/indexPriceKlines",
+                Futures::MarkPriceKlines => "/dapi/v1/markPriceKlines",
+                Futures::PremiumIndexKlines => "/dapi/v1/premiumIndexKlines",
+                Futures::PremiumIndex => "/dapi/v1/premiumIndex",
+                Futures::FundingRate => "/dapi/v1/fundingRate",
+                Futures::Ticker24hr => "/dapi/v1/ticker/24hr",
+                Futures::TickerPrice => "/dapi/v1/ticker/price",
+                Futures::BookTicker => "/dapi/v1/ticker/bookTicker",
+                Futures::AllForceOrders => "/dapi/v1/allForceOrders",
+                Futures::AllOpenOrders => "/dapi/v1/allOpenOrders",
+                Futures::AllOrders => "/dapi/v1/allOrders",
+                Futures::UserTrades => "/dapi/v1/userTrades",
+                Futures::PositionSide => "/dapi/v1/positionSide/dual",
+                Futures::Order => "/dapi/v1/order",
+                Futures::OrderAmendment => "/dapi/v1/orderAmendment",
+                Futures::CountdownCancelAll => "/dapi/v1/countdownCancelAll",
+                Futures::PositionRisk => "/dapi/v1/positionRisk",
+                Futures::Balance => "/dapi/v1/balance",
+                Futures::OpenInterest => "/dapi/v1/openInterest",
+                Futures::OpenInterestHist => "/futures/data/openInterestHist",
+                Futures::TopLongShortAccountRatio => "/futures/data/topLongShortAccountRatio",
+                Futures::TopLongShortPositionRatio => "/futures/data/topLongShortPositionRatio",
+                Futures::GlobalLongShortAccountRatio => "/futures/data/globalLongShortAccountRatio",
+                Futures::TakerlongshortRatio => "/futures/data/takerBuySellVol",
+                Futures::FundingInfo | Futures::LvtKlines | Futures::IndexInfo => {
+                    bail!("Only offered by USDⓈ-M futures")
+                }
+                Futures::ChangeInitialLeverage => "/dapi/v1/leverage",
+                // v2 takes the symbol, v1 the pair
+                Futures::LeverageBracket => "/dapi/v2/leverageBracket",
+                Futures::MarginType => "/dapi/v1/marginType",
+                Futures::PositionMargin => "/dapi/v1/positionMargin",
+                Futures::Account => "/dapi/v1/account",
+                Futures::OpenOrders => "/dapi/v1/openOrders",
+                Futures::UserDataStream => "/dapi/v1/listenKey",
+                Futures::Income => "/dapi/v1/income",
+                Futures::BatchOrders => "/dapi/v1/batchOrders",
             },
-        })
+            API::Options(route) => match route {
+                Options::Ping => "/eapi/v1/ping",
+                Options::Time => "/eapi/v1/time",
+                Options::ExchangeInfo => "/eapi/v1/exchangeInfo",
+                Options::Depth => "/eapi/v1/depth",
+                Options::Klines => "/eapi/v1/klines",
+                Options::Mark => "/eapi/v1/mark",
+                Options::Order => "/eapi/v1/order",
+                Options::OpenOrders => "/eapi/v1/openOrders",
+                Options::AllOpenOrders => "/eapi/v1/allOpenOrders",
+                Options::Position => "/eapi/v1/position",
+                Options::MarginAccount => "/eapi/v1/marginAccount",
+                Options::UserDataStream => "/eapi/v1/listenKey",
+            },
+            API::Margin(route) => match route {
+                Margin::BorrowRepay => "/sapi/v1/margin/borrow-repay",
+                Margin::Order => "/sapi/v1/margin/order",
+                Margin::OpenOrders => "/sapi/v1/margin/openOrders",
+                Margin::Oco => "/sapi/v1/margin/order/oco",
+                Margin::MaxBorrowable => "/sapi/v1/margin/maxBorrowable",
+                Margin::MaxTransferable => "/sapi/v1/margin/maxTransferable",
+                Margin::InterestHistory => "/sapi/v1/margin/interestHistory",
+                Margin::IsolatedAccount => "/sapi/v1/margin/isolated/account",
+                Margin::UserDataStream => "/sapi/v1/userDataStream",
+                Margin::IsolatedUserDataStream => "/sapi/v1/userDataStream/isolated",
+            },
+        }))
     }
 }
 
@@ -181,6 +347,18 @@ pub trait Binance {
     ) -> Self;
 }
 
+/// Builds the futures clients against the COIN-M API (`/dapi`), where contracts are margined
+/// and settled in the base asset and quantities are in contracts.
+pub trait BinanceCoinFutures: Sized {
+    fn new_coin_futures(api_key: Option<String>, secret_key: Option<String>) -> Self {
+        Self::new_coin_futures_with_config(api_key, secret_key, &Config::default())
+    }
+
+    fn new_coin_futures_with_config(
+        api_key: Option<String>, secret_key: Option<String>, config: &Config,
+    ) -> Self;
+}
+
 impl Binance for General {
     fn new(api_key: Option<String>, secret_key: Option<String>) -> General {
         Self::new_with_config(api_key, secret_key, &Config::default())
@@ -190,7 +368,12 @@ impl Binance for General {
         api_key: Option<String>, secret_key: Option<String>, config: &Config,
     ) -> General {
         General {
-            client: Client::new(api_key, secret_key, config.rest_api_endpoint.clone()),
+            client: Client::new(
+                api_key,
+                secret_key,
+                config.rest_api_endpoint.clone(),
+                config,
+            ),
         }
     }
 }
@@ -204,8 +387,14 @@ impl Binance for Account {
         api_key: Option<String>, secret_key: Option<String>, config: &Config,
     ) -> Account {
         Account {
-            client: Client::new(api_key, secret_key, config.rest_api_endpoint.clone()),
+            client: Client::new(
+                api_key,
+                secret_key,
+                config.rest_api_endpoint.clone(),
+                config,
+            ),
             recv_window: config.recv_window,
+            validator: None,
         }
     }
 }
@@ -219,7 +408,32 @@ impl Binance for Savings {
         api_key: Option<String>, secret_key: Option<String>, config: &Config,
     ) -> Self {
         Self {
-            client: Client::new(api_key, secret_key, config.rest_api_endpoint.clone()),
+            client: Client::new(
+                api_key,
+                secret_key,
+                config.rest_api_endpoint.clone(),
+                config,
+            ),
+            recv_window: config.recv_window,
+        }
+    }
+}
+
+impl Binance for SubAccount {
+    fn new(api_key: Option<String>, secret_key: Option<String>) -> Self {
+        Self::new_with_config(api_key, secret_key, &Config::default())
+    }
+
+    fn new_with_config(
+        api_key: Option<String>, secret_key: Option<String>, config: &Config,
+    ) -> Self {
+        Self {
+            client: Client::new(
+                api_key,
+                secret_key,
+                config.rest_api_endpoint.clone(),
+                config,
+            ),
             recv_window: config.recv_window,
         }
     }
@@ -234,7 +448,12 @@ impl Binance for Market {
         api_key: Option<String>, secret_key: Option<String>, config: &Config,
     ) -> Market {
         Market {
-            client: Client::new(api_key, secret_key, config.rest_api_endpoint.clone()),
+            client: Client::new(
+                api_key,
+                secret_key,
+                config.rest_api_endpoint.clone(),
+                config,
+            ),
             recv_window: config.recv_window,
         }
     }
@@ -249,7 +468,12 @@ impl Binance for UserStream {
         api_key: Option<String>, secret_key: Option<String>, config: &Config,
     ) -> UserStream {
         UserStream {
-            client: Client::new(api_key, secret_key, config.rest_api_endpoint.clone()),
+            client: Client::new(
+                api_key,
+                secret_key,
+                config.rest_api_endpoint.clone(),
+                config,
+            ),
             recv_window: config.recv_window,
         }
     }
@@ -272,6 +496,7 @@ impl Binance for FuturesGeneral {
                 api_key,
                 secret_key,
                 config.futures_rest_api_endpoint.clone(),
+                config,
             ),
         }
     }
@@ -290,8 +515,10 @@ impl Binance for FuturesMarket {
                 api_key,
                 secret_key,
                 config.futures_rest_api_endpoint.clone(),
+                config,
             ),
             recv_window: config.recv_window,
+            contracts: PhantomData,
         }
     }
 }
@@ -309,8 +536,11 @@ impl Binance for FuturesAccount {
                 api_key,
                 secret_key,
                 config.futures_rest_api_endpoint.clone(),
+                config,
             ),
             recv_window: config.recv_window,
+            validator: None,
+            contracts: PhantomData,
         }
     }
 }
@@ -328,8 +558,480 @@ impl Binance for FuturesUserStream {
                 api_key,
                 secret_key,
                 config.futures_rest_api_endpoint.clone(),
+                config,
             ),
             recv_window: config.recv_window,
         }
     }
 }
+
+impl BinanceCoinFutures for FuturesGeneral {
+    fn new_coin_futures_with_config(
+        api_key: Option<String>, secret_key: Option<String>, config: &Config,
+    ) -> FuturesGeneral {
+        FuturesGeneral {
+            client: Client::new(
+                api_key,
+                secret_key,
+                config.coin_futures_rest_api_endpoint.clone(),
+                config,
+            )
+            .with_coin_futures(),
+        }
+    }
+}
+
+impl BinanceCoinFutures for FuturesMarket<CoinM> {
+    fn new_coin_futures_with_config(
+        api_key: Option<String>, secret_key: Option<String>, config: &Config,
+    ) -> Self {
+        Self {
+            client: Client::new(
+                api_key,
+                secret_key,
+                config.coin_futures_rest_api_endpoint.clone(),
+                config,
+            )
+            .with_coin_futures(),
+            recv_window: config.recv_window,
+            contracts: PhantomData,
+        }
+    }
+}
+
+impl BinanceCoinFutures for FuturesAccount<CoinM> {
+    fn new_coin_futures_with_config(
+        api_key: Option<String>, secret_key: Option<String>, config: &Config,
+    ) -> Self {
+        Self {
+            client: Client::new(
+                api_key,
+                secret_key,
+                config.coin_futures_rest_api_endpoint.clone(),
+                config,
+            )
+            .with_coin_futures(),
+            recv_window: config.recv_window,
+            validator: None,
+            contracts: PhantomData,
+        }
+    }
+}
+
+impl BinanceCoinFutures for FuturesUserStream {
+    fn new_coin_futures_with_config(
+        api_key: Option<String>, secret_key: Option<String>, config: &Config,
+    ) -> FuturesUserStream {
+        FuturesUserStream {
+            client: Client::new(
+                api_key,
+                secret_key,
+                config.coin_futures_rest_api_endpoint.clone(),
+                config,
+            )
+            .with_coin_futures(),
+            recv_window: config.recv_window,
+        }
+    }
+}
+
+// *****************************************************
+//              Binance Options API
+// *****************************************************
+
+impl Binance for OptionsGeneral {
+    fn new(api_key: Option<String>, secret_key: Option<String>) -> OptionsGeneral {
+        Self::new_with_config(api_key, secret_key, &Config::default())
+    }
+
+    fn new_with_config(
+        api_key: Option<String>, secret_key: Option<String>, config: &Config,
+    ) -> OptionsGeneral {
+        OptionsGeneral {
+            client: Client::new(
+                api_key,
+                secret_key,
+                config.options_rest_api_endpoint.clone(),
+                config,
+            ),
+        }
+    }
+}
+
+impl Binance for OptionsMarket {
+    fn new(api_key: Option<String>, secret_key: Option<String>) -> OptionsMarket {
+        Self::new_with_config(api_key, secret_key, &Config::default())
+    }
+
+    fn new_with_config(
+        api_key: Option<String>, secret_key: Option<String>, config: &Config,
+    ) -> OptionsMarket {
+        OptionsMarket {
+            client: Client::new(
+                api_key,
+                secret_key,
+                config.options_rest_api_endpoint.clone(),
+                config,
+            ),
+            recv_window: config.recv_window,
+        }
+    }
+}
+
+impl Binance for OptionsAccount {
+    fn new(api_key: Option<String>, secret_key: Option<String>) -> OptionsAccount {
+        Self::new_with_config(api_key, secret_key, &Config::default())
+    }
+
+    fn new_with_config(
+        api_key: Option<String>, secret_key: Option<String>, config: &Config,
+    ) -> OptionsAccount {
+        OptionsAccount {
+            client: Client::new(
+                api_key,
+                secret_key,
+                config.options_rest_api_endpoint.clone(),
+                config,
+            ),
+            recv_window: config.recv_window,
+        }
+    }
+}
+
+impl Binance for OptionsUserStream {
+    fn new(api_key: Option<String>, secret_key: Option<String>) -> OptionsUserStream {
+        Self::new_with_config(api_key, secret_key, &Config::default())
+    }
+
+    fn new_with_config(
+        api_key: Option<String>, secret_key: Option<String>, config: &Config,
+    ) -> OptionsUserStream {
+        OptionsUserStream {
+            client: Client::new(
+                api_key,
+                secret_key,
+                config.options_rest_api_endpoint.clone(),
+                config,
+            ),
+            recv_window: config.recv_window,
+        }
+    }
+}
+
+// *****************************************************
+//              Binance Margin API
+// *****************************************************
+
+impl Binance for MarginAccount {
+    fn new(api_key: Option<String>, secret_key: Option<String>) -> MarginAccount {
+        Self::new_with_config(api_key, secret_key, &Config::default())
+    }
+
+    fn new_with_config(
+        api_key: Option<String>, secret_key: Option<String>, config: &Config,
+    ) -> MarginAccount {
+        MarginAccount {
+            client: Client::new(
+                api_key,
+                secret_key,
+                config.rest_api_endpoint.clone(),
+                config,
+            ),
+            recv_window: config.recv_window,
+        }
+    }
+}
+
+impl Binance for MarginUserStream {
+    fn new(api_key: Option<String>, secret_key: Option<String>) -> MarginUserStream {
+        Self::new_with_config(api_key, secret_key, &Config::default())
+    }
+
+    fn new_with_config(
+        api_key: Option<String>, secret_key: Option<String>, config: &Config,
+    ) -> MarginUserStream {
+        MarginUserStream {
+            client: Client::new(
+                api_key,
+                secret_key,
+                config.rest_api_endpoint.clone(),
+                config,
+            ),
+            recv_window: config.recv_window,
+        }
+    }
+}
+
+// *****************************************************
+//              Binance Async API
+// *****************************************************
+
+#[cfg(feature = "async")]
+mod nonblocking {
+    use super::{Binance, BinanceCoinFutures};
+    use crate::config::Config;
+    use crate::nonblocking::account::Account;
+    use crate::nonblocking::client::Client;
+    use crate::nonblocking::futures::account::FuturesAccount;
+    use crate::nonblocking::futures::general::FuturesGeneral;
+    use crate::nonblocking::futures::market::FuturesMarket;
+    use crate::futures::model::CoinM;
+    use std::marker::PhantomData;
+    use crate::nonblocking::futures::userstream::FuturesUserStream;
+    use crate::nonblocking::general::General;
+    use crate::nonblocking::market::Market;
+    use crate::nonblocking::savings::Savings;
+    use crate::nonblocking::userstream::UserStream;
+
+    impl Binance for General {
+        fn new(api_key: Option<String>, secret_key: Option<String>) -> General {
+            Self::new_with_config(api_key, secret_key, &Config::default())
+        }
+
+        fn new_with_config(
+            api_key: Option<String>, secret_key: Option<String>, config: &Config,
+        ) -> General {
+            General {
+                client: Client::new(
+                    api_key,
+                    secret_key,
+                    config.rest_api_endpoint.clone(),
+                    config,
+                ),
+            }
+        }
+    }
+
+    impl Binance for Account {
+        fn new(api_key: Option<String>, secret_key: Option<String>) -> Account {
+            Self::new_with_config(api_key, secret_key, &Config::default())
+        }
+
+        fn new_with_config(
+            api_key: Option<String>, secret_key: Option<String>, config: &Config,
+        ) -> Account {
+            Account {
+                client: Client::new(
+                    api_key,
+                    secret_key,
+                    config.rest_api_endpoint.clone(),
+                    config,
+                ),
+                recv_window: config.recv_window,
+                validator: None,
+            }
+        }
+    }
+
+    impl Binance for Savings {
+        fn new(api_key: Option<String>, secret_key: Option<String>) -> Self {
+            Self::new_with_config(api_key, secret_key, &Config::default())
+        }
+
+        fn new_with_config(
+            api_key: Option<String>, secret_key: Option<String>, config: &Config,
+        ) -> Self {
+            Self {
+                client: Client::new(
+                    api_key,
+                    secret_key,
+                    config.rest_api_endpoint.clone(),
+                    config,
+                ),
+                recv_window: config.recv_window,
+            }
+        }
+    }
+
+    impl Binance for Market {
+        fn new(api_key: Option<String>, secret_key: Option<String>) -> Market {
+            Self::new_with_config(api_key, secret_key, &Config::default())
+        }
+
+        fn new_with_config(
+            api_key: Option<String>, secret_key: Option<String>, config: &Config,
+        ) -> Market {
+            Market {
+                client: Client::new(
+                    api_key,
+                    secret_key,
+                    config.rest_api_endpoint.clone(),
+                    config,
+                ),
+                recv_window: config.recv_window,
+            }
+        }
+    }
+
+    impl Binance for UserStream {
+        fn new(api_key: Option<String>, secret_key: Option<String>) -> UserStream {
+            Self::new_with_config(api_key, secret_key, &Config::default())
+        }
+
+        fn new_with_config(
+            api_key: Option<String>, secret_key: Option<String>, config: &Config,
+        ) -> UserStream {
+            UserStream {
+                client: Client::new(
+                    api_key,
+                    secret_key,
+                    config.rest_api_endpoint.clone(),
+                    config,
+                ),
+                recv_window: config.recv_window,
+            }
+        }
+    }
+
+    impl Binance for FuturesGeneral {
+        fn new(api_key: Option<String>, secret_key: Option<String>) -> FuturesGeneral {
+            Self::new_with_config(api_key, secret_key, &Config::default())
+        }
+
+        fn new_with_config(
+            api_key: Option<String>, secret_key: Option<String>, config: &Config,
+        ) -> FuturesGeneral {
+            FuturesGeneral {
+                client: Client::new(
+                    api_key,
+                    secret_key,
+                    config.futures_rest_api_endpoint.clone(),
+                    config,
+                ),
+            }
+        }
+    }
+
+    impl Binance for FuturesMarket {
+        fn new(api_key: Option<String>, secret_key: Option<String>) -> FuturesMarket {
+            Self::new_with_config(api_key, secret_key, &Config::default())
+        }
+
+        fn new_with_config(
+            api_key: Option<String>, secret_key: Option<String>, config: &Config,
+        ) -> FuturesMarket {
+            FuturesMarket {
+                client: Client::new(
+                    api_key,
+                    secret_key,
+                    config.futures_rest_api_endpoint.clone(),
+                    config,
+                ),
+                recv_window: config.recv_window,
+                contracts: PhantomData,
+            }
+        }
+    }
+
+    impl Binance for FuturesAccount {
+        fn new(api_key: Option<String>, secret_key: Option<String>) -> Self {
+            Self::new_with_config(api_key, secret_key, &Config::default())
+        }
+
+        fn new_with_config(
+            api_key: Option<String>, secret_key: Option<String>, config: &Config,
+        ) -> Self {
+            Self {
+                client: Client::new(
+                    api_key,
+                    secret_key,
+                    config.futures_rest_api_endpoint.clone(),
+                    config,
+                ),
+                recv_window: config.recv_window,
+                validator: None,
+                contracts: PhantomData,
+            }
+        }
+    }
+
+    impl Binance for FuturesUserStream {
+        fn new(api_key: Option<String>, secret_key: Option<String>) -> FuturesUserStream {
+            Self::new_with_config(api_key, secret_key, &Config::default())
+        }
+
+        fn new_with_config(
+            api_key: Option<String>, secret_key: Option<String>, config: &Config,
+        ) -> FuturesUserStream {
+            FuturesUserStream {
+                client: Client::new(
+                    api_key,
+                    secret_key,
+                    config.futures_rest_api_endpoint.clone(),
+                    config,
+                ),
+                recv_window: config.recv_window,
+            }
+        }
+    }
+
+    impl BinanceCoinFutures for FuturesGeneral {
+        fn new_coin_futures_with_config(
+            api_key: Option<String>, secret_key: Option<String>, config: &Config,
+        ) -> FuturesGeneral {
+            FuturesGeneral {
+                client: Client::new(
+                    api_key,
+                    secret_key,
+                    config.coin_futures_rest_api_endpoint.clone(),
+                    config,
+                )
+                .with_coin_futures(),
+            }
+        }
+    }
+
+    impl BinanceCoinFutures for FuturesMarket<CoinM> {
+        fn new_coin_futures_with_config(
+            api_key: Option<String>, secret_key: Option<String>, config: &Config,
+        ) -> Self {
+            Self {
+                client: Client::new(
+                    api_key,
+                    secret_key,
+                    config.coin_futures_rest_api_endpoint.clone(),
+                    config,
+                )
+                .with_coin_futures(),
+                recv_window: config.recv_window,
+                contracts: PhantomData,
+            }
+        }
+    }
+
+    impl BinanceCoinFutures for FuturesAccount<CoinM> {
+        fn new_coin_futures_with_config(
+            api_key: Option<String>, secret_key: Option<String>, config: &Config,
+        ) -> Self {
+            Self {
+                client: Client::new(
+                    api_key,
+                    secret_key,
+                    config.coin_futures_rest_api_endpoint.clone(),
+                    config,
+                )
+                .with_coin_futures(),
+                recv_window: config.recv_window,
+                validator: None,
+                contracts: PhantomData,
+            }
+        }
+    }
+
+    impl BinanceCoinFutures for FuturesUserStream {
+        fn new_coin_futures_with_config(
+            api_key: Option<String>, secret_key: Option<String>, config: &Config,
+        ) -> FuturesUserStream {
+            FuturesUserStream {
+                client: Client::new(
+                    api_key,
+                    secret_key,
+                    config.coin_futures_rest_api_endpoint.clone(),
+                    config,
+                )
+                .with_coin_futures(),
+                recv_window: config.recv_window,
+            }
+        }
+    }
+}
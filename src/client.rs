@@ -1,28 +1,42 @@
+use crate::errors::{BinanceContentError, Error, ErrorKind, Result};
 use error_chain::bail;
-use hex::encode as hex_encode;
-use hmac::{Hmac, Mac};
-use crate::errors::{BinanceContentError, ErrorKind, Result};
-use reqwest::StatusCode;
+use reqwest::{Method, StatusCode};
 use reqwest::blocking::Response;
 use reqwest::header::{HeaderMap, HeaderName, HeaderValue, USER_AGENT, CONTENT_TYPE};
-use sha2::Sha256;
 use serde::de::DeserializeOwned;
-use crate::api::API;
+use crate::api::{Futures, Options, Spot, API};
+use crate::config::Config;
+use crate::model::CancelReplaceFailure;
+use crate::signer::Signer;
+use crate::timesync::TimeSync;
+use crate::ratelimit::{retry_after, RateLimiter};
+use std::thread;
+use std::time::Duration;
 
 #[derive(Clone)]
 pub struct Client {
     api_key: String,
     secret_key: String,
     host: String,
+    rate_limiter: RateLimiter,
+    time_sync: TimeSync,
+    signer: Signer,
+    coin_futures: bool,
     inner_client: reqwest::blocking::Client,
 }
 
 impl Client {
-    pub fn new(api_key: Option<String>, secret_key: Option<String>, host: String) -> Self {
+    pub fn new(
+        api_key: Option<String>, secret_key: Option<String>, host: String, config: &Config,
+    ) -> Self {
         Client {
             api_key: api_key.unwrap_or_default(),
             secret_key: secret_key.unwrap_or_default(),
             host,
+            rate_limiter: config.rate_limiter.clone(),
+            time_sync: config.time_sync.clone(),
+            signer: config.signer.clone(),
+            coin_futures: false,
             inner_client: reqwest::blocking::Client::builder()
                 .pool_idle_timeout(None)
                 .build()
@@ -30,51 +44,59 @@ impl Client {
         }
     }
 
+    /// Sends the `API::Futures` routes to the COIN-M API (`/dapi`) instead of USDⓈ-M (`/fapi`).
+    pub fn with_coin_futures(mut self) -> Self {
+        self.coin_futures = true;
+        self
+    }
+
+    pub fn is_coin_futures(&self) -> bool {
+        self.coin_futures
+    }
+
     pub fn get_signed<T: DeserializeOwned>(
         &self, endpoint: API, request: Option<String>,
     ) -> Result<T> {
-        let url = self.sign_request(endpoint, request);
-        let client = &self.inner_client;
-        let response = client
-            .get(url.as_str())
-            .headers(self.build_headers(true)?)
-            .send()?;
-
-        self.handler(response)
+        self.signed_request(Method::GET, endpoint, request, false)
     }
 
     pub fn post_signed<T: DeserializeOwned>(&self, endpoint: API, request: String) -> Result<T> {
-        let url = self.sign_request(endpoint, Some(request));
-        let client = &self.inner_client;
-        let response = client
-            .post(url.as_str())
-            .headers(self.build_headers(true)?)
-            .send()?;
+        self.signed_request(Method::POST, endpoint, Some(request), false)
+    }
 
-        self.handler(response)
+    /// `post_signed` for requests that place an order, which also wait for the `ORDERS` limits.
+    pub fn post_signed_order<T: DeserializeOwned>(
+        &self, endpoint: API, request: String,
+    ) -> Result<T> {
+        self.signed_request(Method::POST, endpoint, Some(request), true)
     }
 
     pub fn delete_signed<T: DeserializeOwned>(
         &self, endpoint: API, request: Option<String>,
     ) -> Result<T> {
-        let url = self.sign_request(endpoint, request);
-        let client = &self.inner_client;
-        let response = client
-            .delete(url.as_str())
-            .headers(self.build_headers(true)?)
-            .send()?;
+        self.signed_request(Method::DELETE, endpoint, request, false)
+    }
 
-        self.handler(response)
+    pub fn put_signed<T: DeserializeOwned>(&self, endpoint: API, request: String) -> Result<T> {
+        self.signed_request(Method::PUT, endpoint, Some(request), false)
+    }
+
+    /// `put_signed` for requests that count as an order, which also wait for the `ORDERS` limits.
+    pub fn put_signed_order<T: DeserializeOwned>(
+        &self, endpoint: API, request: String,
+    ) -> Result<T> {
+        self.signed_request(Method::PUT, endpoint, Some(request), true)
     }
 
     pub fn get<T: DeserializeOwned>(&self, endpoint: API, request: Option<String>) -> Result<T> {
-        let mut url: String = format!("{}{}", self.host, String::from(endpoint));
+        let mut url: String = format!("{}{}", self.host, String::try_from(self.route(endpoint)?)?);
         if let Some(request) = request {
             if !request.is_empty() {
                 url.push_str(format!("?{}", request).as_str());
             }
         }
 
+        self.throttle(false)?;
         let client = &self.inner_client;
         let response = client.get(url.as_str()).send()?;
 
@@ -82,8 +104,9 @@ impl Client {
     }
 
     pub fn post<T: DeserializeOwned>(&self, endpoint: API) -> Result<T> {
-        let url: String = format!("{}{}", self.host, String::from(endpoint));
+        let url: String = format!("{}{}", self.host, String::try_from(self.route(endpoint)?)?);
 
+        self.throttle(false)?;
         let client = &self.inner_client;
         let response = client
             .post(url.as_str())
@@ -94,9 +117,10 @@ impl Client {
     }
 
     pub fn put<T: DeserializeOwned>(&self, endpoint: API, listen_key: &str) -> Result<T> {
-        let url: String = format!("{}{}", self.host, String::from(endpoint));
+        let url: String = format!("{}{}", self.host, String::try_from(self.route(endpoint)?)?);
         let data: String = format!("listenKey={}", listen_key);
 
+        self.throttle(false)?;
         let client = &self.inner_client;
         let response = client
             .put(url.as_str())
@@ -108,9 +132,10 @@ impl Client {
     }
 
     pub fn delete<T: DeserializeOwned>(&self, endpoint: API, listen_key: &str) -> Result<T> {
-        let url: String = format!("{}{}", self.host, String::from(endpoint));
+        let url: String = format!("{}{}", self.host, String::try_from(self.route(endpoint)?)?);
         let data: String = format!("listenKey={}", listen_key);
 
+        self.throttle(false)?;
         let client = &self.inner_client;
         let response = client
             .delete(url.as_str())
@@ -121,60 +146,171 @@ impl Client {
         self.handler(response)
     }
 
-    // Request must be signed
-    fn sign_request(&self, endpoint: API, request: Option<String>) -> String {
-        if let Some(request) = request {
-            let mut signed_key =
-                Hmac::<Sha256>::new_from_slice(self.secret_key.as_bytes()).unwrap();
-            signed_key.update(request.as_bytes());
-            let signature = hex_encode(signed_key.finalize().into_bytes());
-            let request_body: String = format!("{}&signature={}", request, signature);
-            format!("{}{}?{}", self.host, String::from(endpoint), request_body)
-        } else {
-            let signed_key = Hmac::<Sha256>::new_from_slice(self.secret_key.as_bytes()).unwrap();
-            let signature = hex_encode(signed_key.finalize().into_bytes());
-            let request_body: String = format!("&signature={}", signature);
-            format!("{}{}?{}", self.host, String::from(endpoint), request_body)
+    /// API key request with `request` as the query string, for the listen key endpoints that
+    /// take more than the `listenKey`, e.g. the `symbol` of isolated margin streams.
+    pub fn request_with_api_key<T: DeserializeOwned>(
+        &self, method: Method, endpoint: API, request: String,
+    ) -> Result<T> {
+        let url: String = format!(
+            "{}{}?{}",
+            self.host,
+            String::try_from(self.route(endpoint)?)?,
+            request
+        );
+
+        self.throttle(false)?;
+        let client = &self.inner_client;
+        let response = client
+            .request(method, url.as_str())
+            .headers(self.build_headers(false)?)
+            .send()?;
+
+        self.handler(response)
+    }
+
+    // Throttles before signing, so the timestamp is not left behind by the wait
+    fn signed_request<T: DeserializeOwned>(
+        &self, method: Method, endpoint: API, request: Option<String>, orders: bool,
+    ) -> Result<T> {
+        let waited = self.throttle(orders)?;
+        let url = self.sign_request(endpoint, request, waited)?;
+        let client = &self.inner_client;
+        let response = client
+            .request(method, url.as_str())
+            .headers(self.build_headers(true)?)
+            .send()?;
+
+        self.handler(response)
+    }
+
+    // Request must be signed; `waited` is how long throttling delayed it after it was stamped
+    fn sign_request(
+        &self, endpoint: API, request: Option<String>, waited: Duration,
+    ) -> Result<String> {
+        let endpoint = self.route(endpoint)?;
+        if self.time_sync.needs_sync() {
+            self.time_sync
+                .sync(|| self.get(time_endpoint(&endpoint), None))?;
         }
+        let request = request.map(|request| self.time_sync.apply(request, waited));
+
+        sign_request(
+            &self.host,
+            &self.secret_key,
+            &self.signer,
+            endpoint,
+            request,
+        )
+    }
+
+    fn route(&self, endpoint: API) -> Result<API> {
+        route(endpoint, self.coin_futures)
     }
 
     fn build_headers(&self, content_type: bool) -> Result<HeaderMap> {
-        let mut custom_headers = HeaderMap::new();
-
-        custom_headers.insert(USER_AGENT, HeaderValue::from_static("binance-rs"));
-        if content_type {
-            custom_headers.insert(
-                CONTENT_TYPE,
-                HeaderValue::from_static("application/x-www-form-urlencoded"),
-            );
+        build_headers(&self.api_key, content_type)
+    }
+
+    fn throttle(&self, orders: bool) -> Result<Duration> {
+        match self.rate_limiter.delay(orders)? {
+            Some(delay) => {
+                thread::sleep(delay);
+                Ok(delay)
+            }
+            None => Ok(Duration::ZERO),
+        }
+    }
+
+    fn handler<T: DeserializeOwned>(&self, response: Response) -> Result<T> {
+        self.rate_limiter.update(response.headers());
+        let status = response.status();
+        if status == StatusCode::OK {
+            return Ok(response.json::<T>()?);
+        }
+        let headers = response.headers().clone();
+        let body = response.text()?;
+
+        Err(response_error(status, headers, &body, &self.rate_limiter))
+    }
+}
+
+pub(crate) fn sign_request(
+    host: &str, secret_key: &str, signer: &Signer, endpoint: API, request: Option<String>,
+) -> Result<String> {
+    let request = request.unwrap_or_default();
+    let signature: String =
+        url::form_urlencoded::byte_serialize(signer.sign(secret_key, &request).as_bytes())
+            .collect();
+    let request_body: String = format!("{}&signature={}", request, signature);
+    Ok(format!(
+        "{}{}?{}",
+        host,
+        String::try_from(endpoint)?,
+        request_body
+    ))
+}
+
+// Futures routes of a COIN-M client go to `/dapi`, which lacks some USDⓈ-M routes
+pub(crate) fn route(endpoint: API, coin_futures: bool) -> Result<API> {
+    match endpoint {
+        API::Futures(route) if coin_futures && route.usdm_only() => {
+            bail!("Only offered by USDⓈ-M futures")
         }
+        API::CoinFutures(route) if route.usdm_only() => bail!("Only offered by USDⓈ-M futures"),
+        API::Futures(route) if coin_futures => Ok(API::CoinFutures(route)),
+        endpoint => Ok(endpoint),
+    }
+}
+
+// Server time of the API an endpoint belongs to
+pub(crate) fn time_endpoint(endpoint: &API) -> API {
+    match endpoint {
+        API::Futures(_) => API::Futures(Futures::Time),
+        API::CoinFutures(_) => API::CoinFutures(Futures::Time),
+        API::Options(_) => API::Options(Options::Time),
+        _ => API::Spot(Spot::Time),
+    }
+}
+
+pub(crate) fn build_headers(api_key: &str, content_type: bool) -> Result<HeaderMap> {
+    let mut custom_headers = HeaderMap::new();
+
+    custom_headers.insert(USER_AGENT, HeaderValue::from_static("binance-rs"));
+    if content_type {
         custom_headers.insert(
-            HeaderName::from_static("x-mbx-apikey"),
-            HeaderValue::from_str(self.api_key.as_str())?,
+            CONTENT_TYPE,
+            HeaderValue::from_static("application/x-www-form-urlencoded"),
         );
-
-        Ok(custom_headers)
     }
+    custom_headers.insert(
+        HeaderName::from_static("x-mbx-apikey"),
+        HeaderValue::from_str(api_key)?,
+    );
 
-    fn handler<T: DeserializeOwned>(&self, response: Response) -> Result<T> {
-        match response.status() {
-            StatusCode::OK => Ok(response.json::<T>()?),
-            StatusCode::INTERNAL_SERVER_ERROR => {
-                bail!("Internal Server Error");
-            }
-            StatusCode::SERVICE_UNAVAILABLE => {
-                bail!("Service Unavailable");
-            }
-            StatusCode::UNAUTHORIZED => {
-                bail!("Unauthorized");
-            }
-            StatusCode::BAD_REQUEST => {
-                let error: BinanceContentError = response.json()?;
+    Ok(custom_headers)
+}
 
-                Err(ErrorKind::BinanceError(error).into())
+// Error of a non-200 response; 4xx bodies carry a Binance error code
+pub(crate) fn response_error(
+    status: StatusCode, headers: HeaderMap, body: &str, rate_limiter: &RateLimiter,
+) -> Error {
+    match status {
+        StatusCode::TOO_MANY_REQUESTS | StatusCode::IM_A_TEAPOT => {
+            let retry_after = rate_limiter.ban(status.as_u16(), &headers);
+            ErrorKind::RateLimited(status.as_u16(), retry_after, headers).into()
+        }
+        status if status.is_server_error() => {
+            let retry_after = retry_after(&headers);
+            ErrorKind::ServerError(status.as_u16(), retry_after, headers).into()
+        }
+        status => {
+            // Failed cancel-replaces also report what happened to either order
+            if let Ok(failure) = serde_json::from_str::<CancelReplaceFailure>(body) {
+                return ErrorKind::CancelReplaceFailed(failure).into();
             }
-            s => {
-                bail!(format!("Received response: {:?}", s));
+            match serde_json::from_str::<BinanceContentError>(body) {
+                Ok(error) => ErrorKind::BinanceError(error).into(),
+                Err(_) => ErrorKind::HttpError(status.as_u16(), body.into(), headers).into(),
             }
         }
     }
@@ -1,12 +1,30 @@
+use crate::ratelimit::RateLimiter;
+use crate::signer::Signer;
+use crate::timesync::TimeSync;
+
+use std::time::Duration;
+
 #[derive(Clone, Debug)]
 pub struct Config {
     pub rest_api_endpoint: String,
     pub ws_endpoint: String,
+    pub ws_api_endpoint: String,
+    pub ws_api_timeout: Duration,
 
     pub futures_rest_api_endpoint: String,
     pub futures_ws_endpoint: String,
 
+    pub coin_futures_rest_api_endpoint: String,
+    pub coin_futures_ws_endpoint: String,
+
+    pub options_rest_api_endpoint: String,
+    pub options_ws_endpoint: String,
+
     pub recv_window: u64,
+
+    pub rate_limiter: RateLimiter,
+    pub time_sync: TimeSync,
+    pub signer: Signer,
 }
 
 impl Default for Config {
@@ -14,11 +32,23 @@ impl Default for Config {
         Self {
             rest_api_endpoint: "https://api.binance.com".into(),
             ws_endpoint: "wss://stream.binance.com/ws".into(),
+            ws_api_endpoint: "wss://ws-api.binance.com:443/ws-api/v3".into(),
+            ws_api_timeout: Duration::from_secs(10),
 
             futures_rest_api_endpoint: "https://fapi.binance.com".into(),
             futures_ws_endpoint: "wss://fstream.binance.com/ws".into(),
 
+            coin_futures_rest_api_endpoint: "https://dapi.binance.com".into(),
+            coin_futures_ws_endpoint: "wss://dstream.binance.com/ws".into(),
+
+            options_rest_api_endpoint: "https://eapi.binance.com".into(),
+            options_ws_endpoint: "wss://nbstream.binance.com/eoptions/ws".into(),
+
             recv_window: 5000,
+
+            rate_limiter: RateLimiter::default(),
+            time_sync: TimeSync::default(),
+            signer: Signer::default(),
         }
     }
 }
@@ -28,8 +58,11 @@ impl Config {
         Self::default()
             .set_rest_api_endpoint("https://testnet.binance.vision")
             .set_ws_endpoint("wss://testnet.binance.vision/ws")
+            .set_ws_api_endpoint("wss://testnet.binance.vision/ws-api/v3")
             .set_futures_rest_api_endpoint("https://testnet.binancefuture.com")
             .set_futures_ws_endpoint("https://testnet.binancefuture.com/ws")
+            .set_coin_futures_rest_api_endpoint("https://testnet.binancefuture.com")
+            .set_coin_futures_ws_endpoint("wss://dstream.binancefuture.com/ws")
     }
 
     pub fn set_rest_api_endpoint<T: Into<String>>(mut self, rest_api_endpoint: T) -> Self {
@@ -41,6 +74,18 @@ impl Config {
         self.ws_endpoint = ws_endpoint.into();
         self
     }
+
+    pub fn set_ws_api_endpoint<T: Into<String>>(mut self, ws_api_endpoint: T) -> Self {
+        self.ws_api_endpoint = ws_api_endpoint.into();
+        self
+    }
+
+    /// How long `WsApi` waits for the response to a request before failing.
+    pub fn set_ws_api_timeout(mut self, ws_api_timeout: Duration) -> Self {
+        self.ws_api_timeout = ws_api_timeout;
+        self
+    }
+
     pub fn set_futures_rest_api_endpoint<T: Into<String>>(
         mut self, futures_rest_api_endpoint: T,
     ) -> Self {
@@ -53,8 +98,54 @@ impl Config {
         self
     }
 
+    pub fn set_coin_futures_rest_api_endpoint<T: Into<String>>(
+        mut self, coin_futures_rest_api_endpoint: T,
+    ) -> Self {
+        self.coin_futures_rest_api_endpoint = coin_futures_rest_api_endpoint.into();
+        self
+    }
+
+    pub fn set_coin_futures_ws_endpoint<T: Into<String>>(
+        mut self, coin_futures_ws_endpoint: T,
+    ) -> Self {
+        self.coin_futures_ws_endpoint = coin_futures_ws_endpoint.into();
+        self
+    }
+
+    pub fn set_options_rest_api_endpoint<T: Into<String>>(
+        mut self, options_rest_api_endpoint: T,
+    ) -> Self {
+        self.options_rest_api_endpoint = options_rest_api_endpoint.into();
+        self
+    }
+
+    pub fn set_options_ws_endpoint<T: Into<String>>(mut self, options_ws_endpoint: T) -> Self {
+        self.options_ws_endpoint = options_ws_endpoint.into();
+        self
+    }
+
     pub fn set_recv_window(mut self, recv_window: u64) -> Self {
         self.recv_window = recv_window;
         self
     }
+
+    /// Shares request weight and order count tracking between the clients built from this config.
+    pub fn set_rate_limiter(mut self, rate_limiter: RateLimiter) -> Self {
+        self.rate_limiter = rate_limiter;
+        self
+    }
+
+    /// Applies the server time offset to the signed requests of the clients built from this
+    /// config.
+    pub fn set_time_sync(mut self, time_sync: TimeSync) -> Self {
+        self.time_sync = time_sync;
+        self
+    }
+
+    /// Signs the requests of the clients built from this config with an Ed25519 or RSA key
+    /// instead of HMAC.
+    pub fn set_signer(mut self, signer: Signer) -> Self {
+        self.signer = signer;
+        self
+    }
 }
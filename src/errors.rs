@@ -1,12 +1,185 @@
 use serde::Deserialize;
 use error_chain::error_chain;
+use reqwest::header::HeaderMap;
 
-#[derive(Debug, Deserialize)]
+use crate::model::CancelReplaceFailure;
+use crate::validation::FilterViolation;
+use std::time::Duration;
+
+#[derive(Debug, Deserialize, Clone)]
 pub struct BinanceContentError {
     pub code: i16,
     pub msg: String,
 }
 
+impl BinanceContentError {
+    pub fn error_code(&self) -> BinanceErrorCode {
+        BinanceErrorCode::from(self.code)
+    }
+}
+
+/// Error codes returned by the exchange in the `code` field of an error response.
+///
+/// Codes without a variant are kept as `Other`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum BinanceErrorCode {
+    /// -1000 An unknown error occurred while processing the request.
+    Unknown,
+    /// -1001 Internal error; unable to process your request. Please try again.
+    Disconnected,
+    /// -1002 You are not authorized to execute this request.
+    Unauthorized,
+    /// -1003 Too many requests queued or request weight exceeded.
+    TooManyRequests,
+    /// -1006 An unexpected response was received from the message bus.
+    UnexpectedResponse,
+    /// -1007 Timeout waiting for response from backend server.
+    Timeout,
+    /// -1008 Server is currently overloaded with other requests.
+    ServerBusy,
+    /// -1013 The request was rejected by a symbol filter.
+    InvalidMessage,
+    /// -1014 Unsupported order combination.
+    UnknownOrderComposition,
+    /// -1015 Too many new orders.
+    TooManyOrders,
+    /// -1016 This service is no longer available.
+    ServiceShuttingDown,
+    /// -1020 This operation is not supported.
+    UnsupportedOperation,
+    /// -1021 Timestamp for this request is outside of the recvWindow.
+    InvalidTimestamp,
+    /// -1022 Signature for this request is not valid.
+    InvalidSignature,
+    /// -1100 Illegal characters found in a parameter.
+    IllegalChars,
+    /// -1101 Too many parameters sent for this endpoint.
+    TooManyParameters,
+    /// -1102 A mandatory parameter was not sent, was empty/null, or malformed.
+    MandatoryParamEmptyOrMalformed,
+    /// -1103 An unknown parameter was sent.
+    UnknownParam,
+    /// -1111 Precision is over the maximum defined for this asset.
+    BadPrecision,
+    /// -1116 Invalid orderType.
+    InvalidOrderType,
+    /// -1117 Invalid side.
+    InvalidSide,
+    /// -1121 Invalid symbol.
+    BadSymbol,
+    /// -1125 This listenKey does not exist.
+    InvalidListenKey,
+    /// -2010 New order rejected, e.g. for an insufficient balance.
+    NewOrderRejected,
+    /// -2011 Cancel rejected, e.g. for an unknown order.
+    CancelRejected,
+    /// -2013 Order does not exist.
+    NoSuchOrder,
+    /// -2014 API-key format invalid.
+    BadApiKeyFormat,
+    /// -2015 Invalid API-key, IP, or permissions for action.
+    RejectedApiKey,
+    /// -2019 Margin is insufficient.
+    MarginInsufficient,
+    /// -2021 Order cancel-replace partially failed.
+    CancelReplacePartiallyFailed,
+    /// -2022 Order cancel-replace failed.
+    CancelReplaceFailed,
+    Other(i16),
+}
+
+impl BinanceErrorCode {
+    pub fn code(&self) -> i16 {
+        match self {
+            BinanceErrorCode::Unknown => -1000,
+            BinanceErrorCode::Disconnected => -1001,
+            BinanceErrorCode::Unauthorized => -1002,
+            BinanceErrorCode::TooManyRequests => -1003,
+            BinanceErrorCode::UnexpectedResponse => -1006,
+            BinanceErrorCode::Timeout => -1007,
+            BinanceErrorCode::ServerBusy => -1008,
+            BinanceErrorCode::InvalidMessage => -1013,
+            BinanceErrorCode::UnknownOrderComposition => -1014,
+            BinanceErrorCode::TooManyOrders => -1015,
+            BinanceErrorCode::ServiceShuttingDown => -1016,
+            BinanceErrorCode::UnsupportedOperation => -1020,
+            BinanceErrorCode::InvalidTimestamp => -1021,
+            BinanceErrorCode::InvalidSignature => -1022,
+            BinanceErrorCode::IllegalChars => -1100,
+            BinanceErrorCode::TooManyParameters => -1101,
+            BinanceErrorCode::MandatoryParamEmptyOrMalformed => -1102,
+            BinanceErrorCode::UnknownParam => -1103,
+            BinanceErrorCode::BadPrecision => -1111,
+            BinanceErrorCode::InvalidOrderType => -1116,
+            BinanceErrorCode::InvalidSide => -1117,
+            BinanceErrorCode::BadSymbol => -1121,
+            BinanceErrorCode::InvalidListenKey => -1125,
+            BinanceErrorCode::NewOrderRejected => -2010,
+            BinanceErrorCode::CancelRejected => -2011,
+            BinanceErrorCode::NoSuchOrder => -2013,
+            BinanceErrorCode::BadApiKeyFormat => -2014,
+            BinanceErrorCode::RejectedApiKey => -2015,
+            BinanceErrorCode::MarginInsufficient => -2019,
+            BinanceErrorCode::CancelReplacePartiallyFailed => -2021,
+            BinanceErrorCode::CancelReplaceFailed => -2022,
+            BinanceErrorCode::Other(code) => *code,
+        }
+    }
+
+    /// Whether the same request can succeed when sent again, after a backoff or, for
+    /// `InvalidTimestamp`, once the clock is back in sync.
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            BinanceErrorCode::Disconnected
+                | BinanceErrorCode::TooManyRequests
+                | BinanceErrorCode::UnexpectedResponse
+                | BinanceErrorCode::Timeout
+                | BinanceErrorCode::ServerBusy
+                | BinanceErrorCode::InvalidTimestamp
+        )
+    }
+}
+
+impl From<i16> for BinanceErrorCode {
+    fn from(code: i16) -> Self {
+        match code {
+            -1000 => BinanceErrorCode::Unknown,
+            -1001 => BinanceErrorCode::Disconnected,
+            -1002 => BinanceErrorCode::Unauthorized,
+            -1003 => BinanceErrorCode::TooManyRequests,
+            -1006 => BinanceErrorCode::UnexpectedResponse,
+            -1007 => BinanceErrorCode::Timeout,
+            -1008 => BinanceErrorCode::ServerBusy,
+            -1013 => BinanceErrorCode::InvalidMessage,
+            -1014 => BinanceErrorCode::UnknownOrderComposition,
+            -1015 => BinanceErrorCode::TooManyOrders,
+            -1016 => BinanceErrorCode::ServiceShuttingDown,
+            -1020 => BinanceErrorCode::UnsupportedOperation,
+            -1021 => BinanceErrorCode::InvalidTimestamp,
+            -1022 => BinanceErrorCode::InvalidSignature,
+            -1100 => BinanceErrorCode::IllegalChars,
+            -1101 => BinanceErrorCode::TooManyParameters,
+            -1102 => BinanceErrorCode::MandatoryParamEmptyOrMalformed,
+            -1103 => BinanceErrorCode::UnknownParam,
+            -1111 => BinanceErrorCode::BadPrecision,
+            -1116 => BinanceErrorCode::InvalidOrderType,
+            -1117 => BinanceErrorCode::InvalidSide,
+            -1121 => BinanceErrorCode::BadSymbol,
+            -1125 => BinanceErrorCode::InvalidListenKey,
+            -2010 => BinanceErrorCode::NewOrderRejected,
+            -2011 => BinanceErrorCode::CancelRejected,
+            -2013 => BinanceErrorCode::NoSuchOrder,
+            -2014 => BinanceErrorCode::BadApiKeyFormat,
+            -2015 => BinanceErrorCode::RejectedApiKey,
+            -2019 => BinanceErrorCode::MarginInsufficient,
+            -2021 => BinanceErrorCode::CancelReplacePartiallyFailed,
+            -2022 => BinanceErrorCode::CancelReplaceFailed,
+            code => BinanceErrorCode::Other(code),
+        }
+    }
+}
+
 error_chain! {
     errors {
         BinanceError(response: BinanceContentError)
@@ -15,6 +188,31 @@ error_chain! {
             description("invalid Vec for Kline"),
             display("{} at {} is missing", name, index),
         }
+
+        RateLimited(status: u16, retry_after: Option<Duration>, headers: HeaderMap) {
+            description("request rate limit exceeded"),
+            display("Rate limited with status {}, retry after {:?}", status, retry_after),
+        }
+
+        ServerError(status: u16, retry_after: Option<Duration>, headers: HeaderMap) {
+            description("server error"),
+            display("Server error with status {}", status),
+        }
+
+        HttpError(status: u16, body: String, headers: HeaderMap) {
+            description("unexpected response status"),
+            display("Received response with status {}: {}", status, body),
+        }
+
+        CancelReplaceFailed(failure: CancelReplaceFailure) {
+            description("cancel-replace did not cancel and place both orders"),
+            display("{}: cancel {}, new order {}", failure.msg, failure.data.cancel_result, failure.data.new_order_result),
+        }
+
+        FilterError(violation: FilterViolation) {
+            description("order rejected by an exchange filter"),
+            display("{}", violation),
+        }
      }
 
     foreign_links {
@@ -28,3 +226,47 @@ error_chain! {
         TimestampError(std::time::SystemTimeError);
     }
 }
+
+impl Error {
+    /// Error code of a `BinanceError`.
+    pub fn binance_code(&self) -> Option<BinanceErrorCode> {
+        match &self.0 {
+            ErrorKind::BinanceError(response) => Some(response.error_code()),
+            ErrorKind::CancelReplaceFailed(failure) => Some(BinanceErrorCode::from(failure.code)),
+            _ => None,
+        }
+    }
+
+    /// Whether the request can be sent again: rate limits, 5xx responses, retryable
+    /// error codes and timed out or failed connections.
+    ///
+    /// A 503 can mean the request was executed, so check the order status before placing it
+    /// again.
+    pub fn is_retryable(&self) -> bool {
+        match &self.0 {
+            ErrorKind::BinanceError(response) => response.error_code().is_retryable(),
+            ErrorKind::RateLimited(..) | ErrorKind::ServerError(..) => true,
+            ErrorKind::ReqError(error) => error.is_timeout() || error.is_connect(),
+            _ => false,
+        }
+    }
+
+    /// How long to wait before retrying, from the `Retry-After` header.
+    pub fn retry_after(&self) -> Option<Duration> {
+        match &self.0 {
+            ErrorKind::RateLimited(_, retry_after, _)
+            | ErrorKind::ServerError(_, retry_after, _) => *retry_after,
+            _ => None,
+        }
+    }
+
+    /// Response headers of an HTTP error.
+    pub fn headers(&self) -> Option<&HeaderMap> {
+        match &self.0 {
+            ErrorKind::RateLimited(_, _, headers)
+            | ErrorKind::ServerError(_, _, headers)
+            | ErrorKind::HttpError(_, _, headers) => Some(headers),
+            _ => None,
+        }
+    }
+}
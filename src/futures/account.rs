@@ -1,22 +1,55 @@
 use std::collections::BTreeMap;
 use std::fmt::Display;
-use crate::util::build_signed_request;
-use crate::errors::Result;
+use std::marker::PhantomData;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+use error_chain::bail;
+use serde::{Deserialize, Serialize};
+use crate::util::{build_signed_request, encode, insert_time_range};
+use crate::errors::{BinanceContentError, Error, Result};
 use crate::client::Client;
+use crate::validation::OrderValidator;
+use crate::websockets::sleep_while_running;
 use crate::api::{API, Futures};
-use crate::model::Empty;
+use crate::model::{Empty, IntoNumber, Number};
 use crate::account::OrderSide;
-use crate::futures::model::{Order, TradeHistory};
 
 use super::model::{
-    ChangeLeverageResponse, Transaction, CanceledOrder, PositionRisk, AccountBalance,
-    AccountInformation,
+    CountdownCancelAll, FuturesContracts, LeverageBracket, OneOrMany, OrderAmendment,
+    PositionSizing, UsdM,
 };
 
 #[derive(Clone)]
-pub struct FuturesAccount {
+pub struct FuturesAccount<M = UsdM> {
     pub client: Client,
     pub recv_window: u64,
+    /// Checks custom orders against the exchange filters before they are sent.
+    pub validator: Option<OrderValidator>,
+    pub(crate) contracts: PhantomData<M>,
+}
+
+/// Outcome of a single entry of a batch request.
+pub type BatchResult<T> = std::result::Result<T, BinanceContentError>;
+
+const MAX_BATCH_ORDERS: usize = 5;
+const MAX_BATCH_CANCELS: usize = 10;
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+pub(crate) enum BatchResponse<T> {
+    Error(BinanceContentError),
+    Success(T),
+}
+
+impl<T> BatchResponse<T> {
+    pub(crate) fn into_result(self) -> BatchResult<T> {
+        match self {
+            Self::Error(error) => Err(error),
+            Self::Success(value) => Ok(value),
+        }
+    }
 }
 
 pub enum ContractType {
@@ -112,19 +145,19 @@ impl Display for TimeInForce {
     }
 }
 
-struct OrderRequest {
+pub(crate) struct OrderRequest {
     pub symbol: String,
     pub side: OrderSide,
     pub position_side: Option<PositionSide>,
     pub order_type: OrderType,
     pub time_in_force: Option<TimeInForce>,
-    pub qty: Option<f64>,
+    pub qty: Option<Number>,
     pub reduce_only: Option<bool>,
-    pub price: Option<f64>,
-    pub stop_price: Option<f64>,
+    pub price: Option<Number>,
+    pub stop_price: Option<Number>,
     pub close_position: Option<bool>,
-    pub activation_price: Option<f64>,
-    pub callback_rate: Option<f64>,
+    pub activation_price: Option<Number>,
+    pub callback_rate: Option<Number>,
     pub working_type: Option<WorkingType>,
     pub price_protect: Option<f64>,
 }
@@ -135,17 +168,69 @@ pub struct CustomOrderRequest {
     pub position_side: Option<PositionSide>,
     pub order_type: OrderType,
     pub time_in_force: Option<TimeInForce>,
-    pub qty: Option<f64>,
+    pub qty: Option<Number>,
     pub reduce_only: Option<bool>,
-    pub price: Option<f64>,
-    pub stop_price: Option<f64>,
+    pub price: Option<Number>,
+    pub stop_price: Option<Number>,
     pub close_position: Option<bool>,
-    pub activation_price: Option<f64>,
-    pub callback_rate: Option<f64>,
+    pub activation_price: Option<Number>,
+    pub callback_rate: Option<Number>,
     pub working_type: Option<WorkingType>,
     pub price_protect: Option<f64>,
 }
 
+impl OrderRequest {
+    pub(crate) fn validate(&mut self, validator: &OrderValidator) -> Result<()> {
+        let market = matches!(
+            self.order_type,
+            OrderType::Market
+                | OrderType::StopMarket
+                | OrderType::TakeProfitMarket
+                | OrderType::TrailingStopMarket
+        );
+        let checked =
+            validator.check_order(&self.symbol, market, self.price, self.stop_price, self.qty)?;
+
+        self.price = checked.price;
+        self.stop_price = checked.stop_price;
+        self.qty = checked.qty;
+        Ok(())
+    }
+}
+
+impl From<CustomOrderRequest> for OrderRequest {
+    fn from(order_request: CustomOrderRequest) -> Self {
+        OrderRequest {
+            symbol: order_request.symbol,
+            side: order_request.side,
+            position_side: order_request.position_side,
+            order_type: order_request.order_type,
+            time_in_force: order_request.time_in_force,
+            qty: order_request.qty,
+            reduce_only: order_request.reduce_only,
+            price: order_request.price,
+            stop_price: order_request.stop_price,
+            close_position: order_request.close_position,
+            activation_price: order_request.activation_price,
+            callback_rate: order_request.callback_rate,
+            working_type: order_request.working_type,
+            price_protect: order_request.price_protect,
+        }
+    }
+}
+
+/// New price and quantity of a live LIMIT order, found by `order_id` or `orig_client_order_id`.
+///
+/// `side` must be the order's side.
+pub struct ModifyOrderRequest {
+    pub symbol: String,
+    pub order_id: Option<u64>,
+    pub orig_client_order_id: Option<String>,
+    pub side: OrderSide,
+    pub qty: Number,
+    pub price: Number,
+}
+
 pub struct IncomeRequest {
     pub symbol: Option<String>,
     pub income_type: Option<IncomeType>,
@@ -203,20 +288,26 @@ impl Display for IncomeType {
     }
 }
 
-impl FuturesAccount {
+impl<M: FuturesContracts> FuturesAccount<M> {
+    /// Validate (and optionally round) custom orders before sending them.
+    pub fn with_validator(mut self, validator: OrderValidator) -> Self {
+        self.validator = Some(validator);
+        self
+    }
+
     pub fn limit_buy(
-        &self, symbol: impl Into<String>, qty: impl Into<f64>, price: f64,
+        &self, symbol: impl Into<String>, qty: impl IntoNumber, price: impl IntoNumber,
         time_in_force: TimeInForce,
-    ) -> Result<Transaction> {
+    ) -> Result<M::Transaction> {
         let buy = OrderRequest {
             symbol: symbol.into(),
             side: OrderSide::Buy,
             position_side: None,
             order_type: OrderType::Limit,
             time_in_force: Some(time_in_force),
-            qty: Some(qty.into()),
+            qty: Some(qty.try_into_number()?),
             reduce_only: None,
-            price: Some(price),
+            price: Some(price.try_into_number()?),
             stop_price: None,
             close_position: None,
             activation_price: None,
@@ -224,25 +315,25 @@ impl FuturesAccount {
             working_type: None,
             price_protect: None,
         };
-        let order = self.build_order(buy);
+        let order = build_order(buy);
         let request = build_signed_request(order, self.recv_window)?;
         self.client
-            .post_signed(API::Futures(Futures::Order), request)
+            .post_signed_order(API::Futures(Futures::Order), request)
     }
 
     pub fn limit_sell(
-        &self, symbol: impl Into<String>, qty: impl Into<f64>, price: f64,
+        &self, symbol: impl Into<String>, qty: impl IntoNumber, price: impl IntoNumber,
         time_in_force: TimeInForce,
-    ) -> Result<Transaction> {
+    ) -> Result<M::Transaction> {
         let sell = OrderRequest {
             symbol: symbol.into(),
             side: OrderSide::Sell,
             position_side: None,
             order_type: OrderType::Limit,
             time_in_force: Some(time_in_force),
-            qty: Some(qty.into()),
+            qty: Some(qty.try_into_number()?),
             reduce_only: None,
-            price: Some(price),
+            price: Some(price.try_into_number()?),
             stop_price: None,
             close_position: None,
             activation_price: None,
@@ -250,17 +341,17 @@ impl FuturesAccount {
             working_type: None,
             price_protect: None,
         };
-        let order = self.build_order(sell);
+        let order = build_order(sell);
         let request = build_signed_request(order, self.recv_window)?;
         self.client
-            .post_signed(API::Futures(Futures::Order), request)
+            .post_signed_order(API::Futures(Futures::Order), request)
     }
 
     // Place a MARKET order - BUY
-    pub fn market_buy<S, F>(&self, symbol: S, qty: F) -> Result<Transaction>
+    pub fn market_buy<S, F>(&self, symbol: S, qty: F) -> Result<M::Transaction>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: IntoNumber,
     {
         let buy = OrderRequest {
             symbol: symbol.into(),
@@ -268,7 +359,7 @@ impl FuturesAccount {
             position_side: None,
             order_type: OrderType::Market,
             time_in_force: None,
-            qty: Some(qty.into()),
+            qty: Some(qty.try_into_number()?),
             reduce_only: None,
             price: None,
             stop_price: None,
@@ -278,17 +369,17 @@ impl FuturesAccount {
             working_type: None,
             price_protect: None,
         };
-        let order = self.build_order(buy);
+        let order = build_order(buy);
         let request = build_signed_request(order, self.recv_window)?;
         self.client
-            .post_signed(API::Futures(Futures::Order), request)
+            .post_signed_order(API::Futures(Futures::Order), request)
     }
 
     // Place a MARKET order - SELL
-    pub fn market_sell<S, F>(&self, symbol: S, qty: F) -> Result<Transaction>
+    pub fn market_sell<S, F>(&self, symbol: S, qty: F) -> Result<M::Transaction>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: IntoNumber,
     {
         let sell = OrderRequest {
             symbol: symbol.into(),
@@ -296,7 +387,7 @@ impl FuturesAccount {
             position_side: None,
             order_type: OrderType::Market,
             time_in_force: None,
-            qty: Some(qty.into()),
+            qty: Some(qty.try_into_number()?),
             reduce_only: None,
             price: None,
             stop_price: None,
@@ -306,13 +397,13 @@ impl FuturesAccount {
             working_type: None,
             price_protect: None,
         };
-        let order = self.build_order(sell);
+        let order = build_order(sell);
         let request = build_signed_request(order, self.recv_window)?;
         self.client
-            .post_signed(API::Futures(Futures::Order), request)
+            .post_signed_order(API::Futures(Futures::Order), request)
     }
 
-    pub fn cancel_order<S>(&self, symbol: S, order_id: u64) -> Result<CanceledOrder>
+    pub fn cancel_order<S>(&self, symbol: S, order_id: u64) -> Result<M::CanceledOrder>
     where
         S: Into<String>,
     {
@@ -327,7 +418,7 @@ impl FuturesAccount {
 
     pub fn cancel_order_with_client_id<S>(
         &self, symbol: S, orig_client_order_id: String,
-    ) -> Result<CanceledOrder>
+    ) -> Result<M::CanceledOrder>
     where
         S: Into<String>,
     {
@@ -340,11 +431,145 @@ impl FuturesAccount {
             .delete_signed(API::Futures(Futures::Order), Some(request))
     }
 
+    // Check an order's status
+    pub fn order_status<S>(&self, symbol: S, order_id: u64) -> Result<M::Order>
+    where
+        S: Into<String>,
+    {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("orderId".into(), order_id.to_string());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Futures(Futures::Order), Some(request))
+    }
+
+    pub fn order_status_with_client_id<S>(
+        &self, symbol: S, orig_client_order_id: String,
+    ) -> Result<M::Order>
+    where
+        S: Into<String>,
+    {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("origClientOrderId".into(), orig_client_order_id);
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Futures(Futures::Order), Some(request))
+    }
+
+    /// Change the price and quantity of a live LIMIT order, which keeps its id
+    pub fn modify_order(&self, modify_request: ModifyOrderRequest) -> Result<M::Order> {
+        let parameters = build_modify_order(modify_request)?;
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .put_signed_order(API::Futures(Futures::Order), request)
+    }
+
+    /// Modifications of one order, found by `order_id` or `orig_client_order_id`, or of all
+    /// orders of `symbol` when both are `None`
+    pub fn get_order_amendments<S>(
+        &self, symbol: S, order_id: Option<u64>, orig_client_order_id: Option<String>,
+        start_time: Option<u64>, end_time: Option<u64>, limit: Option<u16>,
+    ) -> Result<Vec<OrderAmendment>>
+    where
+        S: Into<String>,
+    {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        if let Some(order_id) = order_id {
+            parameters.insert("orderId".into(), order_id.to_string());
+        }
+        if let Some(orig_client_order_id) = orig_client_order_id {
+            parameters.insert("origClientOrderId".into(), orig_client_order_id);
+        }
+        insert_time_range(&mut parameters, start_time, end_time);
+        if let Some(limit) = limit {
+            parameters.insert("limit".into(), limit.to_string());
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Futures(Futures::OrderAmendment), Some(request))
+    }
+
+    /// Cancel all open orders of `symbol` unless called again within `countdown_time`
+    /// milliseconds; 0 cancels the countdown.
+    pub fn countdown_cancel_all<S>(
+        &self, symbol: S, countdown_time: u64,
+    ) -> Result<CountdownCancelAll>
+    where
+        S: Into<String>,
+    {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("countdownTime".into(), countdown_time.to_string());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed(API::Futures(Futures::CountdownCancelAll), request)
+    }
+
+    /// Arm the cancel-all countdown of `symbol` and keep refreshing it from a background
+    /// thread, three times per `countdown`, for as long as `is_healthy` returns true.
+    ///
+    /// Once `is_healthy` fails, or the process dies, the countdown runs out and the exchange
+    /// cancels all open orders of the symbol. Dropping the guard cancels the countdown, except
+    /// while panicking or after a failed health check. Refreshes are not orders, so they do not
+    /// wait for the `ORDERS` rate limits.
+    pub fn countdown_guard<S, F>(
+        &self, symbol: S, countdown: Duration, is_healthy: F,
+    ) -> Result<CountdownGuard<M>>
+    where
+        S: Into<String>,
+        F: Fn() -> bool + Send + 'static,
+    {
+        let symbol = symbol.into();
+        let countdown_time = countdown.as_millis() as u64;
+        if countdown_time == 0 {
+            bail!("The countdown must be at least one millisecond");
+        }
+        self.countdown_cancel_all(symbol.clone(), countdown_time)?;
+
+        let active = Arc::new(AtomicBool::new(true));
+        let tripped = Arc::new(AtomicBool::new(false));
+        let refresh_error = Arc::new(Mutex::new(None));
+        let (running, unhealthy, failure) =
+            (active.clone(), tripped.clone(), refresh_error.clone());
+        let (account, refreshed_symbol) = (self.clone(), symbol.clone());
+        let thread = thread::spawn(move || {
+            while sleep_while_running(countdown / 3, &running) {
+                if !is_healthy() {
+                    unhealthy.store(true, Ordering::Relaxed);
+                    break;
+                }
+                // A lost refresh is made up by the next one, still within the countdown
+                let refreshed =
+                    account.countdown_cancel_all(refreshed_symbol.clone(), countdown_time);
+                let mut last_error = failure
+                    .lock()
+                    .unwrap_or_else(|poisoned| poisoned.into_inner());
+                *last_error = refreshed.err();
+            }
+        });
+
+        Ok(CountdownGuard {
+            account: self.clone(),
+            symbol,
+            active,
+            tripped,
+            refresh_error,
+            thread: Some(thread),
+        })
+    }
+
     // Place a STOP_MARKET close - BUY
-    pub fn stop_market_close_buy<S, F>(&self, symbol: S, stop_price: F) -> Result<Transaction>
+    pub fn stop_market_close_buy<S, F>(&self, symbol: S, stop_price: F) -> Result<M::Transaction>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: IntoNumber,
     {
         let sell = OrderRequest {
             symbol: symbol.into(),
@@ -355,24 +580,24 @@ impl FuturesAccount {
             qty: None,
             reduce_only: None,
             price: None,
-            stop_price: Some(stop_price.into()),
+            stop_price: Some(stop_price.try_into_number()?),
             close_position: Some(true),
             activation_price: None,
             callback_rate: None,
             working_type: None,
             price_protect: None,
         };
-        let order = self.build_order(sell);
+        let order = build_order(sell);
         let request = build_signed_request(order, self.recv_window)?;
         self.client
-            .post_signed(API::Futures(Futures::Order), request)
+            .post_signed_order(API::Futures(Futures::Order), request)
     }
 
     // Place a STOP_MARKET close - SELL
-    pub fn stop_market_close_sell<S, F>(&self, symbol: S, stop_price: F) -> Result<Transaction>
+    pub fn stop_market_close_sell<S, F>(&self, symbol: S, stop_price: F) -> Result<M::Transaction>
     where
         S: Into<String>,
-        F: Into<f64>,
+        F: IntoNumber,
     {
         let sell = OrderRequest {
             symbol: symbol.into(),
@@ -383,76 +608,104 @@ impl FuturesAccount {
             qty: None,
             reduce_only: None,
             price: None,
-            stop_price: Some(stop_price.into()),
+            stop_price: Some(stop_price.try_into_number()?),
             close_position: Some(true),
             activation_price: None,
             callback_rate: None,
             working_type: None,
             price_protect: None,
         };
-        let order = self.build_order(sell);
+        let order = build_order(sell);
         let request = build_signed_request(order, self.recv_window)?;
         self.client
-            .post_signed(API::Futures(Futures::Order), request)
+            .post_signed_order(API::Futures(Futures::Order), request)
     }
 
     // Custom order for for professional traders
-    pub fn custom_order(&self, order_request: CustomOrderRequest) -> Result<Transaction> {
-        let order = OrderRequest {
-            symbol: order_request.symbol,
-            side: order_request.side,
-            position_side: order_request.position_side,
-            order_type: order_request.order_type,
-            time_in_force: order_request.time_in_force,
-            qty: order_request.qty,
-            reduce_only: order_request.reduce_only,
-            price: order_request.price,
-            stop_price: order_request.stop_price,
-            close_position: order_request.close_position,
-            activation_price: order_request.activation_price,
-            callback_rate: order_request.callback_rate,
-            working_type: order_request.working_type,
-            price_protect: order_request.price_protect,
-        };
-        let order = self.build_order(order);
+    pub fn custom_order(&self, order_request: CustomOrderRequest) -> Result<M::Transaction> {
+        let mut order_request: OrderRequest = order_request.into();
+        if let Some(validator) = &self.validator {
+            order_request.validate(validator)?;
+        }
+        let order = build_order(order_request);
         let request = build_signed_request(order, self.recv_window)?;
         self.client
-            .post_signed(API::Futures(Futures::Order), request)
+            .post_signed_order(API::Futures(Futures::Order), request)
     }
 
-    // Custom order for for professional traders
+    /// Place up to 5 orders in a single request
+    ///
+    /// Binance reports failures per order, so each entry is either the placed order
+    /// or the error returned for it, in request order.
     pub fn custom_batch_orders(
-        &self, _order_count: u64, order_requests: Vec<CustomOrderRequest>,
-    ) -> Result<Transaction> {
-        let request = String::from("");
-        for order_request in order_requests {
-            let order = OrderRequest {
-                symbol: order_request.symbol,
-                side: order_request.side,
-                position_side: order_request.position_side,
-                order_type: order_request.order_type,
-                time_in_force: order_request.time_in_force,
-                qty: order_request.qty,
-                reduce_only: order_request.reduce_only,
-                price: order_request.price,
-                stop_price: order_request.stop_price,
-                close_position: order_request.close_position,
-                activation_price: order_request.activation_price,
-                callback_rate: order_request.callback_rate,
-                working_type: order_request.working_type,
-                price_protect: order_request.price_protect,
-            };
-            let _order = self.build_order(order);
-            // TODO : make a request string for batch orders api
-            // let request = build_signed_request(order, self.recv_window)?;
-        }
+        &self, order_requests: Vec<CustomOrderRequest>,
+    ) -> Result<Vec<BatchResult<M::Transaction>>> {
+        let request = build_batch_orders(order_requests)?;
+        let request = build_signed_request(request, self.recv_window)?;
         self.client
-            .post_signed(API::Futures(Futures::Order), request)
+            .post_signed_order::<Vec<BatchResponse<M::Transaction>>>(
+                API::Futures(Futures::BatchOrders),
+                request,
+            )
+            .map(|responses| {
+                responses
+                    .into_iter()
+                    .map(BatchResponse::into_result)
+                    .collect()
+            })
+    }
+
+    /// Cancel up to 10 orders by order id
+    pub fn cancel_multiple_orders<S>(
+        &self, symbol: S, order_ids: Vec<u64>,
+    ) -> Result<Vec<BatchResult<M::CanceledOrder>>>
+    where
+        S: Into<String>,
+    {
+        let parameters = build_batch_cancel(symbol.into(), "orderIdList", &order_ids)?;
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .delete_signed::<Vec<BatchResponse<M::CanceledOrder>>>(
+                API::Futures(Futures::BatchOrders),
+                Some(request),
+            )
+            .map(|responses| {
+                responses
+                    .into_iter()
+                    .map(BatchResponse::into_result)
+                    .collect()
+            })
+    }
+
+    /// Cancel up to 10 orders by client order id
+    pub fn cancel_multiple_orders_with_client_ids<S>(
+        &self, symbol: S, orig_client_order_ids: Vec<String>,
+    ) -> Result<Vec<BatchResult<M::CanceledOrder>>>
+    where
+        S: Into<String>,
+    {
+        let parameters = build_batch_cancel(
+            symbol.into(),
+            "origClientOrderIdList",
+            &orig_client_order_ids,
+        )?;
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .delete_signed::<Vec<BatchResponse<M::CanceledOrder>>>(
+                API::Futures(Futures::BatchOrders),
+                Some(request),
+            )
+            .map(|responses| {
+                responses
+                    .into_iter()
+                    .map(BatchResponse::into_result)
+                    .collect()
+            })
     }
 
     pub fn get_all_orders<S, F, N>(
         &self, symbol: S, order_id: F, start_time: F, end_time: F, limit: N,
-    ) -> Result<Vec<Order>>
+    ) -> Result<Vec<M::Order>>
     where
         S: Into<String>,
         F: Into<Option<u64>>,
@@ -480,7 +733,7 @@ impl FuturesAccount {
 
     pub fn get_user_trades<S, F, N>(
         &self, symbol: S, from_id: F, start_time: F, end_time: F, limit: N,
-    ) -> Result<Vec<TradeHistory>>
+    ) -> Result<Vec<M::TradeHistory>>
     where
         S: Into<String>,
         F: Into<Option<u64>>,
@@ -505,68 +758,26 @@ impl FuturesAccount {
         self.client
             .get_signed(API::Futures(Futures::UserTrades), Some(request))
     }
-    fn build_order(&self, order: OrderRequest) -> BTreeMap<String, String> {
-        let mut parameters = BTreeMap::new();
-        parameters.insert("symbol".into(), order.symbol);
-        parameters.insert("side".into(), order.side.to_string());
-        parameters.insert("type".into(), order.order_type.to_string());
 
-        if let Some(position_side) = order.position_side {
-            parameters.insert("positionSide".into(), position_side.to_string());
-        }
-        if let Some(time_in_force) = order.time_in_force {
-            parameters.insert("timeInForce".into(), time_in_force.to_string());
-        }
-        if let Some(qty) = order.qty {
-            parameters.insert("quantity".into(), qty.to_string());
-        }
-        if let Some(reduce_only) = order.reduce_only {
-            parameters.insert("reduceOnly".into(), reduce_only.to_string().to_uppercase());
-        }
-        if let Some(price) = order.price {
-            parameters.insert("price".into(), price.to_string());
-        }
-        if let Some(stop_price) = order.stop_price {
-            parameters.insert("stopPrice".into(), stop_price.to_string());
-        }
-        if let Some(close_position) = order.close_position {
-            parameters.insert(
-                "closePosition".into(),
-                close_position.to_string().to_uppercase(),
-            );
-        }
-        if let Some(activation_price) = order.activation_price {
-            parameters.insert("activationPrice".into(), activation_price.to_string());
-        }
-        if let Some(callback_rate) = order.callback_rate {
-            parameters.insert("callbackRate".into(), callback_rate.to_string());
-        }
-        if let Some(working_type) = order.working_type {
-            parameters.insert("workingType".into(), working_type.to_string());
-        }
-        if let Some(price_protect) = order.price_protect {
-            parameters.insert(
-                "priceProtect".into(),
-                price_protect.to_string().to_uppercase(),
-            );
-        }
-
-        parameters
-    }
-
-    pub fn position_information<S>(&self, symbol: S) -> Result<Vec<PositionRisk>>
+    pub fn position_information<S>(&self, symbol: S) -> Result<Vec<M::PositionRisk>>
     where
         S: Into<String>,
     {
         let mut parameters = BTreeMap::new();
-        parameters.insert("symbol".into(), symbol.into());
+        // COIN-M positions are queried by pair, e.g. BTCUSD
+        let key = if self.client.is_coin_futures() {
+            "pair"
+        } else {
+            "symbol"
+        };
+        parameters.insert(key.into(), symbol.into());
 
         let request = build_signed_request(parameters, self.recv_window)?;
         self.client
             .get_signed(API::Futures(Futures::PositionRisk), Some(request))
     }
 
-    pub fn account_information(&self) -> Result<AccountInformation> {
+    pub fn account_information(&self) -> Result<M::AccountInformation> {
         let parameters = BTreeMap::new();
 
         let request = build_signed_request(parameters, self.recv_window)?;
@@ -574,7 +785,7 @@ impl FuturesAccount {
             .get_signed(API::Futures(Futures::Account), Some(request))
     }
 
-    pub fn account_balance(&self) -> Result<Vec<AccountBalance>> {
+    pub fn account_balance(&self) -> Result<Vec<M::AccountBalance>> {
         let parameters = BTreeMap::new();
 
         let request = build_signed_request(parameters, self.recv_window)?;
@@ -582,9 +793,45 @@ impl FuturesAccount {
             .get_signed(API::Futures(Futures::Balance), Some(request))
     }
 
+    // Notional tiers of ONE symbol, or of all symbols
+    pub fn get_leverage_brackets(&self, symbol: Option<String>) -> Result<Vec<LeverageBracket>> {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        if let Some(symbol) = symbol {
+            parameters.insert("symbol".into(), symbol);
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        let brackets: OneOrMany<LeverageBracket> = self
+            .client
+            .get_signed(API::Futures(Futures::LeverageBracket), Some(request))?;
+        Ok(brackets.into_many())
+    }
+
+    /// Largest position of `symbol` that `wallet_balance` can open at `leverage` and `price`,
+    /// from the symbol's leverage brackets. See `LeverageBracket::position_sizing`.
+    pub fn position_sizing<S>(
+        &self, symbol: S, leverage: u8, wallet_balance: impl IntoNumber, price: impl IntoNumber,
+        side: OrderSide,
+    ) -> Result<PositionSizing>
+    where
+        S: Into<String>,
+    {
+        let brackets = self.get_leverage_brackets(Some(symbol.into()))?;
+        let bracket = match brackets.into_iter().next() {
+            Some(bracket) => bracket,
+            None => bail!("No leverage brackets returned"),
+        };
+        bracket.position_sizing(
+            leverage,
+            wallet_balance.try_into_number()?,
+            price.try_into_number()?,
+            side,
+        )
+    }
+
     pub fn change_initial_leverage<S>(
         &self, symbol: S, leverage: u8,
-    ) -> Result<ChangeLeverageResponse>
+    ) -> Result<M::ChangeLeverageResponse>
     where
         S: Into<String>,
     {
@@ -613,7 +860,7 @@ impl FuturesAccount {
     }
 
     pub fn change_position_margin<S>(
-        &self, symbol: S, amount: f64, is_adding_margin: bool,
+        &self, symbol: S, amount: impl IntoNumber, is_adding_margin: bool,
     ) -> Result<()>
     where
         S: Into<String>,
@@ -621,7 +868,7 @@ impl FuturesAccount {
         let mut parameters: BTreeMap<String, String> = BTreeMap::new();
         let margin = if is_adding_margin { "1" } else { "2" };
         parameters.insert("symbol".into(), symbol.into());
-        parameters.insert("amount".into(), amount.to_string());
+        parameters.insert("amount".into(), amount.try_into_number()?.to_string());
         parameters.insert("type".into(), margin.into());
 
         let request = build_signed_request(parameters, self.recv_window)?;
@@ -653,7 +900,7 @@ impl FuturesAccount {
             .map(|_| ())
     }
 
-    pub fn get_all_open_orders<S>(&self, symbol: S) -> Result<Vec<crate::futures::model::Order>>
+    pub fn get_all_open_orders<S>(&self, symbol: S) -> Result<Vec<M::Order>>
     where
         S: Into<String>,
     {
@@ -690,3 +937,168 @@ impl FuturesAccount {
             .get_signed(API::Futures(Futures::Income), Some(request))
     }
 }
+
+/// Cancel-all countdown of a symbol, refreshed until dropped, see
+/// `FuturesAccount::countdown_guard`.
+pub struct CountdownGuard<M: FuturesContracts = UsdM> {
+    account: FuturesAccount<M>,
+    symbol: String,
+    active: Arc<AtomicBool>,
+    tripped: Arc<AtomicBool>,
+    refresh_error: Arc<Mutex<Option<Error>>>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl<M: FuturesContracts> CountdownGuard<M> {
+    /// Whether a failed health check stopped the refreshes, leaving the countdown to run out
+    pub fn is_tripped(&self) -> bool {
+        self.tripped.load(Ordering::Relaxed)
+    }
+
+    /// Takes the error of the last refresh when it failed; the next successful one clears it.
+    /// The countdown keeps running meanwhile and cancels the orders once it runs out.
+    pub fn take_refresh_error(&self) -> Option<Error> {
+        self.refresh_error
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .take()
+    }
+
+    /// Stop refreshing and cancel the countdown, keeping the open orders
+    pub fn disarm(mut self) -> Result<()> {
+        self.stop();
+        self.account
+            .countdown_cancel_all(self.symbol.clone(), 0)
+            .map(|_| ())
+    }
+
+    fn stop(&mut self) {
+        self.active.store(false, Ordering::Relaxed);
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+impl<M: FuturesContracts> Drop for CountdownGuard<M> {
+    fn drop(&mut self) {
+        // Disarmed guards have no thread left
+        if self.thread.is_none() {
+            return;
+        }
+        self.stop();
+        if !thread::panicking() && !self.is_tripped() {
+            let _ = self.account.countdown_cancel_all(self.symbol.clone(), 0);
+        }
+    }
+}
+
+pub(crate) fn build_modify_order(order: ModifyOrderRequest) -> Result<BTreeMap<String, String>> {
+    let mut parameters = BTreeMap::new();
+    parameters.insert("symbol".into(), order.symbol);
+    match (order.order_id, order.orig_client_order_id) {
+        (Some(order_id), _) => {
+            parameters.insert("orderId".into(), order_id.to_string());
+        }
+        (None, Some(orig_client_order_id)) => {
+            parameters.insert("origClientOrderId".into(), orig_client_order_id);
+        }
+        (None, None) => bail!("Order modifications need an order id or a client order id"),
+    }
+    parameters.insert("side".into(), order.side.to_string());
+    parameters.insert("quantity".into(), order.qty.to_string());
+    parameters.insert("price".into(), order.price.to_string());
+
+    Ok(parameters)
+}
+
+pub(crate) fn build_order(order: OrderRequest) -> BTreeMap<String, String> {
+    let mut parameters = BTreeMap::new();
+    parameters.insert("symbol".into(), order.symbol);
+    parameters.insert("side".into(), order.side.to_string());
+    parameters.insert("type".into(), order.order_type.to_string());
+
+    if let Some(position_side) = order.position_side {
+        parameters.insert("positionSide".into(), position_side.to_string());
+    }
+    if let Some(time_in_force) = order.time_in_force {
+        parameters.insert("timeInForce".into(), time_in_force.to_string());
+    }
+    if let Some(qty) = order.qty {
+        parameters.insert("quantity".into(), qty.to_string());
+    }
+    if let Some(reduce_only) = order.reduce_only {
+        parameters.insert("reduceOnly".into(), reduce_only.to_string().to_uppercase());
+    }
+    if let Some(price) = order.price {
+        parameters.insert("price".into(), price.to_string());
+    }
+    if let Some(stop_price) = order.stop_price {
+        parameters.insert("stopPrice".into(), stop_price.to_string());
+    }
+    if let Some(close_position) = order.close_position {
+        parameters.insert(
+            "closePosition".into(),
+            close_position.to_string().to_uppercase(),
+        );
+    }
+    if let Some(activation_price) = order.activation_price {
+        parameters.insert("activationPrice".into(), activation_price.to_string());
+    }
+    if let Some(callback_rate) = order.callback_rate {
+        parameters.insert("callbackRate".into(), callback_rate.to_string());
+    }
+    if let Some(working_type) = order.working_type {
+        parameters.insert("workingType".into(), working_type.to_string());
+    }
+    if let Some(price_protect) = order.price_protect {
+        parameters.insert(
+            "priceProtect".into(),
+            price_protect.to_string().to_uppercase(),
+        );
+    }
+
+    parameters
+}
+
+pub(crate) fn build_batch_orders(
+    order_requests: Vec<CustomOrderRequest>,
+) -> Result<BTreeMap<String, String>> {
+    if order_requests.is_empty() || order_requests.len() > MAX_BATCH_ORDERS {
+        bail!(format!(
+            "Batch orders must contain between 1 and {} orders",
+            MAX_BATCH_ORDERS
+        ));
+    }
+
+    let orders: Vec<BTreeMap<String, String>> = order_requests
+        .into_iter()
+        .map(|order_request| build_order(order_request.into()))
+        .collect();
+
+    let mut parameters = BTreeMap::new();
+    parameters.insert("batchOrders".into(), encode_json(&orders)?);
+    Ok(parameters)
+}
+
+pub(crate) fn build_batch_cancel<T: Serialize>(
+    symbol: String, key: &str, ids: &[T],
+) -> Result<BTreeMap<String, String>> {
+    if ids.is_empty() || ids.len() > MAX_BATCH_CANCELS {
+        bail!(format!(
+            "Batch cancel must contain between 1 and {} ids",
+            MAX_BATCH_CANCELS
+        ));
+    }
+
+    let mut parameters = BTreeMap::new();
+    parameters.insert("symbol".into(), symbol);
+    parameters.insert(key.into(), encode_json(ids)?);
+    Ok(parameters)
+}
+
+// JSON parameters are percent-encoded so the signature covers the exact query sent
+fn encode_json<T: Serialize + ?Sized>(value: &T) -> Result<String> {
+    let json = serde_json::to_string(value)?;
+    Ok(encode(&json))
+}
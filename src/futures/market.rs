@@ -5,29 +5,38 @@
 - [ ] `Old Trades Lookup (MARKET_DATA)`
 - [x] `Compressed/Aggregate Trades List`
 - [x] `Kline/Candlestick Data`
+- [x] `Continuous Contract Kline/Candlestick Data`
+- [x] `Index Price Kline/Candlestick Data`
+- [x] `Mark Price Kline/Candlestick Data`
+- [x] `Premium Index Kline Data`
 - [x] `Mark Price`
-- [ ] `Get Funding Rate History (MARKET_DATA)`
+- [x] `Get Funding Rate History (MARKET_DATA)`
 - [x] `24hr Ticker Price Change Statistics`
 - [x] `Symbol Price Ticker`
 - [x] `Symbol Order Book Ticker`
 - [x] `Get all Liquidation Orders`
 - [x] `Open Interest`
-- [ ] `Notional and Leverage Brackets (MARKET_DATA)`
-- [ ] `Open Interest Statistics (MARKET_DATA)`
-- [ ] `Top Trader Long/Short Ratio (Accounts) (MARKET_DATA)`
-- [ ] `Top Trader Long/Short Ratio (Positions) (MARKET_DATA)`
-- [ ] `Long/Short Ratio (MARKET_DATA)`
-- [ ] `Taker Buy/Sell Volume (MARKET_DATA)`
+- [x] `Notional and Leverage Brackets (USER_DATA)`, on `FuturesAccount`
+- [x] `Open Interest Statistics (MARKET_DATA)`
+- [x] `Top Trader Long/Short Ratio (Accounts) (MARKET_DATA)`
+- [x] `Top Trader Long/Short Ratio (Positions) (MARKET_DATA)`
+- [x] `Long/Short Ratio (MARKET_DATA)`
+- [x] `Taker Buy/Sell Volume (MARKET_DATA)`
 */
 
 use crate::util::{build_request, build_signed_request};
+use crate::futures::account::ContractType;
 use crate::futures::model::{
-    AggTrades, BookTickers, KlineSummaries, KlineSummary, LiquidationOrders, MarkPrices,
-    OpenInterest, OpenInterestHist, OrderBook, PriceStats, SymbolPrice, Tickers, Trades,
+    AggTrades, BookTickers, FundingInfo, FundingRate, KlineSummaries, KlineSummary,
+    FuturesContracts, LiquidationOrders, LongShortRatio, MarkPrice, MarkPrices, OneOrMany,
+    OpenInterest, OpenInterestHist, OrderBook, SymbolPrice, TakerBuySellVolume, Tickers, Trades,
+    UsdM,
 };
 use crate::client::Client;
 use crate::errors::Result;
 use std::collections::BTreeMap;
+use std::marker::PhantomData;
+use serde::de::DeserializeOwned;
 use serde_json::Value;
 use crate::api::API;
 use crate::api::Futures;
@@ -37,13 +46,17 @@ use crate::api::Futures;
 // Add limit parameters to functions
 // Implement all functions
 
+// Most funding rates per request
+const FUNDING_RATE_PAGE: u32 = 1000;
+
 #[derive(Clone)]
-pub struct FuturesMarket {
+pub struct FuturesMarket<M = UsdM> {
     pub client: Client,
     pub recv_window: u64,
+    pub(crate) contracts: PhantomData<M>,
 }
 
-impl FuturesMarket {
+impl<M: FuturesContracts> FuturesMarket<M> {
     // Order book (Default 100; max 1000)
     pub fn get_depth<S>(&self, symbol: S) -> Result<OrderBook>
     where
@@ -70,7 +83,7 @@ impl FuturesMarket {
         self.client.get(API::Futures(Futures::Depth), Some(request))
     }
 
-    pub fn get_trades<S>(&self, symbol: S) -> Result<Trades>
+    pub fn get_trades<S>(&self, symbol: S) -> Result<Trades<M::Trade>>
     where
         S: Into<String>,
     {
@@ -84,7 +97,7 @@ impl FuturesMarket {
     // TODO This may be incomplete, as it hasn't been tested
     pub fn get_historical_trades<S1, S2, S3>(
         &self, symbol: S1, from_id: S2, limit: S3,
-    ) -> Result<Trades>
+    ) -> Result<Trades<M::Trade>>
     where
         S1: Into<String>,
         S2: Into<Option<u64>>,
@@ -159,34 +172,133 @@ impl FuturesMarket {
         parameters.insert("symbol".into(), symbol.into());
         parameters.insert("interval".into(), interval.into());
 
-        // Add three optional parameters
-        if let Some(lt) = limit.into() {
-            parameters.insert("limit".into(), format!("{}", lt));
-        }
-        if let Some(st) = start_time.into() {
-            parameters.insert("startTime".into(), format!("{}", st));
-        }
-        if let Some(et) = end_time.into() {
-            parameters.insert("endTime".into(), format!("{}", et));
-        }
+        self.get_kline_rows(Futures::Klines, parameters, limit, start_time, end_time)
+    }
 
-        let request = build_request(parameters);
+    // Klines of a pair's perpetual or delivery contracts, spliced over their expiries
+    pub fn get_continuous_klines<S1, S2, S3, S4, S5>(
+        &self, pair: S1, contract_type: ContractType, interval: S2, limit: S3, start_time: S4,
+        end_time: S5,
+    ) -> Result<KlineSummaries>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<Option<u16>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
 
-        let data: Vec<Vec<Value>> = self
-            .client
-            .get(API::Futures(Futures::Klines), Some(request))?;
+        parameters.insert("pair".into(), pair.into());
+        parameters.insert("contractType".into(), contract_type.into());
+        parameters.insert("interval".into(), interval.into());
 
-        let klines = KlineSummaries::AllKlineSummaries(
-            data.iter()
-                .map(|row| row.try_into())
-                .collect::<Result<Vec<KlineSummary>>>()?,
-        );
+        self.get_kline_rows(
+            Futures::ContinuousKlines,
+            parameters,
+            limit,
+            start_time,
+            end_time,
+        )
+    }
 
-        Ok(klines)
+    // Index price klines of a pair; volumes and trade counts are zero
+    pub fn get_index_price_klines<S1, S2, S3, S4, S5>(
+        &self, pair: S1, interval: S2, limit: S3, start_time: S4, end_time: S5,
+    ) -> Result<KlineSummaries>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<Option<u16>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+
+        parameters.insert("pair".into(), pair.into());
+        parameters.insert("interval".into(), interval.into());
+
+        self.get_kline_rows(
+            Futures::IndexPriceKlines,
+            parameters,
+            limit,
+            start_time,
+            end_time,
+        )
+    }
+
+    // Mark price klines of a symbol; volumes and trade counts are zero
+    pub fn get_mark_price_klines<S1, S2, S3, S4, S5>(
+        &self, symbol: S1, interval: S2, limit: S3, start_time: S4, end_time: S5,
+    ) -> Result<KlineSummaries>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<Option<u16>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("interval".into(), interval.into());
+
+        self.get_kline_rows(
+            Futures::MarkPriceKlines,
+            parameters,
+            limit,
+            start_time,
+            end_time,
+        )
+    }
+
+    // Premium index klines of a symbol; volumes and trade counts are zero
+    pub fn get_premium_index_klines<S1, S2, S3, S4, S5>(
+        &self, symbol: S1, interval: S2, limit: S3, start_time: S4, end_time: S5,
+    ) -> Result<KlineSummaries>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<Option<u16>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("interval".into(), interval.into());
+
+        self.get_kline_rows(
+            Futures::PremiumIndexKlines,
+            parameters,
+            limit,
+            start_time,
+            end_time,
+        )
+    }
+
+    // Klines of a leveraged token's net asset value (USDⓈ-M only). `volume` holds the real
+    // leverage at the close and `number_of_trades` the number of NAV updates.
+    pub fn get_lvt_klines<S1, S2, S3, S4, S5>(
+        &self, symbol: S1, interval: S2, limit: S3, start_time: S4, end_time: S5,
+    ) -> Result<KlineSummaries>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<Option<u16>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("interval".into(), interval.into());
+
+        self.get_kline_rows(Futures::LvtKlines, parameters, limit, start_time, end_time)
     }
 
     // 24hr ticker price change statistics
-    pub fn get_24h_price_stats<S>(&self, symbol: S) -> Result<PriceStats>
+    pub fn get_24h_price_stats<S>(&self, symbol: S) -> Result<M::PriceStats>
     where
         S: Into<String>,
     {
@@ -196,11 +308,12 @@ impl FuturesMarket {
         let request = build_request(parameters);
 
         self.client
-            .get(API::Futures(Futures::Ticker24hr), Some(request))
+            .get::<OneOrMany<M::PriceStats>>(API::Futures(Futures::Ticker24hr), Some(request))?
+            .into_one()
     }
 
     // 24hr ticker price change statistics for all symbols
-    pub fn get_all_24h_price_stats(&self) -> Result<Vec<PriceStats>> {
+    pub fn get_all_24h_price_stats(&self) -> Result<Vec<M::PriceStats>> {
         self.client.get(API::Futures(Futures::Ticker24hr), None)
     }
 
@@ -215,7 +328,8 @@ impl FuturesMarket {
         let request = build_request(parameters);
 
         self.client
-            .get(API::Futures(Futures::TickerPrice), Some(request))
+            .get::<OneOrMany<SymbolPrice>>(API::Futures(Futures::TickerPrice), Some(request))?
+            .into_one()
     }
 
     // Latest price for all symbols.
@@ -238,13 +352,85 @@ impl FuturesMarket {
         parameters.insert("symbol".into(), symbol.into());
         let request = build_request(parameters);
         self.client
-            .get(API::Futures(Futures::BookTicker), Some(request))
+            .get::<OneOrMany<Tickers>>(API::Futures(Futures::BookTicker), Some(request))?
+            .into_one()
     }
 
     pub fn get_mark_prices(&self) -> Result<MarkPrices> {
         self.client.get(API::Futures(Futures::PremiumIndex), None)
     }
 
+    /// Mark, index and estimated settlement price, and funding rate of one symbol
+    pub fn get_premium_index<S>(&self, symbol: S) -> Result<MarkPrice>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        let request = build_request(parameters);
+        self.client
+            .get::<OneOrMany<MarkPrice>>(API::Futures(Futures::PremiumIndex), Some(request))?
+            .into_one()
+    }
+
+    /// Funding rates of `symbol`, oldest first.
+    ///
+    /// With a `start_time`, pages of 1000 rates are requested until `end_time` or `limit` rates
+    /// are reached. Without it, only the latest `limit` rates are returned, at most 1000 however
+    /// large `limit` is. A `limit` of 0 returns no rates without sending a request.
+    pub fn get_funding_rate_history<S>(
+        &self, symbol: S, start_time: Option<u64>, end_time: Option<u64>, limit: Option<u32>,
+    ) -> Result<Vec<FundingRate>>
+    where
+        S: Into<String>,
+    {
+        if limit == Some(0) {
+            return Ok(Vec::new());
+        }
+        let symbol = symbol.into();
+        let mut start_time = start_time;
+        let mut rates: Vec<FundingRate> = Vec::new();
+
+        loop {
+            // Clamped to one page with or without a `start_time`
+            let page_limit = limit.map_or(FUNDING_RATE_PAGE, |limit| {
+                (limit - rates.len() as u32).min(FUNDING_RATE_PAGE)
+            });
+            let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+            parameters.insert("symbol".into(), symbol.clone());
+            parameters.insert("limit".into(), page_limit.to_string());
+            if let Some(start_time) = start_time {
+                parameters.insert("startTime".into(), start_time.to_string());
+            }
+            if let Some(end_time) = end_time {
+                parameters.insert("endTime".into(), end_time.to_string());
+            }
+            let request = build_request(parameters);
+            let page: Vec<FundingRate> = self
+                .client
+                .get(API::Futures(Futures::FundingRate), Some(request))?;
+
+            let full_page = page.len() as u32 == page_limit;
+            let last_funding_time = page.last().map(|rate| rate.funding_time);
+            rates.extend(page);
+
+            match (start_time, last_funding_time) {
+                (Some(_), Some(last_funding_time))
+                    if full_page && limit.map_or(true, |limit| (rates.len() as u32) < limit) =>
+                {
+                    start_time = Some(last_funding_time + 1);
+                }
+                _ => return Ok(rates),
+            }
+        }
+    }
+
+    /// Funding rate caps, floors and intervals of the symbols whose funding was adjusted
+    /// (USDⓈ-M only)
+    pub fn get_funding_info(&self) -> Result<Vec<FundingInfo>> {
+        self.client.get(API::Futures(Futures::FundingInfo), None)
+    }
+
     pub fn get_all_liquidation_orders(&self) -> Result<LiquidationOrders> {
         self.client.get(API::Futures(Futures::AllForceOrders), None)
     }
@@ -269,6 +455,113 @@ impl FuturesMarket {
         S3: Into<Option<u16>>,
         S4: Into<Option<u64>>,
         S5: Into<Option<u64>>,
+    {
+        self.get_statistics(
+            Futures::OpenInterestHist,
+            symbol,
+            period,
+            limit,
+            start_time,
+            end_time,
+        )
+    }
+
+    // Long/short ratio of the accounts of the top traders by margin balance
+    pub fn top_long_short_account_ratio<S1, S2, S3, S4, S5>(
+        &self, symbol: S1, period: S2, limit: S3, start_time: S4, end_time: S5,
+    ) -> Result<Vec<LongShortRatio>>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<Option<u16>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        self.get_statistics(
+            Futures::TopLongShortAccountRatio,
+            symbol,
+            period,
+            limit,
+            start_time,
+            end_time,
+        )
+    }
+
+    // Long/short ratio of the positions of the top traders by margin balance
+    pub fn top_long_short_position_ratio<S1, S2, S3, S4, S5>(
+        &self, symbol: S1, period: S2, limit: S3, start_time: S4, end_time: S5,
+    ) -> Result<Vec<LongShortRatio>>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<Option<u16>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        self.get_statistics(
+            Futures::TopLongShortPositionRatio,
+            symbol,
+            period,
+            limit,
+            start_time,
+            end_time,
+        )
+    }
+
+    // Long/short ratio of all accounts holding a position in the symbol
+    pub fn global_long_short_account_ratio<S1, S2, S3, S4, S5>(
+        &self, symbol: S1, period: S2, limit: S3, start_time: S4, end_time: S5,
+    ) -> Result<Vec<LongShortRatio>>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<Option<u16>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        self.get_statistics(
+            Futures::GlobalLongShortAccountRatio,
+            symbol,
+            period,
+            limit,
+            start_time,
+            end_time,
+        )
+    }
+
+    // Volume bought and sold by takers in each period
+    pub fn taker_buy_sell_volume<S1, S2, S3, S4, S5>(
+        &self, symbol: S1, period: S2, limit: S3, start_time: S4, end_time: S5,
+    ) -> Result<Vec<TakerBuySellVolume>>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<Option<u16>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        self.get_statistics(
+            Futures::TakerlongshortRatio,
+            symbol,
+            period,
+            limit,
+            start_time,
+            end_time,
+        )
+    }
+
+    // Statistics of the `/futures/data` endpoints, kept for the last 30 days in periods of
+    // "5m", "15m", "30m", "1h", "2h", "4h", "6h", "12h" or "1d"
+    fn get_statistics<T, S1, S2, S3, S4, S5>(
+        &self, endpoint: Futures, symbol: S1, period: S2, limit: S3, start_time: S4, end_time: S5,
+    ) -> Result<Vec<T>>
+    where
+        T: DeserializeOwned,
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<Option<u16>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
     {
         let mut parameters: BTreeMap<String, String> = BTreeMap::new();
         parameters.insert("symbol".into(), symbol.into());
@@ -285,7 +578,40 @@ impl FuturesMarket {
         }
 
         let request = build_request(parameters);
-        self.client
-            .get(API::Futures(Futures::OpenInterestHist), Some(request))
+        self.client.get(API::Futures(endpoint), Some(request))
+    }
+
+    // Klines of any kline endpoint, which all answer with the rows of `get_klines`
+    fn get_kline_rows<S3, S4, S5>(
+        &self, endpoint: Futures, mut parameters: BTreeMap<String, String>, limit: S3,
+        start_time: S4, end_time: S5,
+    ) -> Result<KlineSummaries>
+    where
+        S3: Into<Option<u16>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        // Add three optional parameters
+        if let Some(lt) = limit.into() {
+            parameters.insert("limit".into(), format!("{}", lt));
+        }
+        if let Some(st) = start_time.into() {
+            parameters.insert("startTime".into(), format!("{}", st));
+        }
+        if let Some(et) = end_time.into() {
+            parameters.insert("endTime".into(), format!("{}", et));
+        }
+
+        let request = build_request(parameters);
+
+        let data: Vec<Vec<Value>> = self.client.get(API::Futures(endpoint), Some(request))?;
+
+        let klines = KlineSummaries::AllKlineSummaries(
+            data.iter()
+                .map(|row| row.try_into())
+                .collect::<Result<Vec<KlineSummary>>>()?,
+        );
+
+        Ok(klines)
     }
 }
@@ -1,8 +1,10 @@
-use serde::{Deserialize, Serialize};
-use crate::model::{string_or_float, string_or_float_opt, string_or_bool};
+use serde::de::DeserializeOwned;
+use serde::{de, Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+use crate::model::{string_or_float, string_or_float_opt, string_or_bool, IntoNumber};
 
 pub use crate::model::{
-    Asks, Bids, BookTickers, Filters, KlineSummaries, KlineSummary, RateLimit, ServerTime,
+    Asks, Bids, BookTickers, Filters, KlineSummaries, KlineSummary, Number, RateLimit, ServerTime,
     SymbolPrice, Tickers,
 };
 
@@ -20,6 +22,7 @@ pub struct ExchangeInformation {
 #[serde(rename_all = "camelCase")]
 pub struct Symbol {
     pub symbol: String,
+    #[serde(alias = "contractStatus")]
     pub status: String,
     pub maint_margin_percent: String,
     pub required_margin_percent: String,
@@ -33,6 +36,12 @@ pub struct Symbol {
     pub filters: Vec<Filters>,
     pub order_types: Vec<String>,
     pub time_in_force: Vec<String>,
+    pub pair: Option<String>,
+    pub contract_type: Option<String>,
+    pub delivery_date: Option<u64>,
+    // COIN-M only, in USD per contract
+    pub contract_size: Option<u64>,
+    pub margin_asset: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -57,19 +66,19 @@ pub struct PriceStats {
     pub price_change_percent: String,
     pub weighted_avg_price: String,
     #[serde(with = "string_or_float")]
-    pub last_price: f64,
+    pub last_price: Number,
     #[serde(with = "string_or_float")]
-    pub open_price: f64,
+    pub open_price: Number,
     #[serde(with = "string_or_float")]
-    pub high_price: f64,
+    pub high_price: Number,
     #[serde(with = "string_or_float")]
-    pub low_price: f64,
+    pub low_price: Number,
     #[serde(with = "string_or_float")]
-    pub volume: f64,
+    pub volume: Number,
     #[serde(with = "string_or_float")]
-    pub quote_volume: f64,
+    pub quote_volume: Number,
     #[serde(with = "string_or_float")]
-    pub last_qty: f64,
+    pub last_qty: Number,
     pub open_time: u64,
     pub close_time: u64,
     pub first_id: u64,
@@ -82,19 +91,19 @@ pub struct PriceStats {
 pub struct TradeHistory {
     pub buyer: bool,
     #[serde(with = "string_or_float")]
-    pub commission: f64,
+    pub commission: Number,
     pub commission_asset: String,
     pub id: u64,
     pub maker: bool,
     pub order_id: u64,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Number,
     #[serde(with = "string_or_float")]
-    pub qty: f64,
+    pub qty: Number,
     #[serde(with = "string_or_float")]
-    pub quote_qty: f64,
+    pub quote_qty: Number,
     #[serde(with = "string_or_float")]
-    pub realized_pnl: f64,
+    pub realized_pnl: Number,
     pub side: String,
     pub position_side: String,
     pub symbol: String,
@@ -103,8 +112,8 @@ pub struct TradeHistory {
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(untagged)]
-pub enum Trades {
-    AllTrades(Vec<Trade>),
+pub enum Trades<T = Trade> {
+    AllTrades(Vec<T>),
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -113,11 +122,11 @@ pub struct Trade {
     pub id: u64,
     pub is_buyer_maker: bool,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Number,
     #[serde(with = "string_or_float")]
-    pub qty: f64,
+    pub qty: Number,
     #[serde(with = "string_or_float")]
-    pub quote_qty: f64,
+    pub quote_qty: Number,
     pub time: u64,
 }
 
@@ -141,9 +150,9 @@ pub struct AggTrade {
     #[serde(rename = "m")]
     pub maker: bool,
     #[serde(rename = "p", with = "string_or_float")]
-    pub price: f64,
+    pub price: Number,
     #[serde(rename = "q", with = "string_or_float")]
-    pub qty: f64,
+    pub qty: Number,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -156,14 +165,59 @@ pub enum MarkPrices {
 #[serde(rename_all = "camelCase")]
 pub struct MarkPrice {
     pub symbol: String,
-    #[serde(with = "string_or_float")]
-    pub mark_price: f64,
-    #[serde(with = "string_or_float")]
-    pub last_funding_rate: f64,
+    pub pair: Option<String>,
+    #[serde(with = "string_or_float")]
+    pub mark_price: Number,
+    // Empty for COIN-M delivery contracts
+    #[serde(
+        serialize_with = "string_or_float::serialize",
+        deserialize_with = "empty_or_float"
+    )]
+    pub last_funding_rate: Number,
+    #[serde(with = "string_or_float", default)]
+    pub index_price: Number,
+    #[serde(with = "string_or_float", default)]
+    pub estimated_settle_price: Number,
+    #[serde(
+        default,
+        serialize_with = "string_or_float::serialize",
+        deserialize_with = "empty_or_float"
+    )]
+    pub interest_rate: Number,
     pub next_funding_time: u64,
     pub time: u64,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FundingRate {
+    pub symbol: String,
+    #[serde(with = "string_or_float")]
+    pub funding_rate: Number,
+    pub funding_time: u64,
+    // Empty for the oldest rates
+    #[serde(
+        default,
+        serialize_with = "string_or_float::serialize",
+        deserialize_with = "empty_or_float"
+    )]
+    pub mark_price: Number,
+}
+
+/// Funding rate cap, floor and interval of a symbol whose funding was adjusted.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FundingInfo {
+    pub symbol: String,
+    #[serde(with = "string_or_float")]
+    pub adjusted_funding_rate_cap: Number,
+    #[serde(with = "string_or_float")]
+    pub adjusted_funding_rate_floor: Number,
+    pub funding_interval_hours: u64,
+    #[serde(default)]
+    pub disclaimer: bool,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(untagged)]
 pub enum LiquidationOrders {
@@ -174,13 +228,13 @@ pub enum LiquidationOrders {
 #[serde(rename_all = "camelCase")]
 pub struct LiquidationOrder {
     #[serde(with = "string_or_float")]
-    pub average_price: f64,
+    pub average_price: Number,
     #[serde(with = "string_or_float")]
-    pub executed_qty: f64,
+    pub executed_qty: Number,
     #[serde(with = "string_or_float")]
-    pub orig_qty: f64,
+    pub orig_qty: Number,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Number,
     pub side: String,
     pub status: String,
     pub symbol: String,
@@ -193,8 +247,9 @@ pub struct LiquidationOrder {
 #[serde(rename_all = "camelCase")]
 pub struct OpenInterest {
     #[serde(with = "string_or_float")]
-    pub open_interest: f64,
+    pub open_interest: Number,
     pub symbol: String,
+    pub pair: Option<String>,
 }
 
 #[derive(Debug, Deserialize, PartialEq, Eq)]
@@ -206,29 +261,50 @@ pub struct OpenInterestHist {
     pub timestamp: u64,
 }
 
+/// Share of long and short accounts, or of long and short positions for the top traders'
+/// position ratio
+#[derive(Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct LongShortRatio {
+    pub symbol: String,
+    pub long_short_ratio: String,
+    pub long_account: String,
+    pub short_account: String,
+    pub timestamp: u64,
+}
+
+#[derive(Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct TakerBuySellVolume {
+    pub buy_sell_ratio: String,
+    pub buy_vol: String,
+    pub sell_vol: String,
+    pub timestamp: u64,
+}
+
 #[derive(Debug, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Order {
     pub client_order_id: String,
     #[serde(with = "string_or_float", default = "default_stop_price")]
-    pub cum_qty: f64,
+    pub cum_qty: Number,
     #[serde(with = "string_or_float")]
-    pub cum_quote: f64,
+    pub cum_quote: Number,
     #[serde(with = "string_or_float")]
-    pub executed_qty: f64,
+    pub executed_qty: Number,
     pub order_id: u64,
     #[serde(with = "string_or_float")]
-    pub avg_price: f64,
+    pub avg_price: Number,
     #[serde(with = "string_or_float")]
-    pub orig_qty: f64,
+    pub orig_qty: Number,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Number,
     pub side: String,
     pub reduce_only: bool,
     pub position_side: String,
     pub status: String,
     #[serde(with = "string_or_float", default = "default_stop_price")]
-    pub stop_price: f64,
+    pub stop_price: Number,
     pub close_position: bool,
     pub symbol: String,
     pub time_in_force: String,
@@ -236,35 +312,74 @@ pub struct Order {
     pub order_type: String,
     pub orig_type: String,
     #[serde(with = "string_or_float", default = "default_activation_price")]
-    pub activation_price: f64,
+    pub activation_price: Number,
     #[serde(with = "string_or_float", default = "default_price_rate")]
-    pub price_rate: f64,
+    pub price_rate: Number,
     pub update_time: u64,
     pub working_type: String,
     pub price_protect: bool,
 }
 
+/// Price and quantity change of one modification of an order
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderAmendment {
+    pub amendment_id: u64,
+    pub symbol: String,
+    pub pair: String,
+    pub order_id: u64,
+    pub client_order_id: String,
+    pub time: u64,
+    pub amendment: Amendment,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Amendment {
+    pub price: AmendedValue,
+    pub orig_qty: AmendedValue,
+    // Number of modifications of the order so far
+    pub count: u32,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct AmendedValue {
+    #[serde(with = "string_or_float")]
+    pub before: Number,
+    #[serde(with = "string_or_float")]
+    pub after: Number,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CountdownCancelAll {
+    pub symbol: String,
+    // Milliseconds, 0 once the countdown is cancelled
+    #[serde(with = "string_or_float")]
+    pub countdown_time: Number,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Transaction {
     pub client_order_id: String,
     #[serde(with = "string_or_float")]
-    pub cum_qty: f64,
+    pub cum_qty: Number,
     #[serde(with = "string_or_float")]
-    pub cum_quote: f64,
+    pub cum_quote: Number,
     #[serde(with = "string_or_float")]
-    pub executed_qty: f64,
+    pub executed_qty: Number,
     pub order_id: u64,
     #[serde(with = "string_or_float")]
-    pub avg_price: f64,
+    pub avg_price: Number,
     #[serde(with = "string_or_float")]
-    pub orig_qty: f64,
+    pub orig_qty: Number,
     pub reduce_only: bool,
     pub side: String,
     pub position_side: String,
     pub status: String,
     #[serde(with = "string_or_float")]
-    pub stop_price: f64,
+    pub stop_price: Number,
     pub close_position: bool,
     pub symbol: String,
     pub time_in_force: String,
@@ -273,10 +388,10 @@ pub struct Transaction {
     pub orig_type: String,
     #[serde(default)]
     #[serde(with = "string_or_float_opt")]
-    pub activate_price: Option<f64>,
+    pub activate_price: Option<Number>,
     #[serde(default)]
     #[serde(with = "string_or_float_opt")]
-    pub price_rate: Option<f64>,
+    pub price_rate: Option<Number>,
     pub update_time: u64,
     pub working_type: String,
     price_protect: bool,
@@ -287,23 +402,23 @@ pub struct Transaction {
 pub struct CanceledOrder {
     pub client_order_id: String,
     #[serde(with = "string_or_float")]
-    pub cum_qty: f64,
+    pub cum_qty: Number,
     #[serde(with = "string_or_float")]
-    pub cum_quote: f64,
+    pub cum_quote: Number,
     #[serde(with = "string_or_float")]
-    pub executed_qty: f64,
+    pub executed_qty: Number,
     pub order_id: u64,
     #[serde(with = "string_or_float")]
-    pub orig_qty: f64,
+    pub orig_qty: Number,
     pub orig_type: String,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Number,
     pub reduce_only: bool,
     pub side: String,
     pub position_side: String,
     pub status: String,
     #[serde(with = "string_or_float")]
-    pub stop_price: f64,
+    pub stop_price: Number,
     pub close_position: bool,
     pub symbol: String,
     pub time_in_force: String,
@@ -311,10 +426,10 @@ pub struct CanceledOrder {
     pub type_name: String,
     #[serde(default)]
     #[serde(with = "string_or_float_opt")]
-    pub activate_price: Option<f64>,
+    pub activate_price: Option<Number>,
     #[serde(default)]
     #[serde(with = "string_or_float_opt")]
-    pub price_rate: Option<f64>,
+    pub price_rate: Option<Number>,
     pub update_time: u64,
     pub working_type: String,
     price_protect: bool,
@@ -324,29 +439,29 @@ pub struct CanceledOrder {
 #[serde(rename_all = "camelCase")]
 pub struct PositionRisk {
     #[serde(with = "string_or_float")]
-    pub entry_price: f64,
+    pub entry_price: Number,
     pub margin_type: String,
     #[serde(with = "string_or_bool")]
     pub is_auto_add_margin: bool,
     #[serde(with = "string_or_float")]
-    pub isolated_margin: f64,
+    pub isolated_margin: Number,
     pub leverage: String,
     #[serde(with = "string_or_float")]
-    pub liquidation_price: f64,
+    pub liquidation_price: Number,
     #[serde(with = "string_or_float")]
-    pub mark_price: f64,
+    pub mark_price: Number,
     #[serde(with = "string_or_float")]
-    pub max_notional_value: f64,
+    pub max_notional_value: Number,
     #[serde(with = "string_or_float", rename = "positionAmt")]
-    pub position_amount: f64,
+    pub position_amount: Number,
     pub symbol: String,
     #[serde(with = "string_or_float", rename = "unRealizedProfit")]
-    pub unrealized_profit: f64,
+    pub unrealized_profit: Number,
     pub position_side: String,
     #[serde(with = "string_or_float")]
-    pub notional: f64,
+    pub notional: Number,
     #[serde(with = "string_or_float")]
-    pub isolated_wallet: f64,
+    pub isolated_wallet: Number,
     pub update_time: u64,
 }
 
@@ -355,27 +470,27 @@ pub struct PositionRisk {
 pub struct FuturesAsset {
     pub asset: String,
     #[serde(with = "string_or_float")]
-    pub wallet_balance: f64,
+    pub wallet_balance: Number,
     #[serde(with = "string_or_float")]
-    pub unrealized_profit: f64,
+    pub unrealized_profit: Number,
     #[serde(with = "string_or_float")]
-    pub margin_balance: f64,
+    pub margin_balance: Number,
     #[serde(with = "string_or_float")]
-    pub maint_margin: f64,
+    pub maint_margin: Number,
     #[serde(with = "string_or_float")]
-    pub initial_margin: f64,
+    pub initial_margin: Number,
     #[serde(with = "string_or_float")]
-    pub position_initial_margin: f64,
+    pub position_initial_margin: Number,
     #[serde(with = "string_or_float")]
-    pub open_order_initial_margin: f64,
+    pub open_order_initial_margin: Number,
     #[serde(with = "string_or_float")]
-    pub max_withdraw_amount: f64,
+    pub max_withdraw_amount: Number,
     #[serde(with = "string_or_float")]
-    pub cross_wallet_balance: f64,
+    pub cross_wallet_balance: Number,
     #[serde(with = "string_or_float")]
-    pub cross_un_pnl: f64,
+    pub cross_un_pnl: Number,
     #[serde(with = "string_or_float")]
-    pub available_balance: f64,
+    pub available_balance: Number,
     #[serde(with = "string_or_bool")]
     pub margin_available: bool,
     pub update_time: u64,
@@ -386,41 +501,41 @@ pub struct FuturesAsset {
 pub struct FuturesPosition {
     pub symbol: String,
     #[serde(with = "string_or_float")]
-    pub initial_margin: f64,
+    pub initial_margin: Number,
     #[serde(with = "string_or_float")]
-    pub maint_margin: f64,
+    pub maint_margin: Number,
     #[serde(with = "string_or_float")]
-    pub unrealized_profit: f64,
+    pub unrealized_profit: Number,
     #[serde(with = "string_or_float")]
-    pub position_initial_margin: f64,
+    pub position_initial_margin: Number,
     #[serde(with = "string_or_float")]
-    pub open_order_initial_margin: f64,
+    pub open_order_initial_margin: Number,
     pub leverage: String,
     #[serde(with = "string_or_bool")]
     pub isolated: bool,
     #[serde(with = "string_or_float")]
-    pub entry_price: f64,
+    pub entry_price: Number,
     #[serde(with = "string_or_float")]
-    pub max_notional: f64,
+    pub max_notional: Number,
     pub position_side: String,
     #[serde(with = "string_or_float", rename = "positionAmt")]
-    pub position_amount: f64,
+    pub position_amount: Number,
     #[serde(with = "string_or_float")]
-    pub notional: f64,
+    pub notional: Number,
     #[serde(with = "string_or_float")]
-    pub isolated_wallet: f64,
+    pub isolated_wallet: Number,
     pub update_time: u64,
     #[serde(with = "string_or_float")]
-    pub bid_notional: f64,
+    pub bid_notional: Number,
     #[serde(with = "string_or_float")]
-    pub ask_notional: f64,
+    pub ask_notional: Number,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct AccountInformation {
     #[serde(with = "string_or_float")]
-    pub fee_tier: f64,
+    pub fee_tier: Number,
     #[serde(with = "string_or_bool")]
     pub can_trade: bool,
     #[serde(with = "string_or_bool")]
@@ -428,29 +543,29 @@ pub struct AccountInformation {
     #[serde(with = "string_or_bool")]
     pub can_withdraw: bool,
     #[serde(with = "string_or_float")]
-    pub update_time: f64,
+    pub update_time: Number,
     #[serde(with = "string_or_float")]
-    pub total_initial_margin: f64,
+    pub total_initial_margin: Number,
     #[serde(with = "string_or_float")]
-    pub total_maint_margin: f64,
+    pub total_maint_margin: Number,
     #[serde(with = "string_or_float")]
-    pub total_wallet_balance: f64,
+    pub total_wallet_balance: Number,
     #[serde(with = "string_or_float")]
-    pub total_unrealized_profit: f64,
+    pub total_unrealized_profit: Number,
     #[serde(with = "string_or_float")]
-    pub total_margin_balance: f64,
+    pub total_margin_balance: Number,
     #[serde(with = "string_or_float")]
-    pub total_position_initial_margin: f64,
+    pub total_position_initial_margin: Number,
     #[serde(with = "string_or_float")]
-    pub total_open_order_initial_margin: f64,
+    pub total_open_order_initial_margin: Number,
     #[serde(with = "string_or_float")]
-    pub total_cross_wallet_balance: f64,
+    pub total_cross_wallet_balance: Number,
     #[serde(with = "string_or_float")]
-    pub total_cross_un_pnl: f64,
+    pub total_cross_un_pnl: Number,
     #[serde(with = "string_or_float")]
-    pub available_balance: f64,
+    pub available_balance: Number,
     #[serde(with = "string_or_float")]
-    pub max_withdraw_amount: f64,
+    pub max_withdraw_amount: Number,
     pub assets: Vec<FuturesAsset>,
     pub positions: Vec<FuturesPosition>,
 }
@@ -461,15 +576,15 @@ pub struct AccountBalance {
     pub account_alias: String,
     pub asset: String,
     #[serde(with = "string_or_float")]
-    pub balance: f64,
+    pub balance: Number,
     #[serde(with = "string_or_float")]
-    pub cross_wallet_balance: f64,
+    pub cross_wallet_balance: Number,
     #[serde(with = "string_or_float", rename = "crossUnPnl")]
-    pub cross_unrealized_pnl: f64,
+    pub cross_unrealized_pnl: Number,
     #[serde(with = "string_or_float")]
-    pub available_balance: f64,
+    pub available_balance: Number,
     #[serde(with = "string_or_float")]
-    pub max_withdraw_amount: f64,
+    pub max_withdraw_amount: Number,
     pub margin_available: bool,
     pub update_time: u64,
 }
@@ -479,18 +594,552 @@ pub struct AccountBalance {
 pub struct ChangeLeverageResponse {
     pub leverage: u8,
     #[serde(with = "string_or_float")]
-    pub max_notional_value: f64,
+    pub max_notional_value: Number,
     pub symbol: String,
 }
 
-fn default_stop_price() -> f64 {
-    0.0
+/// Contracts a futures client trades, picking the models its answers are parsed into
+pub trait FuturesContracts: Clone + Send + Sync + 'static {
+    type Order: DeserializeOwned;
+    type Transaction: DeserializeOwned;
+    type CanceledOrder: DeserializeOwned;
+    type PriceStats: DeserializeOwned;
+    type Trade: DeserializeOwned;
+    type TradeHistory: DeserializeOwned;
+    type PositionRisk: DeserializeOwned;
+    type AccountInformation: DeserializeOwned;
+    type AccountBalance: DeserializeOwned;
+    type ChangeLeverageResponse: DeserializeOwned;
 }
-fn default_activation_price() -> f64 {
-    0.0
+
+/// USDⓈ-M futures, margined and settled in USDT or BUSD
+#[derive(Debug, Clone, Copy, Default)]
+pub struct UsdM;
+
+/// COIN-M futures, margined and settled in the base asset, quantities in contracts
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CoinM;
+
+impl FuturesContracts for UsdM {
+    type Order = Order;
+    type Transaction = Transaction;
+    type CanceledOrder = CanceledOrder;
+    type PriceStats = PriceStats;
+    type Trade = Trade;
+    type TradeHistory = TradeHistory;
+    type PositionRisk = PositionRisk;
+    type AccountInformation = AccountInformation;
+    type AccountBalance = AccountBalance;
+    type ChangeLeverageResponse = ChangeLeverageResponse;
+}
+
+impl FuturesContracts for CoinM {
+    type Order = CoinOrder;
+    type Transaction = CoinTransaction;
+    type CanceledOrder = CoinCanceledOrder;
+    type PriceStats = CoinPriceStats;
+    type Trade = CoinTrade;
+    type TradeHistory = CoinTradeHistory;
+    type PositionRisk = CoinPositionRisk;
+    type AccountInformation = CoinAccountInformation;
+    type AccountBalance = CoinAccountBalance;
+    type ChangeLeverageResponse = CoinChangeLeverageResponse;
 }
-fn default_price_rate() -> f64 {
-    0.0
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CoinPriceStats {
+    pub symbol: String,
+    pub pair: String,
+    pub price_change: String,
+    pub price_change_percent: String,
+    pub weighted_avg_price: String,
+    #[serde(with = "string_or_float")]
+    pub last_price: Number,
+    #[serde(with = "string_or_float")]
+    pub open_price: Number,
+    #[serde(with = "string_or_float")]
+    pub high_price: Number,
+    #[serde(with = "string_or_float")]
+    pub low_price: Number,
+    // In contracts
+    #[serde(with = "string_or_float")]
+    pub volume: Number,
+    #[serde(with = "string_or_float")]
+    pub base_volume: Number,
+    #[serde(with = "string_or_float")]
+    pub last_qty: Number,
+    pub open_time: u64,
+    pub close_time: u64,
+    pub first_id: u64,
+    pub last_id: u64,
+    pub count: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CoinTrade {
+    pub id: u64,
+    pub is_buyer_maker: bool,
+    #[serde(with = "string_or_float")]
+    pub price: Number,
+    #[serde(with = "string_or_float")]
+    pub qty: Number,
+    #[serde(with = "string_or_float")]
+    pub base_qty: Number,
+    pub time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CoinTradeHistory {
+    pub buyer: bool,
+    #[serde(with = "string_or_float")]
+    pub commission: Number,
+    pub commission_asset: String,
+    pub id: u64,
+    pub maker: bool,
+    pub order_id: u64,
+    #[serde(with = "string_or_float")]
+    pub price: Number,
+    #[serde(with = "string_or_float")]
+    pub qty: Number,
+    #[serde(with = "string_or_float")]
+    pub base_qty: Number,
+    #[serde(with = "string_or_float")]
+    pub realized_pnl: Number,
+    pub side: String,
+    pub position_side: String,
+    pub symbol: String,
+    pub pair: String,
+    pub margin_asset: String,
+    pub time: u64,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CoinOrder {
+    pub client_order_id: String,
+    #[serde(with = "string_or_float", default = "default_stop_price")]
+    pub cum_qty: Number,
+    #[serde(with = "string_or_float")]
+    pub cum_base: Number,
+    #[serde(with = "string_or_float")]
+    pub executed_qty: Number,
+    pub order_id: u64,
+    #[serde(with = "string_or_float")]
+    pub avg_price: Number,
+    #[serde(with = "string_or_float")]
+    pub orig_qty: Number,
+    #[serde(with = "string_or_float")]
+    pub price: Number,
+    pub side: String,
+    pub reduce_only: bool,
+    pub position_side: String,
+    pub status: String,
+    #[serde(with = "string_or_float", default = "default_stop_price")]
+    pub stop_price: Number,
+    pub close_position: bool,
+    pub symbol: String,
+    pub pair: String,
+    pub time_in_force: String,
+    #[serde(rename = "type")]
+    pub order_type: String,
+    pub orig_type: String,
+    #[serde(with = "string_or_float", default = "default_activation_price")]
+    pub activation_price: Number,
+    #[serde(with = "string_or_float", default = "default_price_rate")]
+    pub price_rate: Number,
+    pub update_time: u64,
+    pub working_type: String,
+    pub price_protect: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CoinTransaction {
+    pub client_order_id: String,
+    #[serde(with = "string_or_float")]
+    pub cum_qty: Number,
+    #[serde(with = "string_or_float")]
+    pub cum_base: Number,
+    #[serde(with = "string_or_float")]
+    pub executed_qty: Number,
+    pub order_id: u64,
+    #[serde(with = "string_or_float")]
+    pub avg_price: Number,
+    #[serde(with = "string_or_float")]
+    pub orig_qty: Number,
+    pub reduce_only: bool,
+    pub side: String,
+    pub position_side: String,
+    pub status: String,
+    #[serde(with = "string_or_float")]
+    pub stop_price: Number,
+    pub close_position: bool,
+    pub symbol: String,
+    pub pair: String,
+    pub time_in_force: String,
+    #[serde(rename = "type")]
+    pub type_name: String,
+    pub orig_type: String,
+    #[serde(default)]
+    #[serde(with = "string_or_float_opt")]
+    pub activate_price: Option<Number>,
+    #[serde(default)]
+    #[serde(with = "string_or_float_opt")]
+    pub price_rate: Option<Number>,
+    pub update_time: u64,
+    pub working_type: String,
+    pub price_protect: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CoinCanceledOrder {
+    pub client_order_id: String,
+    #[serde(with = "string_or_float")]
+    pub cum_qty: Number,
+    #[serde(with = "string_or_float")]
+    pub cum_base: Number,
+    #[serde(with = "string_or_float")]
+    pub executed_qty: Number,
+    pub order_id: u64,
+    #[serde(with = "string_or_float")]
+    pub orig_qty: Number,
+    pub orig_type: String,
+    #[serde(with = "string_or_float")]
+    pub price: Number,
+    pub reduce_only: bool,
+    pub side: String,
+    pub position_side: String,
+    pub status: String,
+    #[serde(with = "string_or_float")]
+    pub stop_price: Number,
+    pub close_position: bool,
+    pub symbol: String,
+    pub pair: String,
+    pub time_in_force: String,
+    #[serde(rename = "type")]
+    pub type_name: String,
+    #[serde(default)]
+    #[serde(with = "string_or_float_opt")]
+    pub activate_price: Option<Number>,
+    #[serde(default)]
+    #[serde(with = "string_or_float_opt")]
+    pub price_rate: Option<Number>,
+    pub update_time: u64,
+    pub working_type: String,
+    pub price_protect: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CoinPositionRisk {
+    #[serde(with = "string_or_float")]
+    pub entry_price: Number,
+    pub margin_type: String,
+    #[serde(with = "string_or_bool")]
+    pub is_auto_add_margin: bool,
+    #[serde(with = "string_or_float")]
+    pub isolated_margin: Number,
+    pub leverage: String,
+    #[serde(with = "string_or_float")]
+    pub liquidation_price: Number,
+    #[serde(with = "string_or_float")]
+    pub mark_price: Number,
+    // Positions are capped in contracts rather than notional
+    #[serde(with = "string_or_float")]
+    pub max_qty: Number,
+    #[serde(with = "string_or_float", rename = "positionAmt")]
+    pub position_amount: Number,
+    pub symbol: String,
+    #[serde(with = "string_or_float", rename = "unRealizedProfit")]
+    pub unrealized_profit: Number,
+    pub position_side: String,
+    // In the base asset
+    #[serde(with = "string_or_float")]
+    pub notional_value: Number,
+    #[serde(with = "string_or_float")]
+    pub isolated_wallet: Number,
+    pub update_time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CoinFuturesAsset {
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub wallet_balance: Number,
+    #[serde(with = "string_or_float")]
+    pub unrealized_profit: Number,
+    #[serde(with = "string_or_float")]
+    pub margin_balance: Number,
+    #[serde(with = "string_or_float")]
+    pub maint_margin: Number,
+    #[serde(with = "string_or_float")]
+    pub initial_margin: Number,
+    #[serde(with = "string_or_float")]
+    pub position_initial_margin: Number,
+    #[serde(with = "string_or_float")]
+    pub open_order_initial_margin: Number,
+    #[serde(with = "string_or_float")]
+    pub max_withdraw_amount: Number,
+    #[serde(with = "string_or_float")]
+    pub cross_wallet_balance: Number,
+    #[serde(with = "string_or_float")]
+    pub cross_un_pnl: Number,
+    #[serde(with = "string_or_float")]
+    pub available_balance: Number,
+    pub update_time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CoinFuturesPosition {
+    pub symbol: String,
+    #[serde(with = "string_or_float")]
+    pub initial_margin: Number,
+    #[serde(with = "string_or_float")]
+    pub maint_margin: Number,
+    #[serde(with = "string_or_float")]
+    pub unrealized_profit: Number,
+    #[serde(with = "string_or_float")]
+    pub position_initial_margin: Number,
+    #[serde(with = "string_or_float")]
+    pub open_order_initial_margin: Number,
+    pub leverage: String,
+    #[serde(with = "string_or_bool")]
+    pub isolated: bool,
+    #[serde(with = "string_or_float")]
+    pub entry_price: Number,
+    #[serde(with = "string_or_float")]
+    pub max_qty: Number,
+    pub position_side: String,
+    #[serde(with = "string_or_float", rename = "positionAmt")]
+    pub position_amount: Number,
+    pub update_time: u64,
+}
+
+// Margin totals are per asset, COIN-M has no account wide ones
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CoinAccountInformation {
+    #[serde(with = "string_or_float")]
+    pub fee_tier: Number,
+    #[serde(with = "string_or_bool")]
+    pub can_trade: bool,
+    #[serde(with = "string_or_bool")]
+    pub can_deposit: bool,
+    #[serde(with = "string_or_bool")]
+    pub can_withdraw: bool,
+    #[serde(with = "string_or_float")]
+    pub update_time: Number,
+    pub assets: Vec<CoinFuturesAsset>,
+    pub positions: Vec<CoinFuturesPosition>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CoinAccountBalance {
+    pub account_alias: String,
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub balance: Number,
+    #[serde(with = "string_or_float")]
+    pub withdraw_available: Number,
+    #[serde(with = "string_or_float")]
+    pub cross_wallet_balance: Number,
+    #[serde(with = "string_or_float", rename = "crossUnPnl")]
+    pub cross_unrealized_pnl: Number,
+    #[serde(with = "string_or_float")]
+    pub available_balance: Number,
+    pub update_time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CoinChangeLeverageResponse {
+    pub leverage: u8,
+    #[serde(with = "string_or_float")]
+    pub max_qty: Number,
+    pub symbol: String,
+}
+
+/// Notional tiers of a symbol; COIN-M tiers are capped in quantity instead of notional
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LeverageBracket {
+    pub symbol: String,
+    // Only set for accounts whose brackets were adjusted
+    #[serde(default, with = "string_or_float_opt")]
+    pub notional_coef: Option<Number>,
+    pub brackets: Vec<Bracket>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Bracket {
+    pub bracket: u16,
+    pub initial_leverage: u8,
+    #[serde(with = "string_or_float", default)]
+    pub notional_cap: Number,
+    #[serde(with = "string_or_float", default)]
+    pub notional_floor: Number,
+    #[serde(default, with = "string_or_float_opt")]
+    pub qty_cap: Option<Number>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub qty_floor: Option<Number>,
+    #[serde(with = "string_or_float")]
+    pub maint_margin_ratio: Number,
+    // Maintenance amount, deducted from the notional times `maint_margin_ratio`
+    #[serde(with = "string_or_float")]
+    pub cum: Number,
+}
+
+/// Largest position a wallet balance can open at one leverage and price.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PositionSizing {
+    pub leverage: u8,
+    pub notional: Number,
+    pub qty: Number,
+    pub initial_margin: Number,
+    pub maint_margin_ratio: Number,
+    pub maint_amount: Number,
+    /// Zero when the position cannot be liquidated, e.g. a long at a leverage of 1
+    pub liquidation_price: Number,
+}
+
+impl LeverageBracket {
+    /// Bracket a position of `notional` falls into, none above the last cap
+    pub fn bracket_for_notional(&self, notional: Number) -> Option<&Bracket> {
+        self.brackets
+            .iter()
+            .find(|bracket| notional <= bracket.notional_cap)
+    }
+
+    /// Highest notional that can be held at `leverage`, none when the leverage is not allowed
+    pub fn max_notional(&self, leverage: u8) -> Option<Number> {
+        self.brackets
+            .iter()
+            .filter(|bracket| leverage > 0 && bracket.initial_leverage >= leverage)
+            .map(|bracket| bracket.notional_cap)
+            .fold(None, |max, cap| match max {
+                Some(max) if max >= cap => Some(max),
+                _ => Some(cap),
+            })
+    }
+
+    /// Size of the largest position `wallet_balance` can open at `leverage` and `price`.
+    ///
+    /// The position is taken as the only one of the account and margined by the whole
+    /// `wallet_balance` (`total_wallet_balance` of `account_information`), so the liquidation
+    /// price is an estimate that ignores fees and funding.
+    pub fn position_sizing(
+        &self, leverage: u8, wallet_balance: Number, price: Number, side: crate::account::OrderSide,
+    ) -> crate::errors::Result<PositionSizing> {
+        let zero = Number::default();
+        if self
+            .brackets
+            .iter()
+            .any(|bracket| bracket.qty_cap.is_some())
+        {
+            error_chain::bail!("Position sizing needs the notional brackets of USDⓈ-M futures");
+        }
+        if price <= zero || wallet_balance <= zero {
+            error_chain::bail!("Position sizing needs a positive price and wallet balance");
+        }
+        let max_notional = match self.max_notional(leverage) {
+            Some(max_notional) => max_notional,
+            None => error_chain::bail!("{} does not allow a leverage of {}", self.symbol, leverage),
+        };
+
+        let leverage_number = leverage.into_number();
+        let mut notional = wallet_balance * leverage_number;
+        if notional > max_notional {
+            notional = max_notional;
+        }
+        let bracket = match self.bracket_for_notional(notional) {
+            Some(bracket) => bracket,
+            None => error_chain::bail!("No bracket of {} holds {}", self.symbol, notional),
+        };
+        let qty = notional / price;
+
+        // Price at which the margin balance falls to the maintenance margin:
+        // wallet + cum + side * qty * (liquidation - price) = qty * liquidation * ratio
+        let one = 1.into_number();
+        let liquidation_price = match side {
+            crate::account::OrderSide::Buy => {
+                (wallet_balance + bracket.cum - notional)
+                    / (qty * (bracket.maint_margin_ratio - one))
+            }
+            crate::account::OrderSide::Sell => {
+                (wallet_balance + bracket.cum + notional)
+                    / (qty * (bracket.maint_margin_ratio + one))
+            }
+        };
+
+        Ok(PositionSizing {
+            leverage,
+            notional,
+            qty,
+            initial_margin: notional / leverage_number,
+            maint_margin_ratio: bracket.maint_margin_ratio,
+            maint_amount: bracket.cum,
+            liquidation_price: if liquidation_price < zero {
+                zero
+            } else {
+                liquidation_price
+            },
+        })
+    }
+}
+
+fn default_stop_price() -> Number {
+    Number::default()
+}
+fn default_activation_price() -> Number {
+    Number::default()
+}
+fn default_price_rate() -> Number {
+    Number::default()
+}
+
+fn empty_or_float<'de, D>(deserializer: D) -> Result<Number, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match Value::deserialize(deserializer)? {
+        Value::String(s) if s.is_empty() => Ok(Number::default()),
+        value => string_or_float::deserialize(value).map_err(de::Error::custom),
+    }
+}
+
+// COIN-M answers the tickers of a single symbol with a list
+#[derive(Deserialize)]
+#[serde(untagged)]
+pub(crate) enum OneOrMany<T> {
+    One(T),
+    Many(Vec<T>),
+}
+
+impl<T> OneOrMany<T> {
+    pub(crate) fn into_one(self) -> Result<T, crate::errors::Error> {
+        match self {
+            OneOrMany::One(one) => Ok(one),
+            OneOrMany::Many(many) => many
+                .into_iter()
+                .next()
+                .ok_or_else(|| "Empty response".into()),
+        }
+    }
+
+    pub(crate) fn into_many(self) -> Vec<T> {
+        match self {
+            OneOrMany::One(one) => vec![one],
+            OneOrMany::Many(many) => many,
+        }
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -618,7 +1267,7 @@ pub struct Income {
     pub symbol: String,
     pub income_type: String,
     #[serde(with = "string_or_float")]
-    pub income: f64,
+    pub income: Number,
     pub asset: String,
     pub info: String,
     pub time: u64,
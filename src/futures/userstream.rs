@@ -1,6 +1,10 @@
 use crate::model::{Success, UserDataStream};
 use crate::client::Client;
+use crate::config::Config;
 use crate::errors::Result;
+use crate::futures::websockets::{parse_msg, FuturesWebsocketEvent};
+use crate::userstream::UserDataStreamApi;
+use crate::websockets::ReconnectedEvent;
 use crate::api::API;
 use crate::api::Futures;
 
@@ -26,3 +30,43 @@ impl FuturesUserStream {
             .delete(API::Futures(Futures::UserDataStream), listen_key)
     }
 }
+
+impl UserDataStreamApi for FuturesUserStream {
+    type Event = FuturesWebsocketEvent;
+
+    fn start(&self) -> Result<UserDataStream> {
+        FuturesUserStream::start(self)
+    }
+
+    fn keep_alive(&self, listen_key: &str) -> Result<Success> {
+        FuturesUserStream::keep_alive(self, listen_key)
+    }
+
+    fn close(&self, listen_key: &str) -> Result<Success> {
+        FuturesUserStream::close(self, listen_key)
+    }
+
+    fn ws_endpoint(&self, config: &Config) -> String {
+        if self.client.is_coin_futures() {
+            config.coin_futures_ws_endpoint.clone()
+        } else {
+            config.futures_ws_endpoint.clone()
+        }
+    }
+
+    fn parse_event(msg: &str) -> Result<Option<FuturesWebsocketEvent>> {
+        parse_msg(msg)
+    }
+
+    fn is_expired(event: &FuturesWebsocketEvent) -> bool {
+        matches!(
+            event,
+            FuturesWebsocketEvent::UserDataStreamExpiredEvent(expired)
+                if expired.event_type == "listenKeyExpired"
+        )
+    }
+
+    fn reconnected(event: ReconnectedEvent) -> FuturesWebsocketEvent {
+        FuturesWebsocketEvent::Reconnected(event)
+    }
+}
@@ -6,18 +6,18 @@ use crate::model::{
     MarkPriceEvent, MiniTickerEvent, OrderBook, TradeEvent, UserDataStreamExpiredEvent,
 };
 use crate::futures::model;
+use crate::websockets::{
+    connect_socket, reconnect_with_backoff, ConnectionStats, ReconnectConfig, ReconnectedEvent,
+    Socket,
+};
 use error_chain::bail;
-use url::Url;
 use serde::{Deserialize, Serialize};
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::net::TcpStream;
-use tungstenite::{connect, Message};
-use tungstenite::protocol::WebSocket;
-use tungstenite::stream::MaybeTlsStream;
-use tungstenite::handshake::client::Response;
+use std::time::SystemTime;
+use tungstenite::Message;
 
 #[allow(clippy::all)]
-enum FuturesWebsocketAPI {
+pub(crate) enum FuturesWebsocketAPI {
     Default,
     MultiStream,
     Custom(String),
@@ -30,7 +30,7 @@ pub enum FuturesMarket {
 }
 
 impl FuturesWebsocketAPI {
-    fn params(self, market: &FuturesMarket, subscription: &str) -> String {
+    pub(crate) fn params(self, market: &FuturesMarket, subscription: &str) -> String {
         let baseurl = match market {
             FuturesMarket::USDM => "wss://fstream.binance.com",
             FuturesMarket::COINM => "wss://dstream.binance.com",
@@ -71,11 +71,15 @@ pub enum FuturesWebsocketEvent {
     DepthOrderBook(DepthOrderBookEvent),
     BookTicker(BookTickerEvent),
     UserDataStreamExpiredEvent(UserDataStreamExpiredEvent),
+    Reconnected(ReconnectedEvent),
 }
 
 pub struct FuturesWebSockets<'a> {
-    pub socket: Option<(WebSocket<MaybeTlsStream<TcpStream>>, Response)>,
+    pub socket: Option<Socket>,
     handler: Box<dyn FnMut(FuturesWebsocketEvent) -> Result<()> + 'a>,
+    wss: Option<String>,
+    reconnect: Option<ReconnectConfig>,
+    stats: ConnectionStats,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -110,9 +114,24 @@ impl<'a> FuturesWebSockets<'a> {
         FuturesWebSockets {
             socket: None,
             handler: Box::new(handler),
+            wss: None,
+            reconnect: None,
+            stats: ConnectionStats::default(),
         }
     }
 
+    /// Reconnect and resubscribe with the given backoff whenever the connection drops.
+    ///
+    /// A `FuturesWebsocketEvent::Reconnected` is delivered to the handler after every reconnection.
+    pub fn with_reconnect(mut self, reconnect: ReconnectConfig) -> Self {
+        self.reconnect = Some(reconnect);
+        self
+    }
+
+    pub fn stats(&self) -> &ConnectionStats {
+        &self.stats
+    }
+
     pub fn connect(&mut self, market: &FuturesMarket, subscription: &'a str) -> Result<()> {
         self.connect_wss(&FuturesWebsocketAPI::Default.params(market, subscription))
     }
@@ -132,14 +151,30 @@ impl<'a> FuturesWebSockets<'a> {
     }
 
     fn connect_wss(&mut self, wss: &str) -> Result<()> {
-        let url = Url::parse(wss)?;
-        match connect(url) {
-            Ok(answer) => {
-                self.socket = Some(answer);
-                Ok(())
-            }
-            Err(e) => bail!(format!("Error during handshake {}", e)),
+        self.socket = Some(connect_socket(wss)?);
+        self.wss = Some(wss.to_string());
+        self.stats.connects += 1;
+        self.stats.last_connected_at = Some(SystemTime::now());
+        Ok(())
+    }
+
+    fn reconnect(&mut self, running: &AtomicBool) -> Result<()> {
+        let (reconnect, wss) = match (&self.reconnect, &self.wss) {
+            (Some(reconnect), Some(wss)) => (reconnect.clone(), wss.clone()),
+            _ => bail!("Not able to reconnect"),
+        };
+        self.socket = None;
+        let reconnected = reconnect_with_backoff(&reconnect, &mut self.stats, running, || {
+            connect_socket(&wss)
+        })?;
+        if let Some((socket, attempts)) = reconnected {
+            self.socket = Some(socket);
+            (self.handler)(FuturesWebsocketEvent::Reconnected(ReconnectedEvent {
+                attempts,
+                reconnects: self.stats.reconnects,
+            }))?;
         }
+        Ok(())
     }
 
     pub fn disconnect(&mut self) -> Result<()> {
@@ -155,37 +190,7 @@ impl<'a> FuturesWebSockets<'a> {
     }
 
     pub fn handle_msg(&mut self, msg: &str) -> Result<()> {
-        let value: serde_json::Value = serde_json::from_str(msg)?;
-
-        if let Some(data) = value.get("data") {
-            self.handle_msg(&data.to_string())?;
-            return Ok(());
-        }
-
-        if let Ok(events) = serde_json::from_value::<FuturesEvents>(value) {
-            let action = match events {
-                FuturesEvents::Vec(v) => FuturesWebsocketEvent::DayTickerAll(v),
-                FuturesEvents::DayTickerEvent(v) => FuturesWebsocketEvent::DayTicker(v),
-                FuturesEvents::BookTickerEvent(v) => FuturesWebsocketEvent::BookTicker(v),
-                FuturesEvents::MiniTickerEvent(v) => FuturesWebsocketEvent::MiniTicker(v),
-                FuturesEvents::VecMiniTickerEvent(v) => FuturesWebsocketEvent::MiniTickerAll(v),
-                FuturesEvents::AccountUpdateEvent(v) => FuturesWebsocketEvent::AccountUpdate(v),
-                FuturesEvents::OrderTradeEvent(v) => FuturesWebsocketEvent::OrderTrade(v),
-                FuturesEvents::IndexPriceEvent(v) => FuturesWebsocketEvent::IndexPrice(v),
-                FuturesEvents::MarkPriceEvent(v) => FuturesWebsocketEvent::MarkPrice(v),
-                FuturesEvents::VecMarkPriceEvent(v) => FuturesWebsocketEvent::MarkPriceAll(v),
-                FuturesEvents::TradeEvent(v) => FuturesWebsocketEvent::Trade(v),
-                FuturesEvents::ContinuousKlineEvent(v) => FuturesWebsocketEvent::ContinuousKline(v),
-                FuturesEvents::IndexKlineEvent(v) => FuturesWebsocketEvent::IndexKline(v),
-                FuturesEvents::LiquidationEvent(v) => FuturesWebsocketEvent::Liquidation(v),
-                FuturesEvents::KlineEvent(v) => FuturesWebsocketEvent::Kline(v),
-                FuturesEvents::OrderBook(v) => FuturesWebsocketEvent::OrderBook(v),
-                FuturesEvents::DepthOrderBookEvent(v) => FuturesWebsocketEvent::DepthOrderBook(v),
-                FuturesEvents::AggrTradesEvent(v) => FuturesWebsocketEvent::AggrTrades(v),
-                FuturesEvents::UserDataStreamExpiredEvent(v) => {
-                    FuturesWebsocketEvent::UserDataStreamExpiredEvent(v)
-                }
-            };
+        if let Some(action) = parse_msg(msg)? {
             (self.handler)(action)?;
         }
         Ok(())
@@ -194,9 +199,17 @@ impl<'a> FuturesWebSockets<'a> {
     pub fn event_loop(&mut self, running: &AtomicBool) -> Result<()> {
         while running.load(Ordering::Relaxed) {
             if let Some(ref mut socket) = self.socket {
-                let message = socket.0.read_message()?;
+                let message = match socket.0.read_message() {
+                    Ok(message) => message,
+                    Err(e) if self.reconnect.is_none() => return Err(e.into()),
+                    Err(_) => {
+                        self.reconnect(running)?;
+                        continue;
+                    }
+                };
                 match message {
                     Message::Text(msg) => {
+                        self.stats.messages_received += 1;
                         if let Err(e) = self.handle_msg(&msg) {
                             bail!(format!("Error on handling stream message: {}", e));
                         }
@@ -205,10 +218,54 @@ impl<'a> FuturesWebSockets<'a> {
                         socket.0.write_message(Message::Pong(payload)).unwrap();
                     }
                     Message::Pong(_) | Message::Binary(_) | Message::Frame(_) => (),
-                    Message::Close(e) => bail!(format!("Disconnected {:?}", e)),
+                    Message::Close(e) => {
+                        if self.reconnect.is_none() {
+                            bail!(format!("Disconnected {:?}", e));
+                        }
+                        self.reconnect(running)?;
+                    }
                 }
             }
         }
         bail!("running loop closed");
     }
 }
+
+/// Decodes a raw stream message, unwrapping combined stream payloads.
+///
+/// Returns `None` for messages that do not map to a known `FuturesWebsocketEvent`.
+pub(crate) fn parse_msg(msg: &str) -> Result<Option<FuturesWebsocketEvent>> {
+    let value: serde_json::Value = serde_json::from_str(msg)?;
+
+    if let Some(data) = value.get("data") {
+        return parse_msg(&data.to_string());
+    }
+
+    if let Ok(events) = serde_json::from_value::<FuturesEvents>(value) {
+        let action = match events {
+            FuturesEvents::Vec(v) => FuturesWebsocketEvent::DayTickerAll(v),
+            FuturesEvents::DayTickerEvent(v) => FuturesWebsocketEvent::DayTicker(v),
+            FuturesEvents::BookTickerEvent(v) => FuturesWebsocketEvent::BookTicker(v),
+            FuturesEvents::MiniTickerEvent(v) => FuturesWebsocketEvent::MiniTicker(v),
+            FuturesEvents::VecMiniTickerEvent(v) => FuturesWebsocketEvent::MiniTickerAll(v),
+            FuturesEvents::AccountUpdateEvent(v) => FuturesWebsocketEvent::AccountUpdate(v),
+            FuturesEvents::OrderTradeEvent(v) => FuturesWebsocketEvent::OrderTrade(v),
+            FuturesEvents::IndexPriceEvent(v) => FuturesWebsocketEvent::IndexPrice(v),
+            FuturesEvents::MarkPriceEvent(v) => FuturesWebsocketEvent::MarkPrice(v),
+            FuturesEvents::VecMarkPriceEvent(v) => FuturesWebsocketEvent::MarkPriceAll(v),
+            FuturesEvents::TradeEvent(v) => FuturesWebsocketEvent::Trade(v),
+            FuturesEvents::ContinuousKlineEvent(v) => FuturesWebsocketEvent::ContinuousKline(v),
+            FuturesEvents::IndexKlineEvent(v) => FuturesWebsocketEvent::IndexKline(v),
+            FuturesEvents::LiquidationEvent(v) => FuturesWebsocketEvent::Liquidation(v),
+            FuturesEvents::KlineEvent(v) => FuturesWebsocketEvent::Kline(v),
+            FuturesEvents::OrderBook(v) => FuturesWebsocketEvent::OrderBook(v),
+            FuturesEvents::DepthOrderBookEvent(v) => FuturesWebsocketEvent::DepthOrderBook(v),
+            FuturesEvents::AggrTradesEvent(v) => FuturesWebsocketEvent::AggrTrades(v),
+            FuturesEvents::UserDataStreamExpiredEvent(v) => {
+                FuturesWebsocketEvent::UserDataStreamExpiredEvent(v)
+            }
+        };
+        return Ok(Some(action));
+    }
+    Ok(None)
+}
@@ -26,8 +26,20 @@ pub mod api;
 pub mod config;
 pub mod general;
 pub mod market;
+pub mod orderbook;
+pub mod ratelimit;
 pub mod savings;
+pub mod signer;
+pub mod subaccount;
+pub mod timesync;
 pub mod userstream;
+pub mod validation;
 pub mod websockets;
+pub mod wsapi;
 
 pub mod futures;
+pub mod margin;
+pub mod options;
+
+#[cfg(feature = "async")]
+pub mod nonblocking;
@@ -0,0 +1,338 @@
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use crate::util::{build_signed_request, insert_time_range};
+use crate::errors::Result;
+use crate::client::Client;
+use crate::api::{API, Margin};
+use crate::model::{IntoNumber, Number};
+use crate::account::{build_oco_order, OcoOrderRequest, OrderSide, OrderType, TimeInForce};
+use crate::margin::model::{
+    BorrowRepayRecord, InterestRecord, IsolatedPairStatus, MaxBorrowable, MaxTransferable, Order,
+    OrderCanceled, OrderList, RecordsQueryResult, Transaction, TransactionId,
+};
+
+/// Cross and isolated margin account.
+///
+/// Requests on an isolated margin pair take the pair's symbol, cross margin requests leave it
+/// out.
+#[derive(Clone)]
+pub struct MarginAccount {
+    pub client: Client,
+    pub recv_window: u64,
+}
+
+/// Margin order, sent with the `FULL` response type.
+///
+/// One of `qty` and `quote_order_qty` is required; `time_in_force` defaults to GTC for orders
+/// with a price.
+pub struct OrderRequest {
+    pub symbol: String,
+    pub is_isolated: bool,
+    pub side: OrderSide,
+    pub order_type: OrderType,
+    pub qty: Option<Number>,
+    pub quote_order_qty: Option<Number>,
+    pub price: Option<Number>,
+    pub stop_price: Option<Number>,
+    pub time_in_force: Option<TimeInForce>,
+    pub new_client_order_id: Option<String>,
+    pub side_effect_type: Option<SideEffectType>,
+}
+
+/// Borrowing and repaying done by a margin order.
+pub enum SideEffectType {
+    NoSideEffect,
+    MarginBuy,
+    AutoRepay,
+    AutoBorrowRepay,
+}
+
+impl Display for SideEffectType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NoSideEffect => write!(f, "NO_SIDE_EFFECT"),
+            Self::MarginBuy => write!(f, "MARGIN_BUY"),
+            Self::AutoRepay => write!(f, "AUTO_REPAY"),
+            Self::AutoBorrowRepay => write!(f, "AUTO_BORROW_REPAY"),
+        }
+    }
+}
+
+pub enum BorrowRepayType {
+    Borrow,
+    Repay,
+}
+
+impl Display for BorrowRepayType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Borrow => write!(f, "BORROW"),
+            Self::Repay => write!(f, "REPAY"),
+        }
+    }
+}
+
+impl MarginAccount {
+    /// Borrow `amount` of `asset`, into an isolated pair when `isolated_symbol` is set
+    pub fn borrow<S>(
+        &self, asset: S, amount: impl IntoNumber, isolated_symbol: Option<String>,
+    ) -> Result<TransactionId>
+    where
+        S: Into<String>,
+    {
+        self.borrow_repay(
+            BorrowRepayType::Borrow,
+            asset.into(),
+            amount,
+            isolated_symbol,
+        )
+    }
+
+    /// Repay `amount` of `asset`, interest first
+    pub fn repay<S>(
+        &self, asset: S, amount: impl IntoNumber, isolated_symbol: Option<String>,
+    ) -> Result<TransactionId>
+    where
+        S: Into<String>,
+    {
+        self.borrow_repay(
+            BorrowRepayType::Repay,
+            asset.into(),
+            amount,
+            isolated_symbol,
+        )
+    }
+
+    fn borrow_repay(
+        &self, borrow_repay_type: BorrowRepayType, asset: String, amount: impl IntoNumber,
+        isolated_symbol: Option<String>,
+    ) -> Result<TransactionId> {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("asset".into(), asset);
+        parameters.insert("amount".into(), amount.try_into_number()?.to_string());
+        parameters.insert("type".into(), borrow_repay_type.to_string());
+        insert_isolated_symbol(&mut parameters, isolated_symbol);
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed(API::Margin(Margin::BorrowRepay), request)
+    }
+
+    /// Loan (`Borrow`) or repay history, the last 7 days when no time range is given
+    pub fn borrow_repay_history(
+        &self, borrow_repay_type: BorrowRepayType, asset: Option<String>,
+        isolated_symbol: Option<String>, start_time: Option<u64>, end_time: Option<u64>,
+    ) -> Result<RecordsQueryResult<BorrowRepayRecord>> {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("type".into(), borrow_repay_type.to_string());
+        if let Some(asset) = asset {
+            parameters.insert("asset".into(), asset);
+        }
+        if let Some(isolated_symbol) = isolated_symbol {
+            parameters.insert("isolatedSymbol".into(), isolated_symbol);
+        }
+        insert_time_range(&mut parameters, start_time, end_time);
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Margin(Margin::BorrowRepay), Some(request))
+    }
+
+    /// Interest charged, the last 7 days when no time range is given
+    pub fn interest_history(
+        &self, asset: Option<String>, isolated_symbol: Option<String>, start_time: Option<u64>,
+        end_time: Option<u64>,
+    ) -> Result<RecordsQueryResult<InterestRecord>> {
+        let mut parameters = BTreeMap::new();
+        if let Some(asset) = asset {
+            parameters.insert("asset".into(), asset);
+        }
+        if let Some(isolated_symbol) = isolated_symbol {
+            parameters.insert("isolatedSymbol".into(), isolated_symbol);
+        }
+        insert_time_range(&mut parameters, start_time, end_time);
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Margin(Margin::InterestHistory), Some(request))
+    }
+
+    pub fn max_borrowable<S>(
+        &self, asset: S, isolated_symbol: Option<String>,
+    ) -> Result<MaxBorrowable>
+    where
+        S: Into<String>,
+    {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("asset".into(), asset.into());
+        if let Some(isolated_symbol) = isolated_symbol {
+            parameters.insert("isolatedSymbol".into(), isolated_symbol);
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Margin(Margin::MaxBorrowable), Some(request))
+    }
+
+    /// Amount of `asset` that can be transferred out of the margin account
+    pub fn max_transferable<S>(
+        &self, asset: S, isolated_symbol: Option<String>,
+    ) -> Result<MaxTransferable>
+    where
+        S: Into<String>,
+    {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("asset".into(), asset.into());
+        if let Some(isolated_symbol) = isolated_symbol {
+            parameters.insert("isolatedSymbol".into(), isolated_symbol);
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Margin(Margin::MaxTransferable), Some(request))
+    }
+
+    pub fn enable_isolated_pair<S>(&self, symbol: S) -> Result<IsolatedPairStatus>
+    where
+        S: Into<String>,
+    {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed(API::Margin(Margin::IsolatedAccount), request)
+    }
+
+    pub fn disable_isolated_pair<S>(&self, symbol: S) -> Result<IsolatedPairStatus>
+    where
+        S: Into<String>,
+    {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .delete_signed(API::Margin(Margin::IsolatedAccount), Some(request))
+    }
+
+    pub fn custom_order(&self, order_request: OrderRequest) -> Result<Transaction> {
+        let order = build_order(order_request);
+        let request = build_signed_request(order, self.recv_window)?;
+        self.client
+            .post_signed_order(API::Margin(Margin::Order), request)
+    }
+
+    /// Place an OCO order, borrowing or repaying as set by `side_effect_type`
+    pub fn oco_order(
+        &self, order: OcoOrderRequest, is_isolated: bool, side_effect_type: Option<SideEffectType>,
+    ) -> Result<OrderList> {
+        let mut parameters = build_oco_order(order);
+        insert_is_isolated(&mut parameters, is_isolated);
+        if let Some(side_effect_type) = side_effect_type {
+            parameters.insert("sideEffectType".into(), side_effect_type.to_string());
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed_order(API::Margin(Margin::Oco), request)
+    }
+
+    // Check an order's status
+    pub fn order_status<S>(&self, symbol: S, order_id: u64, is_isolated: bool) -> Result<Order>
+    where
+        S: Into<String>,
+    {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("orderId".into(), order_id.to_string());
+        insert_is_isolated(&mut parameters, is_isolated);
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Margin(Margin::Order), Some(request))
+    }
+
+    pub fn cancel_order<S>(
+        &self, symbol: S, order_id: u64, is_isolated: bool,
+    ) -> Result<OrderCanceled>
+    where
+        S: Into<String>,
+    {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("orderId".into(), order_id.to_string());
+        insert_is_isolated(&mut parameters, is_isolated);
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .delete_signed(API::Margin(Margin::Order), Some(request))
+    }
+
+    // Open orders of ONE symbol, or of all symbols (cross margin only)
+    pub fn get_open_orders(&self, symbol: Option<String>, is_isolated: bool) -> Result<Vec<Order>> {
+        let mut parameters = BTreeMap::new();
+        if let Some(symbol) = symbol {
+            parameters.insert("symbol".into(), symbol);
+        }
+        insert_is_isolated(&mut parameters, is_isolated);
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Margin(Margin::OpenOrders), Some(request))
+    }
+}
+
+fn build_order(order: OrderRequest) -> BTreeMap<String, String> {
+    let mut parameters = BTreeMap::new();
+    parameters.insert("symbol".into(), order.symbol);
+    parameters.insert("side".into(), order.side.to_string());
+    parameters.insert("type".into(), order.order_type.to_string());
+    parameters.insert("newOrderRespType".into(), "FULL".into());
+    insert_is_isolated(&mut parameters, order.is_isolated);
+
+    if let Some(qty) = order.qty {
+        parameters.insert("quantity".into(), qty.to_string());
+    }
+    if let Some(quote_order_qty) = order.quote_order_qty {
+        parameters.insert("quoteOrderQty".into(), quote_order_qty.to_string());
+    }
+    if let Some(price) = order.price {
+        let time_in_force = order.time_in_force.unwrap_or(TimeInForce::GTC);
+        parameters.insert("price".into(), price.to_string());
+        parameters.insert("timeInForce".into(), time_in_force.to_string());
+    }
+    if let Some(stop_price) = order.stop_price {
+        parameters.insert("stopPrice".into(), stop_price.to_string());
+    }
+    if let Some(client_order_id) = order.new_client_order_id {
+        parameters.insert("newClientOrderId".into(), client_order_id);
+    }
+    if let Some(side_effect_type) = order.side_effect_type {
+        parameters.insert("sideEffectType".into(), side_effect_type.to_string());
+    }
+
+    parameters
+}
+
+// Cross margin is the default, so `isIsolated` is only sent for isolated pairs
+fn insert_is_isolated(parameters: &mut BTreeMap<String, String>, is_isolated: bool) {
+    if is_isolated {
+        parameters.insert("isIsolated".into(), "TRUE".into());
+    }
+}
+
+// Borrowing and repaying always name the account
+fn insert_isolated_symbol(
+    parameters: &mut BTreeMap<String, String>, isolated_symbol: Option<String>,
+) {
+    match isolated_symbol {
+        Some(symbol) => {
+            parameters.insert("isIsolated".into(), "TRUE".into());
+            parameters.insert("symbol".into(), symbol);
+        }
+        None => {
+            parameters.insert("isIsolated".into(), "FALSE".into());
+        }
+    }
+}
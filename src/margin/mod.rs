@@ -0,0 +1,3 @@
+pub mod account;
+pub mod model;
+pub mod userstream;
@@ -0,0 +1,147 @@
+use serde::{Deserialize, Serialize};
+use crate::model::{string_or_float, string_or_float_opt};
+
+pub use crate::model::{FillInfo, Number, OrderList, RecordsQueryResult, TransactionId};
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Transaction {
+    pub symbol: String,
+    pub order_id: u64,
+    pub client_order_id: String,
+    pub transact_time: u64,
+    #[serde(with = "string_or_float")]
+    pub price: Number,
+    #[serde(with = "string_or_float")]
+    pub orig_qty: Number,
+    #[serde(with = "string_or_float")]
+    pub executed_qty: Number,
+    #[serde(with = "string_or_float")]
+    pub cummulative_quote_qty: Number,
+    pub status: String,
+    pub time_in_force: String,
+    #[serde(rename = "type")]
+    pub type_name: String,
+    pub side: String,
+    pub is_isolated: bool,
+    // Set when the order borrowed through its side effect
+    #[serde(default, with = "string_or_float_opt")]
+    pub margin_buy_borrow_amount: Option<Number>,
+    pub margin_buy_borrow_asset: Option<String>,
+    #[serde(default)]
+    pub fills: Vec<FillInfo>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Order {
+    pub symbol: String,
+    pub order_id: u64,
+    pub client_order_id: String,
+    #[serde(with = "string_or_float")]
+    pub price: Number,
+    #[serde(with = "string_or_float")]
+    pub orig_qty: Number,
+    #[serde(with = "string_or_float")]
+    pub executed_qty: Number,
+    #[serde(with = "string_or_float")]
+    pub cummulative_quote_qty: Number,
+    pub status: String,
+    pub time_in_force: String,
+    #[serde(rename = "type")]
+    pub type_name: String,
+    pub side: String,
+    #[serde(with = "string_or_float")]
+    pub stop_price: Number,
+    #[serde(with = "string_or_float")]
+    pub iceberg_qty: Number,
+    pub time: u64,
+    pub update_time: u64,
+    pub is_working: bool,
+    pub is_isolated: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderCanceled {
+    pub symbol: String,
+    pub order_id: u64,
+    pub orig_client_order_id: String,
+    pub client_order_id: String,
+    #[serde(with = "string_or_float")]
+    pub price: Number,
+    #[serde(with = "string_or_float")]
+    pub orig_qty: Number,
+    #[serde(with = "string_or_float")]
+    pub executed_qty: Number,
+    #[serde(with = "string_or_float")]
+    pub cummulative_quote_qty: Number,
+    pub status: String,
+    pub time_in_force: String,
+    #[serde(rename = "type")]
+    pub type_name: String,
+    pub side: String,
+    pub is_isolated: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BorrowRepayRecord {
+    // Empty for the cross margin account
+    #[serde(default)]
+    pub isolated_symbol: String,
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub amount: Number,
+    // Interest and principal paid off by a repay
+    #[serde(default, with = "string_or_float_opt")]
+    pub interest: Option<Number>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub principal: Option<Number>,
+    pub status: String,
+    pub timestamp: u64,
+    pub tx_id: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct InterestRecord {
+    // Empty for the cross margin account
+    #[serde(default)]
+    pub isolated_symbol: String,
+    pub tx_id: u64,
+    pub interest_accured_time: u64,
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub principal: Number,
+    #[serde(with = "string_or_float")]
+    pub interest: Number,
+    #[serde(with = "string_or_float")]
+    pub interest_rate: Number,
+    // PERIODIC, ON_BORROW, PERIODIC_CONVERTED or ON_BORROW_CONVERTED
+    #[serde(rename = "type")]
+    pub interest_type: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MaxBorrowable {
+    #[serde(with = "string_or_float")]
+    pub amount: Number,
+    #[serde(with = "string_or_float")]
+    pub borrow_limit: Number,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MaxTransferable {
+    #[serde(with = "string_or_float")]
+    pub amount: Number,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct IsolatedPairStatus {
+    pub success: bool,
+    pub symbol: String,
+}
@@ -0,0 +1,93 @@
+use reqwest::Method;
+use crate::model::{Success, UserDataStream};
+use crate::client::Client;
+use crate::config::Config;
+use crate::errors::Result;
+use crate::userstream::UserDataStreamApi;
+use crate::websockets::{parse_msg, ReconnectedEvent, WebsocketEvent};
+use crate::api::API;
+use crate::api::Margin;
+
+/// Listen keys of the cross margin account and of isolated margin pairs.
+///
+/// Margin user data streams carry the same events as the spot stream. `UserDataSession`
+/// manages the cross margin key.
+#[derive(Clone)]
+pub struct MarginUserStream {
+    pub client: Client,
+    pub recv_window: u64,
+}
+
+impl MarginUserStream {
+    // Cross margin User Stream
+    pub fn start(&self) -> Result<UserDataStream> {
+        self.client.post(API::Margin(Margin::UserDataStream))
+    }
+
+    pub fn keep_alive(&self, listen_key: &str) -> Result<Success> {
+        self.client
+            .put(API::Margin(Margin::UserDataStream), listen_key)
+    }
+
+    pub fn close(&self, listen_key: &str) -> Result<Success> {
+        self.client
+            .delete(API::Margin(Margin::UserDataStream), listen_key)
+    }
+
+    // Isolated margin User Stream of ONE pair
+    pub fn start_isolated(&self, symbol: &str) -> Result<UserDataStream> {
+        self.client.request_with_api_key(
+            Method::POST,
+            API::Margin(Margin::IsolatedUserDataStream),
+            format!("symbol={}", symbol),
+        )
+    }
+
+    pub fn keep_alive_isolated(&self, symbol: &str, listen_key: &str) -> Result<Success> {
+        self.client.request_with_api_key(
+            Method::PUT,
+            API::Margin(Margin::IsolatedUserDataStream),
+            format!("listenKey={}&symbol={}", listen_key, symbol),
+        )
+    }
+
+    pub fn close_isolated(&self, symbol: &str, listen_key: &str) -> Result<Success> {
+        self.client.request_with_api_key(
+            Method::DELETE,
+            API::Margin(Margin::IsolatedUserDataStream),
+            format!("listenKey={}&symbol={}", listen_key, symbol),
+        )
+    }
+}
+
+impl UserDataStreamApi for MarginUserStream {
+    type Event = WebsocketEvent;
+
+    fn start(&self) -> Result<UserDataStream> {
+        MarginUserStream::start(self)
+    }
+
+    fn keep_alive(&self, listen_key: &str) -> Result<Success> {
+        MarginUserStream::keep_alive(self, listen_key)
+    }
+
+    fn close(&self, listen_key: &str) -> Result<Success> {
+        MarginUserStream::close(self, listen_key)
+    }
+
+    fn ws_endpoint(&self, config: &Config) -> String {
+        config.ws_endpoint.clone()
+    }
+
+    fn parse_event(msg: &str) -> Result<Option<WebsocketEvent>> {
+        parse_msg(msg)
+    }
+
+    fn is_expired(event: &WebsocketEvent) -> bool {
+        matches!(event, WebsocketEvent::UserDataStreamExpiredEvent(_))
+    }
+
+    fn reconnected(event: ReconnectedEvent) -> WebsocketEvent {
+        WebsocketEvent::Reconnected(event)
+    }
+}
@@ -1,6 +1,95 @@
 use serde::{Deserialize, Serialize};
 use serde_json::{from_value, Value};
-use crate::errors::{Error, ErrorKind, Result};
+use crate::errors::{BinanceContentError, Error, ErrorKind, Result};
+
+/// Numeric type of prices and quantities.
+///
+/// `f64` by default, or `rust_decimal::Decimal` with the `decimal` feature, which keeps the
+/// exact values and formatting sent by the exchange.
+#[cfg(not(feature = "decimal"))]
+pub type Number = f64;
+
+/// Numeric type of prices and quantities.
+///
+/// `f64` by default, or `rust_decimal::Decimal` with the `decimal` feature, which keeps the
+/// exact values and formatting sent by the exchange.
+#[cfg(feature = "decimal")]
+pub type Number = rust_decimal::Decimal;
+
+/// Conversion of order parameters (prices, quantities, amounts) into `Number`.
+///
+/// With the `decimal` feature floats go through their shortest decimal representation,
+/// so `0.1` is sent as exactly `0.1`. NaN and infinite floats have no value to send and
+/// are rejected.
+pub trait IntoNumber: Sized {
+    fn try_into_number(self) -> Result<Number>;
+
+    /// `try_into_number` for values known to be valid, panicking on NaN and infinite floats.
+    fn into_number(self) -> Number {
+        match self.try_into_number() {
+            Ok(number) => number,
+            Err(error) => panic!("{}", error),
+        }
+    }
+}
+
+#[cfg(not(feature = "decimal"))]
+impl<T: Into<f64>> IntoNumber for T {
+    fn try_into_number(self) -> Result<Number> {
+        let number: f64 = self.into();
+        if !number.is_finite() {
+            error_chain::bail!("{} is not a valid number", number);
+        }
+        Ok(number)
+    }
+}
+
+#[cfg(feature = "decimal")]
+impl IntoNumber for Number {
+    fn try_into_number(self) -> Result<Number> {
+        Ok(self)
+    }
+}
+
+#[cfg(feature = "decimal")]
+macro_rules! into_number_from_int {
+    ($($int:ty),*) => {
+        $(
+            impl IntoNumber for $int {
+                fn try_into_number(self) -> Result<Number> {
+                    Ok(Number::from(self))
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "decimal")]
+into_number_from_int!(i8, i16, i32, i64, u8, u16, u32, u64);
+
+#[cfg(feature = "decimal")]
+macro_rules! into_number_from_float {
+    ($($float:ty),*) => {
+        $(
+            impl IntoNumber for $float {
+                fn try_into_number(self) -> Result<Number> {
+                    use std::str::FromStr;
+
+                    if !self.is_finite() {
+                        error_chain::bail!("{} is not a valid number", self);
+                    }
+                    match Number::from_str(&self.to_string()).or_else(|_| Number::try_from(self)) {
+                        Ok(number) => Ok(number),
+                        Err(_) => error_chain::bail!("{} is out of the range of Number", self),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "decimal")]
+into_number_from_float!(f32, f64);
 
 #[derive(Deserialize, Clone)]
 pub struct Empty {}
@@ -91,6 +180,7 @@ pub enum Filters {
     Notional {
         notional: Option<String>,
         min_notional: Option<String>,
+        max_notional: Option<String>,
         apply_to_market: Option<bool>,
         avg_price_mins: Option<f64>,
     },
@@ -155,7 +245,7 @@ pub struct Order {
     pub order_list_id: i64,
     pub client_order_id: String,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Number,
     pub orig_qty: String,
     pub executed_qty: String,
     pub cummulative_quote_qty: String,
@@ -165,7 +255,7 @@ pub struct Order {
     pub type_name: String,
     pub side: String,
     #[serde(with = "string_or_float")]
-    pub stop_price: f64,
+    pub stop_price: Number,
     pub iceberg_qty: String,
     pub time: u64,
     pub update_time: u64,
@@ -181,6 +271,87 @@ pub struct OrderCanceled {
     pub order_id: Option<u64>,
     pub client_order_id: Option<String>,
 }
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelReplaceResponse {
+    pub cancel_result: String,
+    pub new_order_result: String,
+    pub cancel_response: Option<OrderCanceled>,
+    pub new_order_response: Option<Transaction>,
+}
+
+/// Error response of a cancel-replace that did not both cancel and place, carrying what
+/// happened to either order.
+#[derive(Debug, Deserialize, Clone)]
+pub struct CancelReplaceFailure {
+    pub code: i16,
+    pub msg: String,
+    pub data: CancelReplaceData,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelReplaceData {
+    /// `SUCCESS`, `FAILURE` or `NOT_ATTEMPTED`
+    pub cancel_result: String,
+    pub new_order_result: String,
+    pub cancel_response: Option<CancelReplaceResult<OrderCanceled>>,
+    pub new_order_response: Option<CancelReplaceResult<Transaction>>,
+}
+
+/// Outcome of either order of a cancel-replace.
+#[derive(Debug, Deserialize, Clone)]
+#[serde(untagged)]
+pub enum CancelReplaceResult<T> {
+    Failure(BinanceContentError),
+    Success(T),
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AmendKeepPriorityResponse {
+    pub transact_time: u64,
+    pub execution_id: u64,
+    pub amended_order: AmendedOrder,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AmendedOrder {
+    pub symbol: String,
+    pub order_id: u64,
+    pub order_list_id: i64,
+    pub orig_client_order_id: String,
+    pub client_order_id: String,
+    #[serde(with = "string_or_float")]
+    pub price: Number,
+    #[serde(with = "string_or_float")]
+    pub qty: Number,
+    #[serde(with = "string_or_float")]
+    pub executed_qty: Number,
+    #[serde(with = "string_or_float", default)]
+    pub prevented_qty: Number,
+    #[serde(with = "string_or_float")]
+    pub cumulative_quote_qty: Number,
+    pub status: String,
+    pub time_in_force: String,
+    #[serde(rename = "type")]
+    pub order_type: String,
+    pub side: String,
+    pub working_time: u64,
+    pub self_trade_prevention_mode: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionStatus {
+    pub api_key: Option<String>,
+    pub authorized_since: Option<u64>,
+    pub connected_since: u64,
+    pub return_rate_limits: bool,
+    pub server_time: u64,
+}
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub enum SpotFuturesTransferType {
@@ -196,6 +367,98 @@ pub struct TransactionId {
     pub tran_id: u64,
 }
 
+/// Wallets of a universal transfer, named source first.
+pub enum UniversalTransferType {
+    MainUmFuture,
+    MainCmFuture,
+    MainMargin,
+    MainFunding,
+    MainOption,
+    UmFutureMain,
+    UmFutureMargin,
+    UmFutureFunding,
+    UmFutureOption,
+    CmFutureMain,
+    CmFutureMargin,
+    CmFutureFunding,
+    MarginMain,
+    MarginUmFuture,
+    MarginCmFuture,
+    MarginIsolatedMargin,
+    MarginFunding,
+    MarginOption,
+    IsolatedMarginMargin,
+    IsolatedMarginIsolatedMargin,
+    FundingMain,
+    FundingUmFuture,
+    FundingCmFuture,
+    FundingMargin,
+    FundingOption,
+    OptionMain,
+    OptionUmFuture,
+    OptionMargin,
+    OptionFunding,
+}
+
+impl std::fmt::Display for UniversalTransferType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let transfer_type = match self {
+            Self::MainUmFuture => "MAIN_UMFUTURE",
+            Self::MainCmFuture => "MAIN_CMFUTURE",
+            Self::MainMargin => "MAIN_MARGIN",
+            Self::MainFunding => "MAIN_FUNDING",
+            Self::MainOption => "MAIN_OPTION",
+            Self::UmFutureMain => "UMFUTURE_MAIN",
+            Self::UmFutureMargin => "UMFUTURE_MARGIN",
+            Self::UmFutureFunding => "UMFUTURE_FUNDING",
+            Self::UmFutureOption => "UMFUTURE_OPTION",
+            Self::CmFutureMain => "CMFUTURE_MAIN",
+            Self::CmFutureMargin => "CMFUTURE_MARGIN",
+            Self::CmFutureFunding => "CMFUTURE_FUNDING",
+            Self::MarginMain => "MARGIN_MAIN",
+            Self::MarginUmFuture => "MARGIN_UMFUTURE",
+            Self::MarginCmFuture => "MARGIN_CMFUTURE",
+            Self::MarginIsolatedMargin => "MARGIN_ISOLATEDMARGIN",
+            Self::MarginFunding => "MARGIN_FUNDING",
+            Self::MarginOption => "MARGIN_OPTION",
+            Self::IsolatedMarginMargin => "ISOLATEDMARGIN_MARGIN",
+            Self::IsolatedMarginIsolatedMargin => "ISOLATEDMARGIN_ISOLATEDMARGIN",
+            Self::FundingMain => "FUNDING_MAIN",
+            Self::FundingUmFuture => "FUNDING_UMFUTURE",
+            Self::FundingCmFuture => "FUNDING_CMFUTURE",
+            Self::FundingMargin => "FUNDING_MARGIN",
+            Self::FundingOption => "FUNDING_OPTION",
+            Self::OptionMain => "OPTION_MAIN",
+            Self::OptionUmFuture => "OPTION_UMFUTURE",
+            Self::OptionMargin => "OPTION_MARGIN",
+            Self::OptionFunding => "OPTION_FUNDING",
+        };
+        write!(f, "{}", transfer_type)
+    }
+}
+
+/// A page of history records, `rows` is left out when there are none.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordsQueryResult<T> {
+    #[serde(default = "Vec::new")]
+    pub rows: Vec<T>,
+    pub total: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UniversalTransfer {
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub amount: Number,
+    #[serde(rename = "type")]
+    pub transfer_type: String,
+    pub status: String,
+    pub tran_id: u64,
+    pub timestamp: u64,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Transaction {
@@ -205,15 +468,15 @@ pub struct Transaction {
     pub client_order_id: String,
     pub transact_time: u64,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Number,
     #[serde(with = "string_or_float")]
-    pub orig_qty: f64,
+    pub orig_qty: Number,
     #[serde(with = "string_or_float")]
-    pub executed_qty: f64,
+    pub executed_qty: Number,
     #[serde(with = "string_or_float")]
-    pub cummulative_quote_qty: f64,
+    pub cummulative_quote_qty: Number,
     #[serde(with = "string_or_float", default = "default_stop_price")]
-    pub stop_price: f64,
+    pub stop_price: Number,
     pub status: String,
     pub time_in_force: String,
     #[serde(rename = "type")]
@@ -222,22 +485,75 @@ pub struct Transaction {
     pub fills: Option<Vec<FillInfo>>,
 }
 
-fn default_stop_price() -> f64 {
-    0.0
+fn default_stop_price() -> Number {
+    Number::default()
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct FillInfo {
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Number,
     #[serde(with = "string_or_float")]
-    pub qty: f64,
+    pub qty: Number,
     #[serde(with = "string_or_float")]
-    pub commission: f64,
+    pub commission: Number,
     pub commission_asset: String,
     pub trade_id: Option<u64>,
 }
+
+/// Order list (OCO) as returned when placing, cancelling or querying it.
+///
+/// `order_reports` is only filled in by the place and cancel endpoints.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderList {
+    pub order_list_id: i64,
+    pub contingency_type: String,
+    pub list_status_type: String,
+    pub list_order_status: String,
+    pub list_client_order_id: String,
+    pub transaction_time: u64,
+    pub symbol: String,
+    pub orders: Vec<OrderListOrder>,
+    #[serde(default)]
+    pub order_reports: Vec<OrderReport>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderListOrder {
+    pub symbol: String,
+    pub order_id: u64,
+    pub client_order_id: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderReport {
+    pub symbol: String,
+    pub orig_client_order_id: Option<String>,
+    pub order_id: u64,
+    pub order_list_id: i64,
+    pub client_order_id: String,
+    pub transact_time: Option<u64>,
+    #[serde(with = "string_or_float")]
+    pub price: Number,
+    #[serde(with = "string_or_float")]
+    pub orig_qty: Number,
+    #[serde(with = "string_or_float")]
+    pub executed_qty: Number,
+    #[serde(with = "string_or_float")]
+    pub cummulative_quote_qty: Number,
+    #[serde(with = "string_or_float", default = "default_stop_price")]
+    pub stop_price: Number,
+    pub status: String,
+    pub time_in_force: String,
+    #[serde(rename = "type")]
+    pub type_name: String,
+    pub side: String,
+}
+
 /// Response to a test order (endpoint /api/v3/order/test).
 ///
 /// Currently, the API responds {} on a successfull test transaction,
@@ -257,13 +573,13 @@ pub struct OrderBook {
 #[derive(PartialEq, Debug, Serialize, Deserialize, Clone)]
 pub struct Bids {
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Number,
     #[serde(with = "string_or_float")]
-    pub qty: f64,
+    pub qty: Number,
 }
 
 impl Bids {
-    pub fn new(price: f64, qty: f64) -> Bids {
+    pub fn new(price: Number, qty: Number) -> Bids {
         Bids { price, qty }
     }
 }
@@ -271,9 +587,15 @@ impl Bids {
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Asks {
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Number,
     #[serde(with = "string_or_float")]
-    pub qty: f64,
+    pub qty: Number,
+}
+
+impl Asks {
+    pub fn new(price: Number, qty: Number) -> Asks {
+        Asks { price, qty }
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -296,14 +618,14 @@ pub enum Prices {
 pub struct SymbolPrice {
     pub symbol: String,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Number,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct AveragePrice {
     pub mins: u64,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Number,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -323,13 +645,13 @@ pub enum KlineSummaries {
 pub struct Tickers {
     pub symbol: String,
     #[serde(with = "string_or_float")]
-    pub bid_price: f64,
+    pub bid_price: Number,
     #[serde(with = "string_or_float")]
-    pub bid_qty: f64,
+    pub bid_qty: Number,
     #[serde(with = "string_or_float")]
-    pub ask_price: f64,
+    pub ask_price: Number,
     #[serde(with = "string_or_float")]
-    pub ask_qty: f64,
+    pub ask_qty: Number,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -337,9 +659,9 @@ pub struct Tickers {
 pub struct TradeHistory {
     pub id: u64,
     #[serde(with = "string_or_float")]
-    pub price: f64,
+    pub price: Number,
     #[serde(with = "string_or_float")]
-    pub qty: f64,
+    pub qty: Number,
     pub commission: String,
     pub commission_asset: String,
     pub time: u64,
@@ -356,21 +678,21 @@ pub struct PriceStats {
     pub price_change_percent: String,
     pub weighted_avg_price: String,
     #[serde(with = "string_or_float")]
-    pub prev_close_price: f64,
+    pub prev_close_price: Number,
     #[serde(with = "string_or_float")]
-    pub last_price: f64,
+    pub last_price: Number,
     #[serde(with = "string_or_float")]
-    pub bid_price: f64,
+    pub bid_price: Number,
     #[serde(with = "string_or_float")]
-    pub ask_price: f64,
+    pub ask_price: Number,
     #[serde(with = "string_or_float")]
-    pub open_price: f64,
+    pub open_price: Number,
     #[serde(with = "string_or_float")]
-    pub high_price: f64,
+    pub high_price: Number,
     #[serde(with = "string_or_float")]
-    pub low_price: f64,
+    pub low_price: Number,
     #[serde(with = "string_or_float")]
-    pub volume: f64,
+    pub volume: Number,
     pub open_time: u64,
     pub close_time: u64,
     pub first_id: i64,
@@ -393,9 +715,9 @@ pub struct AggTrade {
     #[serde(rename = "M")]
     pub best_match: bool,
     #[serde(rename = "p", with = "string_or_float")]
-    pub price: f64,
+    pub price: Number,
     #[serde(rename = "q", with = "string_or_float")]
-    pub qty: f64,
+    pub qty: Number,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -1253,24 +1575,24 @@ pub struct CoinInfo {
     pub coin: String,
     pub deposit_all_enable: bool,
     #[serde(with = "string_or_float")]
-    pub free: f64,
+    pub free: Number,
     #[serde(with = "string_or_float")]
-    pub freeze: f64,
+    pub freeze: Number,
     #[serde(with = "string_or_float")]
-    pub ipoable: f64,
+    pub ipoable: Number,
     #[serde(with = "string_or_float")]
-    pub ipoing: f64,
+    pub ipoing: Number,
     pub is_legal_money: bool,
     #[serde(with = "string_or_float")]
-    pub locked: f64,
+    pub locked: Number,
     pub name: String,
     pub network_list: Vec<Network>,
     #[serde(with = "string_or_float")]
-    pub storage: f64,
+    pub storage: Number,
     pub trading: bool,
     pub withdraw_all_enable: bool,
     #[serde(with = "string_or_float")]
-    pub withdrawing: f64,
+    pub withdrawing: Number,
 }
 
 /// Part of the Savings API get all coins response
@@ -1296,9 +1618,9 @@ pub struct Network {
     pub withdraw_desc: Option<String>,
     pub withdraw_enable: bool,
     #[serde(with = "string_or_float")]
-    pub withdraw_fee: f64,
+    pub withdraw_fee: Number,
     #[serde(with = "string_or_float")]
-    pub withdraw_min: f64,
+    pub withdraw_min: Number,
     // pub insert_time: Option<u64>, //commented out for now, because they are not inside the actual response (only the api doc example)
     // pub update_time: Option<u64>,
     pub withdraw_integer_multiple: Option<String>,
@@ -1308,17 +1630,101 @@ pub struct Network {
 #[serde(rename_all = "camelCase")]
 pub struct AssetDetail {
     #[serde(with = "string_or_float")]
-    pub min_withdraw_amount: f64,
+    pub min_withdraw_amount: Number,
     /// false if ALL of networks' are false
     pub deposit_status: bool,
     #[serde(with = "string_or_float")]
-    pub withdraw_fee: f64,
+    pub withdraw_fee: Number,
     /// false if ALL of networks' are false
     pub withdraw_status: bool,
     /// reason
     pub deposit_tip: Option<String>,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccountInfo {
+    pub email: String,
+    pub is_freeze: bool,
+    pub create_time: u64,
+    #[serde(default)]
+    pub is_managed_sub_account: bool,
+    #[serde(default)]
+    pub is_asset_management_sub_account: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccountList {
+    pub sub_accounts: Vec<SubAccountInfo>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SubAccountBalances {
+    pub balances: Vec<SubAccountBalance>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SubAccountBalance {
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub free: Number,
+    #[serde(with = "string_or_float")]
+    pub locked: Number,
+}
+
+/// Wallet of a master or sub-account in a sub-account transfer.
+pub enum SubAccountType {
+    Spot,
+    UsdtFuture,
+    CoinFuture,
+    Margin,
+    IsolatedMargin,
+}
+
+impl std::fmt::Display for SubAccountType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Spot => write!(f, "SPOT"),
+            Self::UsdtFuture => write!(f, "USDT_FUTURE"),
+            Self::CoinFuture => write!(f, "COIN_FUTURE"),
+            Self::Margin => write!(f, "MARGIN"),
+            Self::IsolatedMargin => write!(f, "ISOLATED_MARGIN"),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccountTransferId {
+    pub tran_id: u64,
+    pub client_tran_id: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccountTransferHistory {
+    pub result: Vec<SubAccountTransfer>,
+    pub total_count: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccountTransfer {
+    pub tran_id: u64,
+    pub from_email: String,
+    pub to_email: String,
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub amount: Number,
+    pub create_time_stamp: u64,
+    pub from_account_type: String,
+    pub to_account_type: String,
+    pub status: String,
+    #[serde(default)]
+    pub client_tran_id: String,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct DepositAddress {
     pub address: String,
@@ -1327,10 +1733,318 @@ pub struct DepositAddress {
     pub url: String,
 }
 
+/// Lifecycle of a deposit, from the numeric `status` of the deposit history.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(from = "u8", into = "u8")]
+pub enum DepositStatus {
+    Pending,
+    Success,
+    Rejected,
+    /// Credited to the account, but cannot be withdrawn yet
+    CreditedCannotWithdraw,
+    WrongDeposit,
+    WaitingUserConfirm,
+    Other(u8),
+}
+
+impl From<u8> for DepositStatus {
+    fn from(status: u8) -> Self {
+        match status {
+            0 => Self::Pending,
+            1 => Self::Success,
+            2 => Self::Rejected,
+            6 => Self::CreditedCannotWithdraw,
+            7 => Self::WrongDeposit,
+            8 => Self::WaitingUserConfirm,
+            other => Self::Other(other),
+        }
+    }
+}
+
+impl From<DepositStatus> for u8 {
+    fn from(status: DepositStatus) -> Self {
+        match status {
+            DepositStatus::Pending => 0,
+            DepositStatus::Success => 1,
+            DepositStatus::Rejected => 2,
+            DepositStatus::CreditedCannotWithdraw => 6,
+            DepositStatus::WrongDeposit => 7,
+            DepositStatus::WaitingUserConfirm => 8,
+            DepositStatus::Other(other) => other,
+        }
+    }
+}
+
+/// Lifecycle of a withdrawal, from the numeric `status` of the withdraw history.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(from = "u8", into = "u8")]
+pub enum WithdrawStatus {
+    EmailSent,
+    Cancelled,
+    AwaitingApproval,
+    Rejected,
+    Processing,
+    Failure,
+    Completed,
+    Other(u8),
+}
+
+impl From<u8> for WithdrawStatus {
+    fn from(status: u8) -> Self {
+        match status {
+            0 => Self::EmailSent,
+            1 => Self::Cancelled,
+            2 => Self::AwaitingApproval,
+            3 => Self::Rejected,
+            4 => Self::Processing,
+            5 => Self::Failure,
+            6 => Self::Completed,
+            other => Self::Other(other),
+        }
+    }
+}
+
+impl From<WithdrawStatus> for u8 {
+    fn from(status: WithdrawStatus) -> Self {
+        match status {
+            WithdrawStatus::EmailSent => 0,
+            WithdrawStatus::Cancelled => 1,
+            WithdrawStatus::AwaitingApproval => 2,
+            WithdrawStatus::Rejected => 3,
+            WithdrawStatus::Processing => 4,
+            WithdrawStatus::Failure => 5,
+            WithdrawStatus::Completed => 6,
+            WithdrawStatus::Other(other) => other,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct WithdrawId {
+    pub id: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DepositRecord {
+    #[serde(default)]
+    pub id: String,
+    #[serde(with = "string_or_float")]
+    pub amount: Number,
+    pub coin: String,
+    pub network: String,
+    pub status: DepositStatus,
+    pub address: String,
+    #[serde(default)]
+    pub address_tag: String,
+    pub tx_id: String,
+    pub insert_time: u64,
+    // 0 for external, 1 for internal transfers
+    #[serde(default)]
+    pub transfer_type: u8,
+    // e.g. "12/12"
+    #[serde(default)]
+    pub confirm_times: String,
+    #[serde(default)]
+    pub unlock_confirm: u32,
+    // 0 for the spot, 1 for the funding wallet
+    #[serde(default)]
+    pub wallet_type: u8,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct WithdrawRecord {
+    pub id: String,
+    #[serde(with = "string_or_float")]
+    pub amount: Number,
+    #[serde(with = "string_or_float")]
+    pub transaction_fee: Number,
+    pub coin: String,
+    pub status: WithdrawStatus,
+    pub address: String,
+    #[serde(default)]
+    pub tx_id: String,
+    // e.g. "2019-10-12 11:12:02", UTC
+    pub apply_time: String,
+    pub network: String,
+    #[serde(default)]
+    pub transfer_type: u8,
+    pub withdraw_order_id: Option<String>,
+    // Reason of a failure
+    #[serde(default)]
+    pub info: String,
+    pub confirm_no: Option<u32>,
+    #[serde(default)]
+    pub wallet_type: u8,
+    pub complete_time: Option<String>,
+}
+
+/// 0 when the system is normal, 1 under maintenance.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SystemStatus {
+    pub status: u8,
+    pub msg: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AccountStatus {
+    pub data: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ApiTradingStatus {
+    pub data: ApiTradingStatusData,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiTradingStatusData {
+    pub is_locked: bool,
+    // Minutes until the trading lock is lifted
+    pub planned_recover_time: u64,
+    pub trigger_condition: TriggerCondition,
+    pub update_time: u64,
+}
+
+/// Order ratios that lock API trading when exceeded.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TriggerCondition {
+    // GTC orders
+    #[serde(rename = "GCR")]
+    pub gcr: u64,
+    // IOC/FOK orders
+    #[serde(rename = "IFER")]
+    pub ifer: u64,
+    // Unfilled orders
+    #[serde(rename = "UFR")]
+    pub ufr: u64,
+}
+
+pub enum AccountSnapshotType {
+    Spot,
+    Margin,
+    Futures,
+}
+
+impl std::fmt::Display for AccountSnapshotType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Spot => write!(f, "SPOT"),
+            Self::Margin => write!(f, "MARGIN"),
+            Self::Futures => write!(f, "FUTURES"),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountSnapshot {
+    pub code: i32,
+    pub msg: String,
+    pub snapshot_vos: Vec<Snapshot>,
+}
+
+/// Daily snapshot of the account, `data` matches the requested `AccountSnapshotType`.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Snapshot {
+    #[serde(rename = "type")]
+    pub snapshot_type: String,
+    pub update_time: u64,
+    pub data: SnapshotData,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(untagged)]
+pub enum SnapshotData {
+    Spot(SpotSnapshot),
+    Margin(MarginSnapshot),
+    Futures(FuturesSnapshot),
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SpotSnapshot {
+    pub balances: Vec<SnapshotBalance>,
+    #[serde(with = "string_or_float")]
+    pub total_asset_of_btc: Number,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SnapshotBalance {
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub free: Number,
+    #[serde(with = "string_or_float")]
+    pub locked: Number,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginSnapshot {
+    #[serde(with = "string_or_float")]
+    pub margin_level: Number,
+    #[serde(with = "string_or_float")]
+    pub total_asset_of_btc: Number,
+    #[serde(with = "string_or_float")]
+    pub total_liability_of_btc: Number,
+    #[serde(with = "string_or_float")]
+    pub total_net_asset_of_btc: Number,
+    pub user_assets: Vec<MarginSnapshotAsset>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginSnapshotAsset {
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub borrowed: Number,
+    #[serde(with = "string_or_float")]
+    pub free: Number,
+    #[serde(with = "string_or_float")]
+    pub interest: Number,
+    #[serde(with = "string_or_float")]
+    pub locked: Number,
+    #[serde(with = "string_or_float")]
+    pub net_asset: Number,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct FuturesSnapshot {
+    pub assets: Vec<FuturesSnapshotAsset>,
+    pub position: Vec<FuturesSnapshotPosition>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FuturesSnapshotAsset {
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub margin_balance: Number,
+    #[serde(with = "string_or_float")]
+    pub wallet_balance: Number,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FuturesSnapshotPosition {
+    pub symbol: String,
+    #[serde(with = "string_or_float")]
+    pub entry_price: Number,
+    #[serde(with = "string_or_float")]
+    pub mark_price: Number,
+    #[serde(with = "string_or_float")]
+    pub position_amt: Number,
+    #[serde(with = "string_or_float")]
+    pub un_realized_profit: Number,
+}
+
 pub(crate) mod string_or_float {
     use std::fmt;
 
     use serde::{de, Serializer, Deserialize, Deserializer};
+    use super::Number;
 
     pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -1340,7 +2054,7 @@ pub(crate) mod string_or_float {
         serializer.collect_str(value)
     }
 
-    pub fn deserialize<'de, D>(deserializer: D) -> Result<f64, D::Error>
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Number, D::Error>
     where
         D: Deserializer<'de>,
     {
@@ -1352,22 +2066,47 @@ pub(crate) mod string_or_float {
         }
 
         match StringOrFloat::deserialize(deserializer)? {
-            StringOrFloat::String(s) => {
-                if s == "INF" {
-                    Ok(f64::INFINITY)
-                } else {
-                    s.parse().map_err(de::Error::custom)
-                }
-            }
-            StringOrFloat::Float(i) => Ok(i),
+            StringOrFloat::String(s) => parse(&s).map_err(de::Error::custom),
+            StringOrFloat::Float(i) => from_f64(i).map_err(de::Error::custom),
         }
     }
+
+    #[cfg(not(feature = "decimal"))]
+    fn parse(s: &str) -> Result<Number, std::num::ParseFloatError> {
+        if s == "INF" {
+            Ok(f64::INFINITY)
+        } else {
+            s.parse()
+        }
+    }
+
+    #[cfg(not(feature = "decimal"))]
+    fn from_f64(value: f64) -> Result<Number, std::convert::Infallible> {
+        Ok(value)
+    }
+
+    #[cfg(feature = "decimal")]
+    fn parse(s: &str) -> Result<Number, rust_decimal::Error> {
+        use std::str::FromStr;
+
+        if s == "INF" {
+            Ok(Number::MAX)
+        } else {
+            Number::from_str(s).or_else(|_| Number::from_scientific(s))
+        }
+    }
+
+    #[cfg(feature = "decimal")]
+    fn from_f64(value: f64) -> Result<Number, rust_decimal::Error> {
+        Number::try_from(value)
+    }
 }
 
 pub(crate) mod string_or_float_opt {
     use std::fmt;
 
-    use serde::{Serializer, Deserialize, Deserializer};
+    use serde::{Serializer, Deserializer};
+    use super::Number;
 
     pub fn serialize<T, S>(value: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -1380,17 +2119,10 @@ pub(crate) mod string_or_float_opt {
         }
     }
 
-    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<f64>, D::Error>
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Number>, D::Error>
     where
         D: Deserializer<'de>,
     {
-        #[derive(Deserialize)]
-        #[serde(untagged)]
-        enum StringOrFloat {
-            String(String),
-            Float(f64),
-        }
-
         Ok(Some(crate::model::string_or_float::deserialize(
             deserializer,
         )?))
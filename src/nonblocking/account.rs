@@ -0,0 +1,873 @@
+use error_chain::bail;
+
+use crate::account::{
+    build_cancel_replace_order, build_oco_order, build_order, build_quote_quantity_order,
+    CancelReplaceRequest, OcoOrderRequest, OrderQuoteQuantityRequest, OrderRequest, OrderSide,
+    OrderType, TimeInForce,
+};
+use crate::util::{build_signed_request, is_start_time_valid};
+use crate::model::{
+    AccountInformation, AmendKeepPriorityResponse, Balance, CancelReplaceResponse, Empty,
+    IntoNumber, Number, Order, OrderCanceled, OrderList, TradeHistory, Transaction,
+};
+use crate::nonblocking::client::Client;
+use crate::validation::OrderValidator;
+use crate::errors::Result;
+use std::collections::BTreeMap;
+use crate::api::API;
+use crate::api::Spot;
+
+#[derive(Clone)]
+pub struct Account {
+    pub client: Client,
+    pub recv_window: u64,
+    /// Checks custom orders against the exchange filters before they are sent.
+    pub validator: Option<OrderValidator>,
+}
+
+impl Account {
+    /// Validate (and optionally round) custom orders before sending them.
+    pub fn with_validator(mut self, validator: OrderValidator) -> Self {
+        self.validator = Some(validator);
+        self
+    }
+
+    // Account Information
+    pub async fn get_account(&self) -> Result<AccountInformation> {
+        let request = build_signed_request(BTreeMap::new(), self.recv_window)?;
+        self.client
+            .get_signed(API::Spot(Spot::Account), Some(request))
+            .await
+    }
+
+    // Balance for a single Asset
+    pub async fn get_balance<S>(&self, asset: S) -> Result<Balance>
+    where
+        S: Into<String>,
+    {
+        match self.get_account().await {
+            Ok(account) => {
+                let cmp_asset = asset.into();
+                for balance in account.balances {
+                    if balance.asset == cmp_asset {
+                        return Ok(balance);
+                    }
+                }
+                bail!("Asset not found");
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    // Current open orders for ONE symbol
+    pub async fn get_open_orders<S>(&self, symbol: S) -> Result<Vec<Order>>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Spot(Spot::OpenOrders), Some(request))
+            .await
+    }
+
+    // All current open orders
+    pub async fn get_all_open_orders(&self) -> Result<Vec<Order>> {
+        let parameters: BTreeMap<String, String> = BTreeMap::new();
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Spot(Spot::OpenOrders), Some(request))
+            .await
+    }
+
+    // Cancel all open orders for a single symbol
+    pub async fn cancel_all_open_orders<S>(&self, symbol: S) -> Result<Vec<OrderCanceled>>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .delete_signed(API::Spot(Spot::OpenOrders), Some(request))
+            .await
+    }
+
+    // Check an order's status
+    pub async fn order_status<S>(&self, symbol: S, order_id: u64) -> Result<Order>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("orderId".into(), order_id.to_string());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Spot(Spot::Order), Some(request))
+            .await
+    }
+
+    /// Place a test status order
+    ///
+    /// This order is sandboxed: it is validated, but not sent to the matching engine.
+    pub async fn test_order_status<S>(&self, symbol: S, order_id: u64) -> Result<()>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("orderId".into(), order_id.to_string());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed::<Empty>(API::Spot(Spot::OrderTest), Some(request))
+            .await
+            .map(|_| ())
+    }
+
+    // Place a LIMIT order - BUY
+    pub async fn limit_buy<S, F>(
+        &self, symbol: S, qty: F, price: impl IntoNumber,
+    ) -> Result<Transaction>
+    where
+        S: Into<String>,
+        F: IntoNumber,
+    {
+        let buy = OrderRequest {
+            symbol: symbol.into(),
+            qty: qty.try_into_number()?,
+            price: price.try_into_number()?,
+            stop_price: None,
+            order_side: OrderSide::Buy,
+            order_type: OrderType::Limit,
+            time_in_force: TimeInForce::GTC,
+            new_client_order_id: None,
+        };
+        let order = build_order(buy);
+        let request = build_signed_request(order, self.recv_window)?;
+        self.client
+            .post_signed_order(API::Spot(Spot::Order), request)
+            .await
+    }
+
+    /// Place a test limit order - BUY
+    ///
+    /// This order is sandboxed: it is validated, but not sent to the matching engine.
+    pub async fn test_limit_buy<S, F>(
+        &self, symbol: S, qty: F, price: impl IntoNumber,
+    ) -> Result<()>
+    where
+        S: Into<String>,
+        F: IntoNumber,
+    {
+        let buy = OrderRequest {
+            symbol: symbol.into(),
+            qty: qty.try_into_number()?,
+            price: price.try_into_number()?,
+            stop_price: None,
+            order_side: OrderSide::Buy,
+            order_type: OrderType::Limit,
+            time_in_force: TimeInForce::GTC,
+            new_client_order_id: None,
+        };
+        let order = build_order(buy);
+        let request = build_signed_request(order, self.recv_window)?;
+        self.client
+            .post_signed::<Empty>(API::Spot(Spot::OrderTest), request)
+            .await
+            .map(|_| ())
+    }
+
+    // Place a LIMIT order - SELL
+    pub async fn limit_sell<S, F>(
+        &self, symbol: S, qty: F, price: impl IntoNumber,
+    ) -> Result<Transaction>
+    where
+        S: Into<String>,
+        F: IntoNumber,
+    {
+        let sell = OrderRequest {
+            symbol: symbol.into(),
+            qty: qty.try_into_number()?,
+            price: price.try_into_number()?,
+            stop_price: None,
+            order_side: OrderSide::Sell,
+            order_type: OrderType::Limit,
+            time_in_force: TimeInForce::GTC,
+            new_client_order_id: None,
+        };
+        let order = build_order(sell);
+        let request = build_signed_request(order, self.recv_window)?;
+        self.client
+            .post_signed_order(API::Spot(Spot::Order), request)
+            .await
+    }
+
+    /// Place a test LIMIT order - SELL
+    ///
+    /// This order is sandboxed: it is validated, but not sent to the matching engine.
+    pub async fn test_limit_sell<S, F>(
+        &self, symbol: S, qty: F, price: impl IntoNumber,
+    ) -> Result<()>
+    where
+        S: Into<String>,
+        F: IntoNumber,
+    {
+        let sell = OrderRequest {
+            symbol: symbol.into(),
+            qty: qty.try_into_number()?,
+            price: price.try_into_number()?,
+            stop_price: None,
+            order_side: OrderSide::Sell,
+            order_type: OrderType::Limit,
+            time_in_force: TimeInForce::GTC,
+            new_client_order_id: None,
+        };
+        let order = build_order(sell);
+        let request = build_signed_request(order, self.recv_window)?;
+        self.client
+            .post_signed::<Empty>(API::Spot(Spot::OrderTest), request)
+            .await
+            .map(|_| ())
+    }
+
+    // Place a MARKET order - BUY
+    pub async fn market_buy<S, F>(&self, symbol: S, qty: F) -> Result<Transaction>
+    where
+        S: Into<String>,
+        F: IntoNumber,
+    {
+        let buy = OrderRequest {
+            symbol: symbol.into(),
+            qty: qty.try_into_number()?,
+            price: Number::default(),
+            stop_price: None,
+            order_side: OrderSide::Buy,
+            order_type: OrderType::Market,
+            time_in_force: TimeInForce::GTC,
+            new_client_order_id: None,
+        };
+        let order = build_order(buy);
+        let request = build_signed_request(order, self.recv_window)?;
+        self.client
+            .post_signed_order(API::Spot(Spot::Order), request)
+            .await
+    }
+
+    /// Place a test MARKET order - BUY
+    ///
+    /// This order is sandboxed: it is validated, but not sent to the matching engine.
+    pub async fn test_market_buy<S, F>(&self, symbol: S, qty: F) -> Result<()>
+    where
+        S: Into<String>,
+        F: IntoNumber,
+    {
+        let buy = OrderRequest {
+            symbol: symbol.into(),
+            qty: qty.try_into_number()?,
+            price: Number::default(),
+            stop_price: None,
+            order_side: OrderSide::Buy,
+            order_type: OrderType::Market,
+            time_in_force: TimeInForce::GTC,
+            new_client_order_id: None,
+        };
+        let order = build_order(buy);
+        let request = build_signed_request(order, self.recv_window)?;
+        self.client
+            .post_signed::<Empty>(API::Spot(Spot::OrderTest), request)
+            .await
+            .map(|_| ())
+    }
+
+    // Place a MARKET order with quote quantity - BUY
+    pub async fn market_buy_using_quote_quantity<S, F>(
+        &self, symbol: S, quote_order_qty: F,
+    ) -> Result<Transaction>
+    where
+        S: Into<String>,
+        F: IntoNumber,
+    {
+        let buy = OrderQuoteQuantityRequest {
+            symbol: symbol.into(),
+            quote_order_qty: quote_order_qty.try_into_number()?,
+            price: Number::default(),
+            order_side: OrderSide::Buy,
+            order_type: OrderType::Market,
+            time_in_force: TimeInForce::GTC,
+            new_client_order_id: None,
+        };
+        let order = build_quote_quantity_order(buy);
+        let request = build_signed_request(order, self.recv_window)?;
+        self.client
+            .post_signed_order(API::Spot(Spot::Order), request)
+            .await
+    }
+
+    /// Place a test MARKET order with quote quantity - BUY
+    ///
+    /// This order is sandboxed: it is validated, but not sent to the matching engine.
+    pub async fn test_market_buy_using_quote_quantity<S, F>(
+        &self, symbol: S, quote_order_qty: F,
+    ) -> Result<()>
+    where
+        S: Into<String>,
+        F: IntoNumber,
+    {
+        let buy = OrderQuoteQuantityRequest {
+            symbol: symbol.into(),
+            quote_order_qty: quote_order_qty.try_into_number()?,
+            price: Number::default(),
+            order_side: OrderSide::Buy,
+            order_type: OrderType::Market,
+            time_in_force: TimeInForce::GTC,
+            new_client_order_id: None,
+        };
+        let order = build_quote_quantity_order(buy);
+        let request = build_signed_request(order, self.recv_window)?;
+        self.client
+            .post_signed::<Empty>(API::Spot(Spot::OrderTest), request)
+            .await
+            .map(|_| ())
+    }
+
+    // Place a MARKET order - SELL
+    pub async fn market_sell<S, F>(&self, symbol: S, qty: F) -> Result<Transaction>
+    where
+        S: Into<String>,
+        F: IntoNumber,
+    {
+        let sell = OrderRequest {
+            symbol: symbol.into(),
+            qty: qty.try_into_number()?,
+            price: Number::default(),
+            stop_price: None,
+            order_side: OrderSide::Sell,
+            order_type: OrderType::Market,
+            time_in_force: TimeInForce::GTC,
+            new_client_order_id: None,
+        };
+        let order = build_order(sell);
+        let request = build_signed_request(order, self.recv_window)?;
+        self.client
+            .post_signed_order(API::Spot(Spot::Order), request)
+            .await
+    }
+
+    /// Place a test MARKET order - SELL
+    ///
+    /// This order is sandboxed: it is validated, but not sent to the matching engine.
+    pub async fn test_market_sell<S, F>(&self, symbol: S, qty: F) -> Result<()>
+    where
+        S: Into<String>,
+        F: IntoNumber,
+    {
+        let sell = OrderRequest {
+            symbol: symbol.into(),
+            qty: qty.try_into_number()?,
+            price: Number::default(),
+            stop_price: None,
+            order_side: OrderSide::Sell,
+            order_type: OrderType::Market,
+            time_in_force: TimeInForce::GTC,
+            new_client_order_id: None,
+        };
+        let order = build_order(sell);
+        let request = build_signed_request(order, self.recv_window)?;
+        self.client
+            .post_signed::<Empty>(API::Spot(Spot::OrderTest), request)
+            .await
+            .map(|_| ())
+    }
+
+    // Place a MARKET order with quote quantity - SELL
+    pub async fn market_sell_using_quote_quantity<S, F>(
+        &self, symbol: S, quote_order_qty: F,
+    ) -> Result<Transaction>
+    where
+        S: Into<String>,
+        F: IntoNumber,
+    {
+        let sell = OrderQuoteQuantityRequest {
+            symbol: symbol.into(),
+            quote_order_qty: quote_order_qty.try_into_number()?,
+            price: Number::default(),
+            order_side: OrderSide::Sell,
+            order_type: OrderType::Market,
+            time_in_force: TimeInForce::GTC,
+            new_client_order_id: None,
+        };
+        let order = build_quote_quantity_order(sell);
+        let request = build_signed_request(order, self.recv_window)?;
+        self.client
+            .post_signed_order(API::Spot(Spot::Order), request)
+            .await
+    }
+
+    /// Place a test MARKET order with quote quantity - SELL
+    ///
+    /// This order is sandboxed: it is validated, but not sent to the matching engine.
+    pub async fn test_market_sell_using_quote_quantity<S, F>(
+        &self, symbol: S, quote_order_qty: F,
+    ) -> Result<()>
+    where
+        S: Into<String>,
+        F: IntoNumber,
+    {
+        let sell = OrderQuoteQuantityRequest {
+            symbol: symbol.into(),
+            quote_order_qty: quote_order_qty.try_into_number()?,
+            price: Number::default(),
+            order_side: OrderSide::Sell,
+            order_type: OrderType::Market,
+            time_in_force: TimeInForce::GTC,
+            new_client_order_id: None,
+        };
+        let order = build_quote_quantity_order(sell);
+        let request = build_signed_request(order, self.recv_window)?;
+        self.client
+            .post_signed::<Empty>(API::Spot(Spot::OrderTest), request)
+            .await
+            .map(|_| ())
+    }
+
+    /// Create a stop limit buy order for the given symbol, price and stop price.
+    /// Returning a `Transaction` value with the same parameters sent on the order.
+    pub async fn stop_limit_buy_order<S, F>(
+        &self, symbol: S, qty: F, price: impl IntoNumber, stop_price: impl IntoNumber,
+        time_in_force: TimeInForce,
+    ) -> Result<Transaction>
+    where
+        S: Into<String>,
+        F: IntoNumber,
+    {
+        let sell = OrderRequest {
+            symbol: symbol.into(),
+            qty: qty.try_into_number()?,
+            price: price.try_into_number()?,
+            stop_price: Some(stop_price.try_into_number()?),
+            order_side: OrderSide::Buy,
+            order_type: OrderType::StopLossLimit,
+            time_in_force,
+            new_client_order_id: None,
+        };
+        let order = build_order(sell);
+        let request = build_signed_request(order, self.recv_window)?;
+        self.client
+            .post_signed_order(API::Spot(Spot::Order), request)
+            .await
+    }
+
+    /// Create a stop limit buy test order for the given symbol, price and stop price.
+    /// Returning a `Transaction` value with the same parameters sent on the order.
+    ///
+    /// This order is sandboxed: it is validated, but not sent to the matching engine.
+    pub async fn test_stop_limit_buy_order<S, F>(
+        &self, symbol: S, qty: F, price: impl IntoNumber, stop_price: impl IntoNumber,
+        time_in_force: TimeInForce,
+    ) -> Result<()>
+    where
+        S: Into<String>,
+        F: IntoNumber,
+    {
+        let sell = OrderRequest {
+            symbol: symbol.into(),
+            qty: qty.try_into_number()?,
+            price: price.try_into_number()?,
+            stop_price: Some(stop_price.try_into_number()?),
+            order_side: OrderSide::Buy,
+            order_type: OrderType::StopLossLimit,
+            time_in_force,
+            new_client_order_id: None,
+        };
+        let order = build_order(sell);
+        let request = build_signed_request(order, self.recv_window)?;
+        self.client
+            .post_signed::<Empty>(API::Spot(Spot::OrderTest), request)
+            .await
+            .map(|_| ())
+    }
+
+    /// Create a stop limit sell order for the given symbol, price and stop price.
+    /// Returning a `Transaction` value with the same parameters sent on the order.
+    pub async fn stop_limit_sell_order<S, F>(
+        &self, symbol: S, qty: F, price: impl IntoNumber, stop_price: impl IntoNumber,
+        time_in_force: TimeInForce,
+    ) -> Result<Transaction>
+    where
+        S: Into<String>,
+        F: IntoNumber,
+    {
+        let sell = OrderRequest {
+            symbol: symbol.into(),
+            qty: qty.try_into_number()?,
+            price: price.try_into_number()?,
+            stop_price: Some(stop_price.try_into_number()?),
+            order_side: OrderSide::Sell,
+            order_type: OrderType::StopLossLimit,
+            time_in_force,
+            new_client_order_id: None,
+        };
+        let order = build_order(sell);
+        let request = build_signed_request(order, self.recv_window)?;
+        self.client
+            .post_signed_order(API::Spot(Spot::Order), request)
+            .await
+    }
+
+    /// Create a stop limit sell order for the given symbol, price and stop price.
+    /// Returning a `Transaction` value with the same parameters sent on the order.
+    ///
+    /// This order is sandboxed: it is validated, but not sent to the matching engine.
+    pub async fn test_stop_limit_sell_order<S, F>(
+        &self, symbol: S, qty: F, price: impl IntoNumber, stop_price: impl IntoNumber,
+        time_in_force: TimeInForce,
+    ) -> Result<()>
+    where
+        S: Into<String>,
+        F: IntoNumber,
+    {
+        let sell = OrderRequest {
+            symbol: symbol.into(),
+            qty: qty.try_into_number()?,
+            price: price.try_into_number()?,
+            stop_price: Some(stop_price.try_into_number()?),
+            order_side: OrderSide::Sell,
+            order_type: OrderType::StopLossLimit,
+            time_in_force,
+            new_client_order_id: None,
+        };
+        let order = build_order(sell);
+        let request = build_signed_request(order, self.recv_window)?;
+        self.client
+            .post_signed::<Empty>(API::Spot(Spot::OrderTest), request)
+            .await
+            .map(|_| ())
+    }
+
+    /// Place a custom order
+    #[allow(clippy::too_many_arguments)]
+    pub async fn custom_order<S, F>(
+        &self, symbol: S, qty: F, price: impl IntoNumber, stop_price: Option<Number>,
+        order_side: OrderSide, order_type: OrderType, time_in_force: TimeInForce,
+        new_client_order_id: Option<String>,
+    ) -> Result<Transaction>
+    where
+        S: Into<String>,
+        F: IntoNumber,
+    {
+        let mut sell = OrderRequest {
+            symbol: symbol.into(),
+            qty: qty.try_into_number()?,
+            price: price.try_into_number()?,
+            stop_price,
+            order_side,
+            order_type,
+            time_in_force,
+            new_client_order_id,
+        };
+        if let Some(validator) = &self.validator {
+            sell.validate(validator)?;
+        }
+        let order = build_order(sell);
+        let request = build_signed_request(order, self.recv_window)?;
+        self.client
+            .post_signed_order(API::Spot(Spot::Order), request)
+            .await
+    }
+
+    /// Place a test custom order
+    ///
+    /// This order is sandboxed: it is validated, but not sent to the matching engine.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn test_custom_order<S, F>(
+        &self, symbol: S, qty: F, price: impl IntoNumber, stop_price: Option<Number>,
+        order_side: OrderSide, order_type: OrderType, time_in_force: TimeInForce,
+        new_client_order_id: Option<String>,
+    ) -> Result<()>
+    where
+        S: Into<String>,
+        F: IntoNumber,
+    {
+        let mut sell = OrderRequest {
+            symbol: symbol.into(),
+            qty: qty.try_into_number()?,
+            price: price.try_into_number()?,
+            stop_price,
+            order_side,
+            order_type,
+            time_in_force,
+            new_client_order_id,
+        };
+        if let Some(validator) = &self.validator {
+            sell.validate(validator)?;
+        }
+        let order = build_order(sell);
+        let request = build_signed_request(order, self.recv_window)?;
+        self.client
+            .post_signed::<Empty>(API::Spot(Spot::OrderTest), request)
+            .await
+            .map(|_| ())
+    }
+
+    // Check an order's status
+    pub async fn cancel_order<S>(&self, symbol: S, order_id: u64) -> Result<OrderCanceled>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("orderId".into(), order_id.to_string());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .delete_signed(API::Spot(Spot::Order), Some(request))
+            .await
+    }
+
+    pub async fn cancel_order_with_client_id<S>(
+        &self, symbol: S, orig_client_order_id: String,
+    ) -> Result<OrderCanceled>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("origClientOrderId".into(), orig_client_order_id);
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .delete_signed(API::Spot(Spot::Order), Some(request))
+            .await
+    }
+
+    /// Place a test cancel order
+    ///
+    /// This order is sandboxed: it is validated, but not sent to the matching engine.
+    pub async fn test_cancel_order<S>(&self, symbol: S, order_id: u64) -> Result<()>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("orderId".into(), order_id.to_string());
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .delete_signed::<Empty>(API::Spot(Spot::OrderTest), Some(request))
+            .await
+            .map(|_| ())
+    }
+
+    /// Cancel an order and place a new one in a single request.
+    ///
+    /// Unless both succeed the error is `ErrorKind::CancelReplaceFailed`, which tells what
+    /// happened to either order.
+    pub async fn cancel_replace_order(
+        &self, order: CancelReplaceRequest,
+    ) -> Result<CancelReplaceResponse> {
+        let parameters = build_cancel_replace_order(order);
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed_order(API::Spot(Spot::OrderCancelReplace), request)
+            .await
+    }
+
+    /// Reduce the quantity of an open order to `new_qty`, keeping its priority in the order book
+    pub async fn amend_order_keep_priority<S>(
+        &self, symbol: S, order_id: u64, new_qty: impl IntoNumber,
+    ) -> Result<AmendKeepPriorityResponse>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("orderId".into(), order_id.to_string());
+        parameters.insert("newQty".into(), new_qty.try_into_number()?.to_string());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .put_signed(API::Spot(Spot::OrderAmendKeepPriority), request)
+            .await
+    }
+
+    pub async fn amend_order_keep_priority_with_client_id<S>(
+        &self, symbol: S, orig_client_order_id: String, new_qty: impl IntoNumber,
+    ) -> Result<AmendKeepPriorityResponse>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("origClientOrderId".into(), orig_client_order_id);
+        parameters.insert("newQty".into(), new_qty.try_into_number()?.to_string());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .put_signed(API::Spot(Spot::OrderAmendKeepPriority), request)
+            .await
+    }
+
+    /// Place an OCO order
+    pub async fn oco_order(&self, order: OcoOrderRequest) -> Result<OrderList> {
+        let order = build_oco_order(order);
+        let request = build_signed_request(order, self.recv_window)?;
+        self.client
+            .post_signed_order(API::Spot(Spot::Oco), request)
+            .await
+    }
+
+    /// Cancel an entire order list
+    pub async fn cancel_order_list<S>(&self, symbol: S, order_list_id: u64) -> Result<OrderList>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("orderListId".into(), order_list_id.to_string());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .delete_signed(API::Spot(Spot::OrderList), Some(request))
+            .await
+    }
+
+    pub async fn cancel_order_list_with_client_id<S>(
+        &self, symbol: S, list_client_order_id: String,
+    ) -> Result<OrderList>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("listClientOrderId".into(), list_client_order_id);
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .delete_signed(API::Spot(Spot::OrderList), Some(request))
+            .await
+    }
+
+    // Check an order list's status
+    pub async fn order_list_status(&self, order_list_id: u64) -> Result<OrderList> {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("orderListId".into(), order_list_id.to_string());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Spot(Spot::OrderList), Some(request))
+            .await
+    }
+
+    pub async fn order_list_status_with_client_id(
+        &self, list_client_order_id: String,
+    ) -> Result<OrderList> {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("origClientOrderId".into(), list_client_order_id);
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Spot(Spot::OrderList), Some(request))
+            .await
+    }
+
+    /// All order lists, optionally starting from `from_id` or bounded by time
+    pub async fn get_all_order_lists(
+        &self, from_id: Option<u64>, start_time: Option<u64>, end_time: Option<u64>,
+        limit: Option<u16>,
+    ) -> Result<Vec<OrderList>> {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        if let Some(from_id) = from_id {
+            parameters.insert("fromId".into(), from_id.to_string());
+        }
+        if let Some(start_time) = start_time {
+            parameters.insert("startTime".into(), start_time.to_string());
+        }
+        if let Some(end_time) = end_time {
+            parameters.insert("endTime".into(), end_time.to_string());
+        }
+        if let Some(limit) = limit {
+            parameters.insert("limit".into(), limit.to_string());
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Spot(Spot::AllOrderList), Some(request))
+            .await
+    }
+
+    // Current open order lists
+    pub async fn get_open_order_lists(&self) -> Result<Vec<OrderList>> {
+        let request = build_signed_request(BTreeMap::new(), self.recv_window)?;
+        self.client
+            .get_signed(API::Spot(Spot::OpenOrderList), Some(request))
+            .await
+    }
+
+    // Trade history
+    pub async fn trade_history<S>(&self, symbol: S) -> Result<Vec<TradeHistory>>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Spot(Spot::MyTrades), Some(request))
+            .await
+    }
+
+    // Trade history starting from selected date
+    pub async fn trade_history_from<S>(
+        &self, symbol: S, start_time: u64,
+    ) -> Result<Vec<TradeHistory>>
+    where
+        S: Into<String>,
+    {
+        if !is_start_time_valid(&start_time) {
+            bail!("Start time should be less than the current time");
+        }
+
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("startTime".into(), start_time.to_string());
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Spot(Spot::MyTrades), Some(request))
+            .await
+    }
+
+    // Trade history starting from selected time to some time
+    pub async fn trade_history_from_to<S>(
+        &self, symbol: S, start_time: u64, end_time: u64,
+    ) -> Result<Vec<TradeHistory>>
+    where
+        S: Into<String>,
+    {
+        if end_time <= start_time {
+            bail!("End time should be greater than start time");
+        }
+        if !is_start_time_valid(&start_time) {
+            bail!("Start time should be less than the current time");
+        }
+        self.get_trades(symbol, start_time, end_time).await
+    }
+
+    async fn get_trades<S>(
+        &self, symbol: S, start_time: u64, end_time: u64,
+    ) -> Result<Vec<TradeHistory>>
+    where
+        S: Into<String>,
+    {
+        let mut trades = match self.trade_history_from(symbol, start_time).await {
+            Ok(trades) => trades,
+            Err(e) => return Err(e),
+        };
+        trades.retain(|trade| trade.time <= end_time);
+        Ok(trades)
+    }
+}
@@ -0,0 +1,230 @@
+use crate::errors::Result;
+use reqwest::{Method, StatusCode};
+use reqwest::Response;
+use reqwest::header::HeaderMap;
+use serde::de::DeserializeOwned;
+use crate::api::API;
+use crate::config::Config;
+use crate::signer::Signer;
+use crate::timesync::TimeSync;
+use crate::ratelimit::RateLimiter;
+use crate::client::{build_headers, response_error, route, sign_request, time_endpoint};
+use std::time::Duration;
+
+#[derive(Clone)]
+pub struct Client {
+    api_key: String,
+    secret_key: String,
+    host: String,
+    rate_limiter: RateLimiter,
+    time_sync: TimeSync,
+    signer: Signer,
+    coin_futures: bool,
+    inner_client: reqwest::Client,
+}
+
+impl Client {
+    pub fn new(
+        api_key: Option<String>, secret_key: Option<String>, host: String, config: &Config,
+    ) -> Self {
+        Client {
+            api_key: api_key.unwrap_or_default(),
+            secret_key: secret_key.unwrap_or_default(),
+            host,
+            rate_limiter: config.rate_limiter.clone(),
+            time_sync: config.time_sync.clone(),
+            signer: config.signer.clone(),
+            coin_futures: false,
+            inner_client: reqwest::Client::builder()
+                .pool_idle_timeout(None)
+                .build()
+                .unwrap(),
+        }
+    }
+
+    /// Sends the `API::Futures` routes to the COIN-M API (`/dapi`) instead of USDⓈ-M (`/fapi`).
+    pub fn with_coin_futures(mut self) -> Self {
+        self.coin_futures = true;
+        self
+    }
+
+    pub fn is_coin_futures(&self) -> bool {
+        self.coin_futures
+    }
+
+    pub async fn get_signed<T: DeserializeOwned>(
+        &self, endpoint: API, request: Option<String>,
+    ) -> Result<T> {
+        self.signed_request(Method::GET, endpoint, request, false)
+            .await
+    }
+
+    pub async fn post_signed<T: DeserializeOwned>(
+        &self, endpoint: API, request: String,
+    ) -> Result<T> {
+        self.signed_request(Method::POST, endpoint, Some(request), false)
+            .await
+    }
+
+    /// `post_signed` for requests that place an order, which also wait for the `ORDERS` limits.
+    pub async fn post_signed_order<T: DeserializeOwned>(
+        &self, endpoint: API, request: String,
+    ) -> Result<T> {
+        self.signed_request(Method::POST, endpoint, Some(request), true)
+            .await
+    }
+
+    pub async fn put_signed<T: DeserializeOwned>(
+        &self, endpoint: API, request: String,
+    ) -> Result<T> {
+        self.signed_request(Method::PUT, endpoint, Some(request), false)
+            .await
+    }
+
+    /// `put_signed` for requests that count as an order, which also wait for the `ORDERS` limits.
+    pub async fn put_signed_order<T: DeserializeOwned>(
+        &self, endpoint: API, request: String,
+    ) -> Result<T> {
+        self.signed_request(Method::PUT, endpoint, Some(request), true)
+            .await
+    }
+
+    pub async fn delete_signed<T: DeserializeOwned>(
+        &self, endpoint: API, request: Option<String>,
+    ) -> Result<T> {
+        self.signed_request(Method::DELETE, endpoint, request, false)
+            .await
+    }
+
+    pub async fn get<T: DeserializeOwned>(
+        &self, endpoint: API, request: Option<String>,
+    ) -> Result<T> {
+        let mut url: String = format!("{}{}", self.host, String::try_from(self.route(endpoint)?)?);
+        if let Some(request) = request {
+            if !request.is_empty() {
+                url.push_str(format!("?{}", request).as_str());
+            }
+        }
+
+        self.throttle(false).await?;
+        let client = &self.inner_client;
+        let response = client.get(url.as_str()).send().await?;
+
+        self.handler(response).await
+    }
+
+    pub async fn post<T: DeserializeOwned>(&self, endpoint: API) -> Result<T> {
+        let url: String = format!("{}{}", self.host, String::try_from(self.route(endpoint)?)?);
+
+        self.throttle(false).await?;
+        let client = &self.inner_client;
+        let response = client
+            .post(url.as_str())
+            .headers(self.build_headers(false)?)
+            .send()
+            .await?;
+
+        self.handler(response).await
+    }
+
+    pub async fn put<T: DeserializeOwned>(&self, endpoint: API, listen_key: &str) -> Result<T> {
+        let url: String = format!("{}{}", self.host, String::try_from(self.route(endpoint)?)?);
+        let data: String = format!("listenKey={}", listen_key);
+
+        self.throttle(false).await?;
+        let client = &self.inner_client;
+        let response = client
+            .put(url.as_str())
+            .headers(self.build_headers(false)?)
+            .body(data)
+            .send()
+            .await?;
+
+        self.handler(response).await
+    }
+
+    pub async fn delete<T: DeserializeOwned>(&self, endpoint: API, listen_key: &str) -> Result<T> {
+        let url: String = format!("{}{}", self.host, String::try_from(self.route(endpoint)?)?);
+        let data: String = format!("listenKey={}", listen_key);
+
+        self.throttle(false).await?;
+        let client = &self.inner_client;
+        let response = client
+            .delete(url.as_str())
+            .headers(self.build_headers(false)?)
+            .body(data)
+            .send()
+            .await?;
+
+        self.handler(response).await
+    }
+
+    // Request must be signed
+    // Throttles before signing, so the timestamp is not left behind by the wait
+    async fn signed_request<T: DeserializeOwned>(
+        &self, method: Method, endpoint: API, request: Option<String>, orders: bool,
+    ) -> Result<T> {
+        let waited = self.throttle(orders).await?;
+        let url = self.sign_request(endpoint, request, waited).await?;
+        let client = &self.inner_client;
+        let response = client
+            .request(method, url.as_str())
+            .headers(self.build_headers(true)?)
+            .send()
+            .await?;
+
+        self.handler(response).await
+    }
+
+    // `waited` is how long throttling delayed the request after it was stamped
+    async fn sign_request(
+        &self, endpoint: API, request: Option<String>, waited: Duration,
+    ) -> Result<String> {
+        let endpoint = self.route(endpoint)?;
+        if self.time_sync.needs_sync() {
+            let time_endpoint = time_endpoint(&endpoint);
+            self.time_sync
+                .sync_async(|| self.get(time_endpoint, None))
+                .await?;
+        }
+        let request = request.map(|request| self.time_sync.apply(request, waited));
+
+        sign_request(
+            &self.host,
+            &self.secret_key,
+            &self.signer,
+            endpoint,
+            request,
+        )
+    }
+
+    fn route(&self, endpoint: API) -> Result<API> {
+        route(endpoint, self.coin_futures)
+    }
+
+    fn build_headers(&self, content_type: bool) -> Result<HeaderMap> {
+        build_headers(&self.api_key, content_type)
+    }
+
+    async fn throttle(&self, orders: bool) -> Result<Duration> {
+        match self.rate_limiter.delay(orders)? {
+            Some(delay) => {
+                tokio::time::sleep(delay).await;
+                Ok(delay)
+            }
+            None => Ok(Duration::ZERO),
+        }
+    }
+
+    async fn handler<T: DeserializeOwned>(&self, response: Response) -> Result<T> {
+        self.rate_limiter.update(response.headers());
+        let status = response.status();
+        if status == StatusCode::OK {
+            return Ok(response.json::<T>().await?);
+        }
+        let headers = response.headers().clone();
+        let body = response.text().await?;
+
+        Err(response_error(status, headers, &body, &self.rate_limiter))
+    }
+}
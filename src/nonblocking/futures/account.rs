@@ -0,0 +1,661 @@
+use std::collections::BTreeMap;
+use std::marker::PhantomData;
+use error_chain::bail;
+use crate::util::{build_signed_request, insert_time_range};
+use crate::errors::Result;
+use crate::nonblocking::client::Client;
+use crate::validation::OrderValidator;
+use crate::api::{API, Futures};
+use crate::model::{Empty, IntoNumber};
+use crate::account::OrderSide;
+use crate::futures::account::{
+    build_batch_cancel, build_batch_orders, build_modify_order, build_order, BatchResponse,
+    BatchResult, CustomOrderRequest, ModifyOrderRequest, IncomeRequest, OrderRequest, OrderType,
+    TimeInForce,
+};
+
+use crate::futures::model::{
+    CountdownCancelAll, FuturesContracts, LeverageBracket, OneOrMany, OrderAmendment,
+    PositionSizing, UsdM,
+};
+
+#[derive(Clone)]
+pub struct FuturesAccount<M = UsdM> {
+    pub client: Client,
+    pub recv_window: u64,
+    /// Checks custom orders against the exchange filters before they are sent.
+    pub validator: Option<OrderValidator>,
+    pub(crate) contracts: PhantomData<M>,
+}
+
+impl<M: FuturesContracts> FuturesAccount<M> {
+    /// Validate (and optionally round) custom orders before sending them.
+    pub fn with_validator(mut self, validator: OrderValidator) -> Self {
+        self.validator = Some(validator);
+        self
+    }
+
+    pub async fn limit_buy(
+        &self, symbol: impl Into<String>, qty: impl IntoNumber, price: impl IntoNumber,
+        time_in_force: TimeInForce,
+    ) -> Result<M::Transaction> {
+        let buy = OrderRequest {
+            symbol: symbol.into(),
+            side: OrderSide::Buy,
+            position_side: None,
+            order_type: OrderType::Limit,
+            time_in_force: Some(time_in_force),
+            qty: Some(qty.try_into_number()?),
+            reduce_only: None,
+            price: Some(price.try_into_number()?),
+            stop_price: None,
+            close_position: None,
+            activation_price: None,
+            callback_rate: None,
+            working_type: None,
+            price_protect: None,
+        };
+        let order = build_order(buy);
+        let request = build_signed_request(order, self.recv_window)?;
+        self.client
+            .post_signed_order(API::Futures(Futures::Order), request)
+            .await
+    }
+
+    pub async fn limit_sell(
+        &self, symbol: impl Into<String>, qty: impl IntoNumber, price: impl IntoNumber,
+        time_in_force: TimeInForce,
+    ) -> Result<M::Transaction> {
+        let sell = OrderRequest {
+            symbol: symbol.into(),
+            side: OrderSide::Sell,
+            position_side: None,
+            order_type: OrderType::Limit,
+            time_in_force: Some(time_in_force),
+            qty: Some(qty.try_into_number()?),
+            reduce_only: None,
+            price: Some(price.try_into_number()?),
+            stop_price: None,
+            close_position: None,
+            activation_price: None,
+            callback_rate: None,
+            working_type: None,
+            price_protect: None,
+        };
+        let order = build_order(sell);
+        let request = build_signed_request(order, self.recv_window)?;
+        self.client
+            .post_signed_order(API::Futures(Futures::Order), request)
+            .await
+    }
+
+    // Place a MARKET order - BUY
+    pub async fn market_buy<S, F>(&self, symbol: S, qty: F) -> Result<M::Transaction>
+    where
+        S: Into<String>,
+        F: IntoNumber,
+    {
+        let buy = OrderRequest {
+            symbol: symbol.into(),
+            side: OrderSide::Buy,
+            position_side: None,
+            order_type: OrderType::Market,
+            time_in_force: None,
+            qty: Some(qty.try_into_number()?),
+            reduce_only: None,
+            price: None,
+            stop_price: None,
+            close_position: None,
+            activation_price: None,
+            callback_rate: None,
+            working_type: None,
+            price_protect: None,
+        };
+        let order = build_order(buy);
+        let request = build_signed_request(order, self.recv_window)?;
+        self.client
+            .post_signed_order(API::Futures(Futures::Order), request)
+            .await
+    }
+
+    // Place a MARKET order - SELL
+    pub async fn market_sell<S, F>(&self, symbol: S, qty: F) -> Result<M::Transaction>
+    where
+        S: Into<String>,
+        F: IntoNumber,
+    {
+        let sell = OrderRequest {
+            symbol: symbol.into(),
+            side: OrderSide::Sell,
+            position_side: None,
+            order_type: OrderType::Market,
+            time_in_force: None,
+            qty: Some(qty.try_into_number()?),
+            reduce_only: None,
+            price: None,
+            stop_price: None,
+            close_position: None,
+            activation_price: None,
+            callback_rate: None,
+            working_type: None,
+            price_protect: None,
+        };
+        let order = build_order(sell);
+        let request = build_signed_request(order, self.recv_window)?;
+        self.client
+            .post_signed_order(API::Futures(Futures::Order), request)
+            .await
+    }
+
+    pub async fn cancel_order<S>(&self, symbol: S, order_id: u64) -> Result<M::CanceledOrder>
+    where
+        S: Into<String>,
+    {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("orderId".into(), order_id.to_string());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .delete_signed(API::Futures(Futures::Order), Some(request))
+            .await
+    }
+
+    pub async fn cancel_order_with_client_id<S>(
+        &self, symbol: S, orig_client_order_id: String,
+    ) -> Result<M::CanceledOrder>
+    where
+        S: Into<String>,
+    {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("origClientOrderId".into(), orig_client_order_id);
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .delete_signed(API::Futures(Futures::Order), Some(request))
+            .await
+    }
+
+    // Check an order's status
+    pub async fn order_status<S>(&self, symbol: S, order_id: u64) -> Result<M::Order>
+    where
+        S: Into<String>,
+    {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("orderId".into(), order_id.to_string());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Futures(Futures::Order), Some(request))
+            .await
+    }
+
+    pub async fn order_status_with_client_id<S>(
+        &self, symbol: S, orig_client_order_id: String,
+    ) -> Result<M::Order>
+    where
+        S: Into<String>,
+    {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("origClientOrderId".into(), orig_client_order_id);
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Futures(Futures::Order), Some(request))
+            .await
+    }
+
+    /// Change the price and quantity of a live LIMIT order, which keeps its id
+    pub async fn modify_order(&self, modify_request: ModifyOrderRequest) -> Result<M::Order> {
+        let parameters = build_modify_order(modify_request)?;
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .put_signed_order(API::Futures(Futures::Order), request)
+            .await
+    }
+
+    /// Modifications of one order, found by `order_id` or `orig_client_order_id`, or of all
+    /// orders of `symbol` when both are `None`
+    pub async fn get_order_amendments<S>(
+        &self, symbol: S, order_id: Option<u64>, orig_client_order_id: Option<String>,
+        start_time: Option<u64>, end_time: Option<u64>, limit: Option<u16>,
+    ) -> Result<Vec<OrderAmendment>>
+    where
+        S: Into<String>,
+    {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        if let Some(order_id) = order_id {
+            parameters.insert("orderId".into(), order_id.to_string());
+        }
+        if let Some(orig_client_order_id) = orig_client_order_id {
+            parameters.insert("origClientOrderId".into(), orig_client_order_id);
+        }
+        insert_time_range(&mut parameters, start_time, end_time);
+        if let Some(limit) = limit {
+            parameters.insert("limit".into(), limit.to_string());
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Futures(Futures::OrderAmendment), Some(request))
+            .await
+    }
+
+    /// Cancel all open orders of `symbol` unless called again within `countdown_time`
+    /// milliseconds; 0 cancels the countdown.
+    pub async fn countdown_cancel_all<S>(
+        &self, symbol: S, countdown_time: u64,
+    ) -> Result<CountdownCancelAll>
+    where
+        S: Into<String>,
+    {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("countdownTime".into(), countdown_time.to_string());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed(API::Futures(Futures::CountdownCancelAll), request)
+            .await
+    }
+
+    // Place a STOP_MARKET close - BUY
+    pub async fn stop_market_close_buy<S, F>(
+        &self, symbol: S, stop_price: F,
+    ) -> Result<M::Transaction>
+    where
+        S: Into<String>,
+        F: IntoNumber,
+    {
+        let sell = OrderRequest {
+            symbol: symbol.into(),
+            side: OrderSide::Buy,
+            position_side: None,
+            order_type: OrderType::StopMarket,
+            time_in_force: None,
+            qty: None,
+            reduce_only: None,
+            price: None,
+            stop_price: Some(stop_price.try_into_number()?),
+            close_position: Some(true),
+            activation_price: None,
+            callback_rate: None,
+            working_type: None,
+            price_protect: None,
+        };
+        let order = build_order(sell);
+        let request = build_signed_request(order, self.recv_window)?;
+        self.client
+            .post_signed_order(API::Futures(Futures::Order), request)
+            .await
+    }
+
+    // Place a STOP_MARKET close - SELL
+    pub async fn stop_market_close_sell<S, F>(
+        &self, symbol: S, stop_price: F,
+    ) -> Result<M::Transaction>
+    where
+        S: Into<String>,
+        F: IntoNumber,
+    {
+        let sell = OrderRequest {
+            symbol: symbol.into(),
+            side: OrderSide::Sell,
+            position_side: None,
+            order_type: OrderType::StopMarket,
+            time_in_force: None,
+            qty: None,
+            reduce_only: None,
+            price: None,
+            stop_price: Some(stop_price.try_into_number()?),
+            close_position: Some(true),
+            activation_price: None,
+            callback_rate: None,
+            working_type: None,
+            price_protect: None,
+        };
+        let order = build_order(sell);
+        let request = build_signed_request(order, self.recv_window)?;
+        self.client
+            .post_signed_order(API::Futures(Futures::Order), request)
+            .await
+    }
+
+    // Custom order for for professional traders
+    pub async fn custom_order(&self, order_request: CustomOrderRequest) -> Result<M::Transaction> {
+        let mut order_request: OrderRequest = order_request.into();
+        if let Some(validator) = &self.validator {
+            order_request.validate(validator)?;
+        }
+        let order = build_order(order_request);
+        let request = build_signed_request(order, self.recv_window)?;
+        self.client
+            .post_signed_order(API::Futures(Futures::Order), request)
+            .await
+    }
+
+    /// Place up to 5 orders in a single request
+    ///
+    /// Binance reports failures per order, so each entry is either the placed order
+    /// or the error returned for it, in request order.
+    pub async fn custom_batch_orders(
+        &self, order_requests: Vec<CustomOrderRequest>,
+    ) -> Result<Vec<BatchResult<M::Transaction>>> {
+        let request = build_batch_orders(order_requests)?;
+        let request = build_signed_request(request, self.recv_window)?;
+        self.client
+            .post_signed_order::<Vec<BatchResponse<M::Transaction>>>(
+                API::Futures(Futures::BatchOrders),
+                request,
+            )
+            .await
+            .map(|responses| {
+                responses
+                    .into_iter()
+                    .map(BatchResponse::into_result)
+                    .collect()
+            })
+    }
+
+    /// Cancel up to 10 orders by order id
+    pub async fn cancel_multiple_orders<S>(
+        &self, symbol: S, order_ids: Vec<u64>,
+    ) -> Result<Vec<BatchResult<M::CanceledOrder>>>
+    where
+        S: Into<String>,
+    {
+        let parameters = build_batch_cancel(symbol.into(), "orderIdList", &order_ids)?;
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .delete_signed::<Vec<BatchResponse<M::CanceledOrder>>>(
+                API::Futures(Futures::BatchOrders),
+                Some(request),
+            )
+            .await
+            .map(|responses| {
+                responses
+                    .into_iter()
+                    .map(BatchResponse::into_result)
+                    .collect()
+            })
+    }
+
+    /// Cancel up to 10 orders by client order id
+    pub async fn cancel_multiple_orders_with_client_ids<S>(
+        &self, symbol: S, orig_client_order_ids: Vec<String>,
+    ) -> Result<Vec<BatchResult<M::CanceledOrder>>>
+    where
+        S: Into<String>,
+    {
+        let parameters = build_batch_cancel(
+            symbol.into(),
+            "origClientOrderIdList",
+            &orig_client_order_ids,
+        )?;
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .delete_signed::<Vec<BatchResponse<M::CanceledOrder>>>(
+                API::Futures(Futures::BatchOrders),
+                Some(request),
+            )
+            .await
+            .map(|responses| {
+                responses
+                    .into_iter()
+                    .map(BatchResponse::into_result)
+                    .collect()
+            })
+    }
+
+    pub async fn get_all_orders<S, F, N>(
+        &self, symbol: S, order_id: F, start_time: F, end_time: F, limit: N,
+    ) -> Result<Vec<M::Order>>
+    where
+        S: Into<String>,
+        F: Into<Option<u64>>,
+        N: Into<Option<u16>>,
+    {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        if let Some(order_id) = order_id.into() {
+            parameters.insert("orderId".into(), order_id.to_string());
+        }
+        if let Some(start_time) = start_time.into() {
+            parameters.insert("startTime".into(), start_time.to_string());
+        }
+        if let Some(end_time) = end_time.into() {
+            parameters.insert("endTime".into(), end_time.to_string());
+        }
+        if let Some(limit) = limit.into() {
+            parameters.insert("limit".into(), limit.to_string());
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Futures(Futures::AllOrders), Some(request))
+            .await
+    }
+
+    pub async fn get_user_trades<S, F, N>(
+        &self, symbol: S, from_id: F, start_time: F, end_time: F, limit: N,
+    ) -> Result<Vec<M::TradeHistory>>
+    where
+        S: Into<String>,
+        F: Into<Option<u64>>,
+        N: Into<Option<u16>>,
+    {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        if let Some(order_id) = from_id.into() {
+            parameters.insert("fromId".into(), order_id.to_string());
+        }
+        if let Some(start_time) = start_time.into() {
+            parameters.insert("startTime".into(), start_time.to_string());
+        }
+        if let Some(end_time) = end_time.into() {
+            parameters.insert("endTime".into(), end_time.to_string());
+        }
+        if let Some(limit) = limit.into() {
+            parameters.insert("limit".into(), limit.to_string());
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Futures(Futures::UserTrades), Some(request))
+            .await
+    }
+
+    pub async fn position_information<S>(&self, symbol: S) -> Result<Vec<M::PositionRisk>>
+    where
+        S: Into<String>,
+    {
+        let mut parameters = BTreeMap::new();
+        // COIN-M positions are queried by pair, e.g. BTCUSD
+        let key = if self.client.is_coin_futures() {
+            "pair"
+        } else {
+            "symbol"
+        };
+        parameters.insert(key.into(), symbol.into());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Futures(Futures::PositionRisk), Some(request))
+            .await
+    }
+
+    pub async fn account_information(&self) -> Result<M::AccountInformation> {
+        let parameters = BTreeMap::new();
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Futures(Futures::Account), Some(request))
+            .await
+    }
+
+    pub async fn account_balance(&self) -> Result<Vec<M::AccountBalance>> {
+        let parameters = BTreeMap::new();
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Futures(Futures::Balance), Some(request))
+            .await
+    }
+
+    // Notional tiers of ONE symbol, or of all symbols
+    pub async fn get_leverage_brackets(
+        &self, symbol: Option<String>,
+    ) -> Result<Vec<LeverageBracket>> {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        if let Some(symbol) = symbol {
+            parameters.insert("symbol".into(), symbol);
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        let brackets: OneOrMany<LeverageBracket> = self
+            .client
+            .get_signed(API::Futures(Futures::LeverageBracket), Some(request))
+            .await?;
+        Ok(brackets.into_many())
+    }
+
+    /// Largest position of `symbol` that `wallet_balance` can open at `leverage` and `price`,
+    /// from the symbol's leverage brackets. See `LeverageBracket::position_sizing`.
+    pub async fn position_sizing<S>(
+        &self, symbol: S, leverage: u8, wallet_balance: impl IntoNumber, price: impl IntoNumber,
+        side: OrderSide,
+    ) -> Result<PositionSizing>
+    where
+        S: Into<String>,
+    {
+        let brackets = self.get_leverage_brackets(Some(symbol.into())).await?;
+        let bracket = match brackets.into_iter().next() {
+            Some(bracket) => bracket,
+            None => bail!("No leverage brackets returned"),
+        };
+        bracket.position_sizing(
+            leverage,
+            wallet_balance.try_into_number()?,
+            price.try_into_number()?,
+            side,
+        )
+    }
+
+    pub async fn change_initial_leverage<S>(
+        &self, symbol: S, leverage: u8,
+    ) -> Result<M::ChangeLeverageResponse>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("leverage".into(), leverage.to_string());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed(API::Futures(Futures::ChangeInitialLeverage), request)
+            .await
+    }
+
+    pub async fn change_margin_type<S>(&self, symbol: S, isolated: bool) -> Result<()>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        let margin_type = if isolated { "ISOLATED" } else { "CROSSED" };
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("marginType".into(), margin_type.into());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed::<Empty>(API::Futures(Futures::MarginType), request)
+            .await
+            .map(|_| ())
+    }
+
+    pub async fn change_position_margin<S>(
+        &self, symbol: S, amount: impl IntoNumber, is_adding_margin: bool,
+    ) -> Result<()>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        let margin = if is_adding_margin { "1" } else { "2" };
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("amount".into(), amount.try_into_number()?.to_string());
+        parameters.insert("type".into(), margin.into());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed::<Empty>(API::Futures(Futures::PositionMargin), request)
+            .await
+            .map(|_| ())
+    }
+
+    pub async fn change_position_mode(&self, dual_side_position: bool) -> Result<()> {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        let dual_side = if dual_side_position { "true" } else { "false" };
+        parameters.insert("dualSidePosition".into(), dual_side.into());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed::<Empty>(API::Futures(Futures::PositionSide), request)
+            .await
+            .map(|_| ())
+    }
+
+    pub async fn cancel_all_open_orders<S>(&self, symbol: S) -> Result<()>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .delete_signed::<Empty>(API::Futures(Futures::AllOpenOrders), Some(request))
+            .await
+            .map(|_| ())
+    }
+
+    pub async fn get_all_open_orders<S>(&self, symbol: S) -> Result<Vec<M::Order>>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Futures(Futures::OpenOrders), Some(request))
+            .await
+    }
+
+    pub async fn get_income(
+        &self, income_request: IncomeRequest,
+    ) -> Result<Vec<crate::futures::model::Income>> {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        if let Some(symbol) = income_request.symbol {
+            parameters.insert("symbol".into(), symbol);
+        }
+        if let Some(income_type) = income_request.income_type {
+            parameters.insert("incomeType".into(), income_type.to_string());
+        }
+        if let Some(start_time) = income_request.start_time {
+            parameters.insert("startTime".into(), start_time.to_string());
+        }
+        if let Some(end_time) = income_request.end_time {
+            parameters.insert("endTime".into(), end_time.to_string());
+        }
+        if let Some(limit) = income_request.limit {
+            parameters.insert("limit".into(), limit.to_string());
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Futures(Futures::Income), Some(request))
+            .await
+    }
+}
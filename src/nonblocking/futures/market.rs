@@ -0,0 +1,625 @@
+use crate::util::{build_request, build_signed_request};
+use crate::futures::account::ContractType;
+use crate::futures::model::{
+    AggTrades, BookTickers, FundingInfo, FundingRate, KlineSummaries, KlineSummary,
+    FuturesContracts, LiquidationOrders, LongShortRatio, MarkPrice, MarkPrices, OneOrMany,
+    OpenInterest, OpenInterestHist, OrderBook, SymbolPrice, TakerBuySellVolume, Tickers, Trades,
+    UsdM,
+};
+use crate::nonblocking::client::Client;
+use crate::errors::Result;
+use std::collections::BTreeMap;
+use std::marker::PhantomData;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use crate::api::API;
+use crate::api::Futures;
+
+// Most funding rates per request
+const FUNDING_RATE_PAGE: u32 = 1000;
+
+#[derive(Clone)]
+pub struct FuturesMarket<M = UsdM> {
+    pub client: Client,
+    pub recv_window: u64,
+    pub(crate) contracts: PhantomData<M>,
+}
+
+impl<M: FuturesContracts> FuturesMarket<M> {
+    // Order book (Default 100; max 1000)
+    pub async fn get_depth<S>(&self, symbol: S) -> Result<OrderBook>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+
+        parameters.insert("symbol".into(), symbol.into());
+        let request = build_request(parameters);
+
+        self.client
+            .get(API::Futures(Futures::Depth), Some(request))
+            .await
+    }
+
+    // Order book at a custom depth. Currently supported values
+    // are 5, 10, 20, 50, 100, 500, 1000
+    pub async fn get_custom_depth<S>(&self, symbol: S, depth: u64) -> Result<OrderBook>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("limit".into(), depth.to_string());
+        let request = build_request(parameters);
+        self.client
+            .get(API::Futures(Futures::Depth), Some(request))
+            .await
+    }
+
+    pub async fn get_trades<S>(&self, symbol: S) -> Result<Trades<M::Trade>>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        let request = build_request(parameters);
+        self.client
+            .get(API::Futures(Futures::Trades), Some(request))
+            .await
+    }
+
+    // TODO This may be incomplete, as it hasn't been tested
+    pub async fn get_historical_trades<S1, S2, S3>(
+        &self, symbol: S1, from_id: S2, limit: S3,
+    ) -> Result<Trades<M::Trade>>
+    where
+        S1: Into<String>,
+        S2: Into<Option<u64>>,
+        S3: Into<Option<u16>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+
+        parameters.insert("symbol".into(), symbol.into());
+
+        // Add three optional parameters
+        if let Some(lt) = limit.into() {
+            parameters.insert("limit".into(), format!("{}", lt));
+        }
+        if let Some(fi) = from_id.into() {
+            parameters.insert("fromId".into(), format!("{}", fi));
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+
+        self.client
+            .get_signed(API::Futures(Futures::HistoricalTrades), Some(request))
+            .await
+    }
+
+    pub async fn get_agg_trades<S1, S2, S3, S4, S5>(
+        &self, symbol: S1, from_id: S2, start_time: S3, end_time: S4, limit: S5,
+    ) -> Result<AggTrades>
+    where
+        S1: Into<String>,
+        S2: Into<Option<u64>>,
+        S3: Into<Option<u64>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u16>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+
+        parameters.insert("symbol".into(), symbol.into());
+
+        // Add three optional parameters
+        if let Some(lt) = limit.into() {
+            parameters.insert("limit".into(), format!("{}", lt));
+        }
+        if let Some(st) = start_time.into() {
+            parameters.insert("startTime".into(), format!("{}", st));
+        }
+        if let Some(et) = end_time.into() {
+            parameters.insert("endTime".into(), format!("{}", et));
+        }
+        if let Some(fi) = from_id.into() {
+            parameters.insert("fromId".into(), format!("{}", fi));
+        }
+
+        let request = build_request(parameters);
+
+        self.client
+            .get(API::Futures(Futures::AggTrades), Some(request))
+            .await
+    }
+
+    // Returns up to 'limit' klines for given symbol and interval ("1m", "5m", ...)
+    // https://github.com/binance-exchange/binance-official-api-docs/blob/master/rest-api.md#klinecandlestick-data
+    pub async fn get_klines<S1, S2, S3, S4, S5>(
+        &self, symbol: S1, interval: S2, limit: S3, start_time: S4, end_time: S5,
+    ) -> Result<KlineSummaries>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<Option<u16>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("interval".into(), interval.into());
+
+        self.get_kline_rows(Futures::Klines, parameters, limit, start_time, end_time)
+            .await
+    }
+
+    // Klines of a pair's perpetual or delivery contracts, spliced over their expiries
+    pub async fn get_continuous_klines<S1, S2, S3, S4, S5>(
+        &self, pair: S1, contract_type: ContractType, interval: S2, limit: S3, start_time: S4,
+        end_time: S5,
+    ) -> Result<KlineSummaries>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<Option<u16>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+
+        parameters.insert("pair".into(), pair.into());
+        parameters.insert("contractType".into(), contract_type.into());
+        parameters.insert("interval".into(), interval.into());
+
+        self.get_kline_rows(
+            Futures::ContinuousKlines,
+            parameters,
+            limit,
+            start_time,
+            end_time,
+        )
+        .await
+    }
+
+    // Index price klines of a pair; volumes and trade counts are zero
+    pub async fn get_index_price_klines<S1, S2, S3, S4, S5>(
+        &self, pair: S1, interval: S2, limit: S3, start_time: S4, end_time: S5,
+    ) -> Result<KlineSummaries>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<Option<u16>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+
+        parameters.insert("pair".into(), pair.into());
+        parameters.insert("interval".into(), interval.into());
+
+        self.get_kline_rows(
+            Futures::IndexPriceKlines,
+            parameters,
+            limit,
+            start_time,
+            end_time,
+        )
+        .await
+    }
+
+    // Mark price klines of a symbol; volumes and trade counts are zero
+    pub async fn get_mark_price_klines<S1, S2, S3, S4, S5>(
+        &self, symbol: S1, interval: S2, limit: S3, start_time: S4, end_time: S5,
+    ) -> Result<KlineSummaries>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<Option<u16>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("interval".into(), interval.into());
+
+        self.get_kline_rows(
+            Futures::MarkPriceKlines,
+            parameters,
+            limit,
+            start_time,
+            end_time,
+        )
+        .await
+    }
+
+    // Premium index klines of a symbol; volumes and trade counts are zero
+    pub async fn get_premium_index_klines<S1, S2, S3, S4, S5>(
+        &self, symbol: S1, interval: S2, limit: S3, start_time: S4, end_time: S5,
+    ) -> Result<KlineSummaries>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<Option<u16>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("interval".into(), interval.into());
+
+        self.get_kline_rows(
+            Futures::PremiumIndexKlines,
+            parameters,
+            limit,
+            start_time,
+            end_time,
+        )
+        .await
+    }
+
+    // Klines of a leveraged token's net asset value (USDⓈ-M only). `volume` holds the real
+    // leverage at the close and `number_of_trades` the number of NAV updates.
+    pub async fn get_lvt_klines<S1, S2, S3, S4, S5>(
+        &self, symbol: S1, interval: S2, limit: S3, start_time: S4, end_time: S5,
+    ) -> Result<KlineSummaries>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<Option<u16>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("interval".into(), interval.into());
+
+        self.get_kline_rows(Futures::LvtKlines, parameters, limit, start_time, end_time)
+            .await
+    }
+
+    // 24hr ticker price change statistics
+    pub async fn get_24h_price_stats<S>(&self, symbol: S) -> Result<M::PriceStats>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+
+        parameters.insert("symbol".into(), symbol.into());
+        let request = build_request(parameters);
+
+        self.client
+            .get::<OneOrMany<M::PriceStats>>(API::Futures(Futures::Ticker24hr), Some(request))
+            .await?
+            .into_one()
+    }
+
+    // 24hr ticker price change statistics for all symbols
+    pub async fn get_all_24h_price_stats(&self) -> Result<Vec<M::PriceStats>> {
+        self.client
+            .get(API::Futures(Futures::Ticker24hr), None)
+            .await
+    }
+
+    // Latest price for ONE symbol.
+    pub async fn get_price<S>(&self, symbol: S) -> Result<SymbolPrice>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+
+        parameters.insert("symbol".into(), symbol.into());
+        let request = build_request(parameters);
+
+        self.client
+            .get::<OneOrMany<SymbolPrice>>(API::Futures(Futures::TickerPrice), Some(request))
+            .await?
+            .into_one()
+    }
+
+    // Latest price for all symbols.
+    pub async fn get_all_prices(&self) -> Result<crate::model::Prices> {
+        self.client
+            .get(API::Futures(Futures::TickerPrice), None)
+            .await
+    }
+
+    // Symbols order book ticker
+    // -> Best price/qty on the order book for ALL symbols.
+    pub async fn get_all_book_tickers(&self) -> Result<BookTickers> {
+        self.client
+            .get(API::Futures(Futures::BookTicker), None)
+            .await
+    }
+
+    // -> Best price/qty on the order book for ONE symbol
+    pub async fn get_book_ticker<S>(&self, symbol: S) -> Result<Tickers>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        let request = build_request(parameters);
+        self.client
+            .get::<OneOrMany<Tickers>>(API::Futures(Futures::BookTicker), Some(request))
+            .await?
+            .into_one()
+    }
+
+    pub async fn get_mark_prices(&self) -> Result<MarkPrices> {
+        self.client
+            .get(API::Futures(Futures::PremiumIndex), None)
+            .await
+    }
+
+    /// Mark, index and estimated settlement price, and funding rate of one symbol
+    pub async fn get_premium_index<S>(&self, symbol: S) -> Result<MarkPrice>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        let request = build_request(parameters);
+        self.client
+            .get::<OneOrMany<MarkPrice>>(API::Futures(Futures::PremiumIndex), Some(request))
+            .await?
+            .into_one()
+    }
+
+    /// Funding rates of `symbol`, oldest first.
+    ///
+    /// With a `start_time`, pages of 1000 rates are requested until `end_time` or `limit` rates
+    /// are reached. Without it, only the latest `limit` rates are returned, at most 1000 however
+    /// large `limit` is. A `limit` of 0 returns no rates without sending a request.
+    pub async fn get_funding_rate_history<S>(
+        &self, symbol: S, start_time: Option<u64>, end_time: Option<u64>, limit: Option<u32>,
+    ) -> Result<Vec<FundingRate>>
+    where
+        S: Into<String>,
+    {
+        if limit == Some(0) {
+            return Ok(Vec::new());
+        }
+        let symbol = symbol.into();
+        let mut start_time = start_time;
+        let mut rates: Vec<FundingRate> = Vec::new();
+
+        loop {
+            // Clamped to one page with or without a `start_time`
+            let page_limit = limit.map_or(FUNDING_RATE_PAGE, |limit| {
+                (limit - rates.len() as u32).min(FUNDING_RATE_PAGE)
+            });
+            let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+            parameters.insert("symbol".into(), symbol.clone());
+            parameters.insert("limit".into(), page_limit.to_string());
+            if let Some(start_time) = start_time {
+                parameters.insert("startTime".into(), start_time.to_string());
+            }
+            if let Some(end_time) = end_time {
+                parameters.insert("endTime".into(), end_time.to_string());
+            }
+            let request = build_request(parameters);
+            let page: Vec<FundingRate> = self
+                .client
+                .get(API::Futures(Futures::FundingRate), Some(request))
+                .await?;
+
+            let full_page = page.len() as u32 == page_limit;
+            let last_funding_time = page.last().map(|rate| rate.funding_time);
+            rates.extend(page);
+
+            match (start_time, last_funding_time) {
+                (Some(_), Some(last_funding_time))
+                    if full_page && limit.map_or(true, |limit| (rates.len() as u32) < limit) =>
+                {
+                    start_time = Some(last_funding_time + 1);
+                }
+                _ => return Ok(rates),
+            }
+        }
+    }
+
+    /// Funding rate caps, floors and intervals of the symbols whose funding was adjusted
+    /// (USDⓈ-M only)
+    pub async fn get_funding_info(&self) -> Result<Vec<FundingInfo>> {
+        self.client
+            .get(API::Futures(Futures::FundingInfo), None)
+            .await
+    }
+
+    pub async fn get_all_liquidation_orders(&self) -> Result<LiquidationOrders> {
+        self.client
+            .get(API::Futures(Futures::AllForceOrders), None)
+            .await
+    }
+
+    pub async fn open_interest<S>(&self, symbol: S) -> Result<OpenInterest>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        let request = build_request(parameters);
+        self.client
+            .get(API::Futures(Futures::OpenInterest), Some(request))
+            .await
+    }
+
+    pub async fn open_interest_statistics<S1, S2, S3, S4, S5>(
+        &self, symbol: S1, period: S2, limit: S3, start_time: S4, end_time: S5,
+    ) -> Result<Vec<OpenInterestHist>>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<Option<u16>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        self.get_statistics(
+            Futures::OpenInterestHist,
+            symbol,
+            period,
+            limit,
+            start_time,
+            end_time,
+        )
+        .await
+    }
+
+    // Long/short ratio of the accounts of the top traders by margin balance
+    pub async fn top_long_short_account_ratio<S1, S2, S3, S4, S5>(
+        &self, symbol: S1, period: S2, limit: S3, start_time: S4, end_time: S5,
+    ) -> Result<Vec<LongShortRatio>>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<Option<u16>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        self.get_statistics(
+            Futures::TopLongShortAccountRatio,
+            symbol,
+            period,
+            limit,
+            start_time,
+            end_time,
+        )
+        .await
+    }
+
+    // Long/short ratio of the positions of the top traders by margin balance
+    pub async fn top_long_short_position_ratio<S1, S2, S3, S4, S5>(
+        &self, symbol: S1, period: S2, limit: S3, start_time: S4, end_time: S5,
+    ) -> Result<Vec<LongShortRatio>>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<Option<u16>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        self.get_statistics(
+            Futures::TopLongShortPositionRatio,
+            symbol,
+            period,
+            limit,
+            start_time,
+            end_time,
+        )
+        .await
+    }
+
+    // Long/short ratio of all accounts holding a position in the symbol
+    pub async fn global_long_short_account_ratio<S1, S2, S3, S4, S5>(
+        &self, symbol: S1, period: S2, limit: S3, start_time: S4, end_time: S5,
+    ) -> Result<Vec<LongShortRatio>>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<Option<u16>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        self.get_statistics(
+            Futures::GlobalLongShortAccountRatio,
+            symbol,
+            period,
+            limit,
+            start_time,
+            end_time,
+        )
+        .await
+    }
+
+    // Volume bought and sold by takers in each period
+    pub async fn taker_buy_sell_volume<S1, S2, S3, S4, S5>(
+        &self, symbol: S1, period: S2, limit: S3, start_time: S4, end_time: S5,
+    ) -> Result<Vec<TakerBuySellVolume>>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<Option<u16>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        self.get_statistics(
+            Futures::TakerlongshortRatio,
+            symbol,
+            period,
+            limit,
+            start_time,
+            end_time,
+        )
+        .await
+    }
+
+    // Statistics of the `/futures/data` endpoints, kept for the last 30 days in periods of
+    // "5m", "15m", "30m", "1h", "2h", "4h", "6h", "12h" or "1d"
+    async fn get_statistics<T, S1, S2, S3, S4, S5>(
+        &self, endpoint: Futures, symbol: S1, period: S2, limit: S3, start_time: S4, end_time: S5,
+    ) -> Result<Vec<T>>
+    where
+        T: DeserializeOwned,
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<Option<u16>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("period".into(), period.into());
+
+        if let Some(lt) = limit.into() {
+            parameters.insert("limit".into(), format!("{}", lt));
+        }
+        if let Some(st) = start_time.into() {
+            parameters.insert("startTime".into(), format!("{}", st));
+        }
+        if let Some(et) = end_time.into() {
+            parameters.insert("endTime".into(), format!("{}", et));
+        }
+
+        let request = build_request(parameters);
+        self.client.get(API::Futures(endpoint), Some(request)).await
+    }
+
+    // Klines of any kline endpoint, which all answer with the rows of `get_klines`
+    async fn get_kline_rows<S3, S4, S5>(
+        &self, endpoint: Futures, mut parameters: BTreeMap<String, String>, limit: S3,
+        start_time: S4, end_time: S5,
+    ) -> Result<KlineSummaries>
+    where
+        S3: Into<Option<u16>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        // Add three optional parameters
+        if let Some(lt) = limit.into() {
+            parameters.insert("limit".into(), format!("{}", lt));
+        }
+        if let Some(st) = start_time.into() {
+            parameters.insert("startTime".into(), format!("{}", st));
+        }
+        if let Some(et) = end_time.into() {
+            parameters.insert("endTime".into(), format!("{}", et));
+        }
+
+        let request = build_request(parameters);
+
+        let data: Vec<Vec<Value>> = self
+            .client
+            .get(API::Futures(endpoint), Some(request))
+            .await?;
+
+        let klines = KlineSummaries::AllKlineSummaries(
+            data.iter()
+                .map(|row| row.try_into())
+                .collect::<Result<Vec<KlineSummary>>>()?,
+        );
+
+        Ok(klines)
+    }
+}
@@ -0,0 +1,5 @@
+pub mod account;
+pub mod general;
+pub mod market;
+pub mod userstream;
+pub mod websockets;
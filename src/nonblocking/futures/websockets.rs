@@ -0,0 +1,70 @@
+use crate::errors::Result;
+use crate::config::Config;
+use crate::futures::websockets::{parse_msg, FuturesMarket, FuturesWebsocketAPI, FuturesWebsocketEvent};
+use crate::nonblocking::websockets::{connect_wss, poll_events, ShutdownHandle, Socket};
+
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use futures_util::Stream;
+
+/// Async futures websocket connection yielding decoded `FuturesWebsocketEvent`s as a `Stream`.
+pub struct FuturesWebSockets {
+    socket: Socket,
+    shutdown: ShutdownHandle,
+    closed: bool,
+}
+
+impl FuturesWebSockets {
+    pub async fn connect(market: &FuturesMarket, subscription: &str) -> Result<FuturesWebSockets> {
+        Self::connect_wss(&FuturesWebsocketAPI::Default.params(market, subscription)).await
+    }
+
+    pub async fn connect_with_config(
+        market: &FuturesMarket, subscription: &str, config: &Config,
+    ) -> Result<FuturesWebSockets> {
+        Self::connect_wss(
+            &FuturesWebsocketAPI::Custom(config.ws_endpoint.clone()).params(market, subscription),
+        )
+        .await
+    }
+
+    pub async fn connect_multiple_streams(
+        market: &FuturesMarket, endpoints: &[String],
+    ) -> Result<FuturesWebSockets> {
+        Self::connect_wss(&FuturesWebsocketAPI::MultiStream.params(market, &endpoints.join("/")))
+            .await
+    }
+
+    async fn connect_wss(wss: &str) -> Result<FuturesWebSockets> {
+        Ok(FuturesWebSockets {
+            socket: connect_wss(wss).await?,
+            shutdown: ShutdownHandle::default(),
+            closed: false,
+        })
+    }
+
+    /// Handle that ends this stream gracefully when triggered.
+    pub fn shutdown_handle(&self) -> ShutdownHandle {
+        self.shutdown.clone()
+    }
+
+    pub async fn disconnect(&mut self) -> Result<()> {
+        self.socket.close(None).await?;
+        Ok(())
+    }
+}
+
+impl Stream for FuturesWebSockets {
+    type Item = Result<FuturesWebsocketEvent>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        poll_events(
+            &mut this.socket,
+            &this.shutdown,
+            &mut this.closed,
+            cx,
+            parse_msg,
+        )
+    }
+}
@@ -0,0 +1,30 @@
+//! Async (tokio) counterparts of the REST API structs and websocket streams.
+//!
+//! Every REST struct here is built through the same `Binance` trait as its blocking
+//! twin and exposes the same methods as `async fn`s, so existing code can move
+//! over by swapping the import and adding `.await`. Websockets are exposed as
+//! `futures_util::Stream`s of the same events the callback API delivers.
+//!
+//! ```no_run
+//! use binance::api::Binance;
+//! use binance::nonblocking::market::Market;
+//!
+//! # async fn run() {
+//! let market: Market = Binance::new(None, None);
+//! match market.get_price("BTCUSDT").await {
+//!     Ok(answer) => println!("{:?}", answer),
+//!     Err(e) => println!("Error: {}", e),
+//! }
+//! # }
+//! ```
+
+pub(crate) mod client;
+
+pub mod account;
+pub mod general;
+pub mod market;
+pub mod savings;
+pub mod userstream;
+pub mod websockets;
+
+pub mod futures;
@@ -0,0 +1,210 @@
+use crate::util::{build_signed_request, insert_time_range};
+use crate::model::{
+    AccountSnapshot, AccountSnapshotType, AccountStatus, ApiTradingStatus, AssetDetail, CoinInfo,
+    DepositAddress, DepositRecord, DepositStatus, IntoNumber, SpotFuturesTransferType,
+    RecordsQueryResult, SystemStatus, TransactionId, UniversalTransfer, UniversalTransferType,
+    WithdrawId, WithdrawRecord, WithdrawStatus,
+};
+use crate::savings::{build_withdraw, WithdrawRequest};
+use crate::nonblocking::client::Client;
+use crate::errors::Result;
+use std::collections::BTreeMap;
+use crate::api::API;
+use crate::api::Sapi;
+
+#[derive(Clone)]
+pub struct Savings {
+    pub client: Client,
+    pub recv_window: u64,
+}
+
+impl Savings {
+    /// Get all coins available for deposit and withdrawal
+    pub async fn get_all_coins(&self) -> Result<Vec<CoinInfo>> {
+        let request = build_signed_request(BTreeMap::new(), self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::AllCoins), Some(request))
+            .await
+    }
+
+    /// Fetch details of assets supported on Binance.
+    pub async fn asset_detail(
+        &self, asset: Option<String>,
+    ) -> Result<BTreeMap<String, AssetDetail>> {
+        let mut parameters = BTreeMap::new();
+        if let Some(asset) = asset {
+            parameters.insert("asset".into(), asset);
+        }
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::AssetDetail), Some(request))
+            .await
+    }
+
+    /// Fetch deposit address with network.
+    ///
+    /// You can get the available networks using `get_all_coins`.
+    /// If no network is specified, the address for the default network is returned.
+    pub async fn deposit_address<S>(
+        &self, coin: S, network: Option<String>,
+    ) -> Result<DepositAddress>
+    where
+        S: Into<String>,
+    {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("coin".into(), coin.into());
+        if let Some(network) = network {
+            parameters.insert("network".into(), network);
+        }
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::DepositAddress), Some(request))
+            .await
+    }
+
+    pub async fn transfer_funds<S>(
+        &self, asset: S, amount: impl IntoNumber, transfer_type: SpotFuturesTransferType,
+    ) -> Result<TransactionId>
+    where
+        S: Into<String>,
+    {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("asset".into(), asset.into());
+        parameters.insert("amount".into(), amount.try_into_number()?.to_string());
+        parameters.insert("type".into(), (transfer_type as u8).to_string());
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed(API::Savings(Sapi::SpotFuturesTransfer), request)
+            .await
+    }
+
+    /// Transfer between any two wallets; isolated margin wallets are named by their symbol
+    pub async fn universal_transfer<S>(
+        &self, transfer_type: UniversalTransferType, asset: S, amount: impl IntoNumber,
+        from_symbol: Option<String>, to_symbol: Option<String>,
+    ) -> Result<TransactionId>
+    where
+        S: Into<String>,
+    {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("type".into(), transfer_type.to_string());
+        parameters.insert("asset".into(), asset.into());
+        parameters.insert("amount".into(), amount.try_into_number()?.to_string());
+        if let Some(from_symbol) = from_symbol {
+            parameters.insert("fromSymbol".into(), from_symbol);
+        }
+        if let Some(to_symbol) = to_symbol {
+            parameters.insert("toSymbol".into(), to_symbol);
+        }
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed(API::Savings(Sapi::UniversalTransfer), request)
+            .await
+    }
+
+    /// Universal transfers of one type, `size` (10 by default) per `current` page
+    pub async fn universal_transfer_history(
+        &self, transfer_type: UniversalTransferType, start_time: Option<u64>,
+        end_time: Option<u64>, current: Option<u32>, size: Option<u16>,
+    ) -> Result<RecordsQueryResult<UniversalTransfer>> {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("type".into(), transfer_type.to_string());
+        insert_time_range(&mut parameters, start_time, end_time);
+        if let Some(current) = current {
+            parameters.insert("current".into(), current.to_string());
+        }
+        if let Some(size) = size {
+            parameters.insert("size".into(), size.to_string());
+        }
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::UniversalTransfer), Some(request))
+            .await
+    }
+
+    /// Submit a withdrawal
+    pub async fn withdraw(&self, withdraw: WithdrawRequest) -> Result<WithdrawId> {
+        let parameters = build_withdraw(withdraw);
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed(API::Savings(Sapi::Withdraw), request)
+            .await
+    }
+
+    /// Deposit history, the last 90 days when no time range is given
+    pub async fn deposit_history(
+        &self, coin: Option<String>, status: Option<DepositStatus>, start_time: Option<u64>,
+        end_time: Option<u64>,
+    ) -> Result<Vec<DepositRecord>> {
+        let mut parameters = BTreeMap::new();
+        if let Some(coin) = coin {
+            parameters.insert("coin".into(), coin);
+        }
+        if let Some(status) = status {
+            parameters.insert("status".into(), u8::from(status).to_string());
+        }
+        insert_time_range(&mut parameters, start_time, end_time);
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::DepositHistory), Some(request))
+            .await
+    }
+
+    /// Withdraw history, the last 90 days when no time range is given
+    pub async fn withdraw_history(
+        &self, coin: Option<String>, status: Option<WithdrawStatus>, start_time: Option<u64>,
+        end_time: Option<u64>,
+    ) -> Result<Vec<WithdrawRecord>> {
+        let mut parameters = BTreeMap::new();
+        if let Some(coin) = coin {
+            parameters.insert("coin".into(), coin);
+        }
+        if let Some(status) = status {
+            parameters.insert("status".into(), u8::from(status).to_string());
+        }
+        insert_time_range(&mut parameters, start_time, end_time);
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::WithdrawHistory), Some(request))
+            .await
+    }
+
+    /// System maintenance status, not signed
+    pub async fn system_status(&self) -> Result<SystemStatus> {
+        self.client
+            .get(API::Savings(Sapi::SystemStatus), None)
+            .await
+    }
+
+    pub async fn account_status(&self) -> Result<AccountStatus> {
+        let request = build_signed_request(BTreeMap::new(), self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::AccountStatus), Some(request))
+            .await
+    }
+
+    /// Whether API trading is locked, and the order ratios that trigger the lock
+    pub async fn api_trading_status(&self) -> Result<ApiTradingStatus> {
+        let request = build_signed_request(BTreeMap::new(), self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::ApiTradingStatus), Some(request))
+            .await
+    }
+
+    /// Daily snapshots of the spot, margin or futures account, at most 30 days apart
+    pub async fn account_snapshot(
+        &self, snapshot_type: AccountSnapshotType, start_time: Option<u64>, end_time: Option<u64>,
+        limit: Option<u16>,
+    ) -> Result<AccountSnapshot> {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("type".into(), snapshot_type.to_string());
+        insert_time_range(&mut parameters, start_time, end_time);
+        if let Some(limit) = limit {
+            parameters.insert("limit".into(), limit.to_string());
+        }
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::AccountSnapshot), Some(request))
+            .await
+    }
+}
@@ -0,0 +1,183 @@
+use crate::errors::Result;
+use crate::config::Config;
+use crate::websockets::{parse_msg, WebsocketAPI, WebsocketEvent};
+use error_chain::bail;
+use url::Url;
+
+use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::task::{Context, Poll};
+use futures_util::{Sink, Stream};
+use futures_util::task::AtomicWaker;
+use tokio::net::TcpStream;
+use tokio_tungstenite::{connect_async, MaybeTlsStream, WebSocketStream};
+use tokio_tungstenite::tungstenite::Message;
+
+pub(crate) type Socket = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+/// Stops a websocket stream from another task.
+///
+/// Once `shutdown` is called the stream sends a close frame and then yields `None`.
+/// Dropping the stream itself also closes the underlying connection.
+#[derive(Clone, Default)]
+pub struct ShutdownHandle {
+    inner: Arc<Shutdown>,
+}
+
+#[derive(Default)]
+struct Shutdown {
+    requested: AtomicBool,
+    waker: AtomicWaker,
+}
+
+impl ShutdownHandle {
+    pub fn shutdown(&self) {
+        self.inner.requested.store(true, Ordering::Relaxed);
+        self.inner.waker.wake();
+    }
+
+    pub fn is_shutdown(&self) -> bool {
+        self.inner.requested.load(Ordering::Relaxed)
+    }
+
+    fn poll_requested(&self, cx: &mut Context<'_>) -> bool {
+        self.inner.waker.register(cx.waker());
+        self.is_shutdown()
+    }
+}
+
+/// Async websocket connection yielding decoded `WebsocketEvent`s as a `Stream`.
+///
+/// ```no_run
+/// use binance::nonblocking::websockets::WebSockets;
+/// use binance::websockets::WebsocketEvent;
+/// use futures_util::StreamExt;
+///
+/// # async fn run() -> binance::errors::Result<()> {
+/// let mut web_socket = WebSockets::connect("btcusdt@aggTrade").await?;
+/// while let Some(event) = web_socket.next().await {
+///     if let WebsocketEvent::AggrTrades(trade) = event? {
+///         println!("{}: {} @ {}", trade.symbol, trade.qty, trade.price);
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct WebSockets {
+    socket: Socket,
+    shutdown: ShutdownHandle,
+    closed: bool,
+}
+
+impl WebSockets {
+    pub async fn connect(subscription: &str) -> Result<WebSockets> {
+        Self::connect_wss(&WebsocketAPI::Default.params(subscription)).await
+    }
+
+    pub async fn connect_with_config(subscription: &str, config: &Config) -> Result<WebSockets> {
+        Self::connect_wss(&WebsocketAPI::Custom(config.ws_endpoint.clone()).params(subscription))
+            .await
+    }
+
+    pub async fn connect_multiple_streams(endpoints: &[String]) -> Result<WebSockets> {
+        Self::connect_wss(&WebsocketAPI::MultiStream.params(&endpoints.join("/"))).await
+    }
+
+    async fn connect_wss(wss: &str) -> Result<WebSockets> {
+        Ok(WebSockets {
+            socket: connect_wss(wss).await?,
+            shutdown: ShutdownHandle::default(),
+            closed: false,
+        })
+    }
+
+    /// Handle that ends this stream gracefully when triggered.
+    pub fn shutdown_handle(&self) -> ShutdownHandle {
+        self.shutdown.clone()
+    }
+
+    pub async fn disconnect(&mut self) -> Result<()> {
+        self.socket.close(None).await?;
+        Ok(())
+    }
+}
+
+impl Stream for WebSockets {
+    type Item = Result<WebsocketEvent>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        poll_events(
+            &mut this.socket,
+            &this.shutdown,
+            &mut this.closed,
+            cx,
+            parse_msg,
+        )
+    }
+}
+
+pub(crate) async fn connect_wss(wss: &str) -> Result<Socket> {
+    let url = Url::parse(wss)?;
+    match connect_async(url).await {
+        Ok((socket, _)) => Ok(socket),
+        Err(e) => bail!(format!("Error during handshake {}", e)),
+    }
+}
+
+/// Drives the socket until a decodable event, a close frame or a shutdown request.
+///
+/// Pings are answered by tungstenite itself on the next read.
+pub(crate) fn poll_events<E>(
+    socket: &mut Socket, shutdown: &ShutdownHandle, closed: &mut bool, cx: &mut Context<'_>,
+    parse: fn(&str) -> Result<Option<E>>,
+) -> Poll<Option<Result<E>>> {
+    if *closed {
+        return Poll::Ready(None);
+    }
+
+    if shutdown.poll_requested(cx) {
+        return match Pin::new(socket).poll_close(cx) {
+            Poll::Pending => Poll::Pending,
+            Poll::Ready(_) => {
+                *closed = true;
+                Poll::Ready(None)
+            }
+        };
+    }
+
+    loop {
+        match Pin::new(&mut *socket).poll_next(cx) {
+            Poll::Pending => return Poll::Pending,
+            Poll::Ready(None) => {
+                *closed = true;
+                return Poll::Ready(None);
+            }
+            Poll::Ready(Some(Err(e))) => {
+                *closed = true;
+                return Poll::Ready(Some(Err(e.into())));
+            }
+            Poll::Ready(Some(Ok(message))) => match message {
+                Message::Text(msg) => match parse(&msg) {
+                    Ok(Some(event)) => return Poll::Ready(Some(Ok(event))),
+                    Ok(None) => continue,
+                    Err(e) => {
+                        return Poll::Ready(Some(Err(format!(
+                            "Error on handling stream message: {}",
+                            e
+                        )
+                        .into())))
+                    }
+                },
+                Message::Close(_) => {
+                    *closed = true;
+                    return Poll::Ready(None);
+                }
+                Message::Ping(_) | Message::Pong(_) | Message::Binary(_) | Message::Frame(_) => {
+                    continue
+                }
+            },
+        }
+    }
+}
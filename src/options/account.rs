@@ -0,0 +1,180 @@
+use std::collections::BTreeMap;
+use crate::util::build_signed_request;
+use crate::errors::Result;
+use crate::client::Client;
+use crate::api::{API, Options};
+use crate::model::{Empty, IntoNumber, Number};
+use crate::account::{OrderSide, TimeInForce};
+use crate::options::model::{AccountInformation, Order, Position};
+
+#[derive(Clone)]
+pub struct OptionsAccount {
+    pub client: Client,
+    pub recv_window: u64,
+}
+
+/// Options are traded with limit orders only.
+pub struct OrderRequest {
+    pub symbol: String,
+    pub side: OrderSide,
+    pub qty: Number,
+    pub price: Number,
+    pub time_in_force: Option<TimeInForce>,
+    pub reduce_only: Option<bool>,
+    pub post_only: Option<bool>,
+    pub client_order_id: Option<String>,
+}
+
+impl OptionsAccount {
+    pub fn limit_buy(
+        &self, symbol: impl Into<String>, qty: impl IntoNumber, price: impl IntoNumber,
+        time_in_force: TimeInForce,
+    ) -> Result<Order> {
+        self.custom_order(OrderRequest {
+            symbol: symbol.into(),
+            side: OrderSide::Buy,
+            qty: qty.try_into_number()?,
+            price: price.try_into_number()?,
+            time_in_force: Some(time_in_force),
+            reduce_only: None,
+            post_only: None,
+            client_order_id: None,
+        })
+    }
+
+    pub fn limit_sell(
+        &self, symbol: impl Into<String>, qty: impl IntoNumber, price: impl IntoNumber,
+        time_in_force: TimeInForce,
+    ) -> Result<Order> {
+        self.custom_order(OrderRequest {
+            symbol: symbol.into(),
+            side: OrderSide::Sell,
+            qty: qty.try_into_number()?,
+            price: price.try_into_number()?,
+            time_in_force: Some(time_in_force),
+            reduce_only: None,
+            post_only: None,
+            client_order_id: None,
+        })
+    }
+
+    pub fn custom_order(&self, order_request: OrderRequest) -> Result<Order> {
+        let order = build_order(order_request);
+        let request = build_signed_request(order, self.recv_window)?;
+        self.client
+            .post_signed_order(API::Options(Options::Order), request)
+    }
+
+    // Check an order's status
+    pub fn order_status<S>(&self, symbol: S, order_id: u64) -> Result<Order>
+    where
+        S: Into<String>,
+    {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("orderId".into(), order_id.to_string());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Options(Options::Order), Some(request))
+    }
+
+    pub fn cancel_order<S>(&self, symbol: S, order_id: u64) -> Result<Order>
+    where
+        S: Into<String>,
+    {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("orderId".into(), order_id.to_string());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .delete_signed(API::Options(Options::Order), Some(request))
+    }
+
+    pub fn cancel_order_with_client_id<S>(
+        &self, symbol: S, client_order_id: String,
+    ) -> Result<Order>
+    where
+        S: Into<String>,
+    {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("clientOrderId".into(), client_order_id);
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .delete_signed(API::Options(Options::Order), Some(request))
+    }
+
+    pub fn cancel_all_open_orders<S>(&self, symbol: S) -> Result<()>
+    where
+        S: Into<String>,
+    {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .delete_signed::<Empty>(API::Options(Options::AllOpenOrders), Some(request))?;
+        Ok(())
+    }
+
+    // Open orders of ONE symbol, or of all symbols
+    pub fn get_open_orders(&self, symbol: Option<String>) -> Result<Vec<Order>> {
+        let mut parameters = BTreeMap::new();
+        if let Some(symbol) = symbol {
+            parameters.insert("symbol".into(), symbol);
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Options(Options::OpenOrders), Some(request))
+    }
+
+    // Positions of ONE symbol, or of all symbols
+    pub fn position_information(&self, symbol: Option<String>) -> Result<Vec<Position>> {
+        let mut parameters = BTreeMap::new();
+        if let Some(symbol) = symbol {
+            parameters.insert("symbol".into(), symbol);
+        }
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Options(Options::Position), Some(request))
+    }
+
+    // Margin account balances and greeks per underlying
+    pub fn account_information(&self) -> Result<AccountInformation> {
+        let parameters = BTreeMap::new();
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Options(Options::MarginAccount), Some(request))
+    }
+}
+
+fn build_order(order: OrderRequest) -> BTreeMap<String, String> {
+    let mut parameters = BTreeMap::new();
+    parameters.insert("symbol".into(), order.symbol);
+    parameters.insert("side".into(), order.side.to_string());
+    parameters.insert("type".into(), "LIMIT".into());
+    parameters.insert("quantity".into(), order.qty.to_string());
+    parameters.insert("price".into(), order.price.to_string());
+    // The full order instead of the acknowledgement
+    parameters.insert("newOrderRespType".into(), "RESULT".into());
+
+    if let Some(time_in_force) = order.time_in_force {
+        parameters.insert("timeInForce".into(), time_in_force.to_string());
+    }
+    if let Some(reduce_only) = order.reduce_only {
+        parameters.insert("reduceOnly".into(), reduce_only.to_string());
+    }
+    if let Some(post_only) = order.post_only {
+        parameters.insert("postOnly".into(), post_only.to_string());
+    }
+    if let Some(client_order_id) = order.client_order_id {
+        parameters.insert("clientOrderId".into(), client_order_id);
+    }
+
+    parameters
+}
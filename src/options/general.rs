@@ -0,0 +1,44 @@
+use crate::model::Empty;
+use crate::options::model::{ExchangeInformation, OptionSymbol, ServerTime};
+use crate::client::Client;
+use crate::errors::Result;
+use crate::api::API;
+use crate::api::Options;
+
+#[derive(Clone)]
+pub struct OptionsGeneral {
+    pub client: Client,
+}
+
+impl OptionsGeneral {
+    // Test connectivity
+    pub fn ping(&self) -> Result<String> {
+        self.client
+            .get::<Empty>(API::Options(Options::Ping), None)?;
+        Ok("pong".into())
+    }
+
+    // Check server time
+    pub fn get_server_time(&self) -> Result<ServerTime> {
+        self.client.get(API::Options(Options::Time), None)
+    }
+
+    // Obtain exchange information
+    // - Option contracts, assets and symbols with their strike and expiry
+    pub fn exchange_info(&self) -> Result<ExchangeInformation> {
+        self.client.get(API::Options(Options::ExchangeInfo), None)
+    }
+
+    // Get Symbol information, e.g. BTC-240628-70000-C
+    pub fn get_symbol_info<S>(&self, symbol: S) -> Result<OptionSymbol>
+    where
+        S: Into<String>,
+    {
+        let upper_symbol = symbol.into().to_uppercase();
+        self.exchange_info()?
+            .option_symbols
+            .into_iter()
+            .find(|item| item.symbol == upper_symbol)
+            .ok_or_else(|| "Symbol not found".into())
+    }
+}
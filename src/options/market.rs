@@ -0,0 +1,84 @@
+use crate::util::build_request;
+use crate::options::model::{KlineSummary, MarkPrice, OrderBook};
+use crate::client::Client;
+use crate::errors::Result;
+use std::collections::BTreeMap;
+use crate::api::API;
+use crate::api::Options;
+
+#[derive(Clone)]
+pub struct OptionsMarket {
+    pub client: Client,
+    pub recv_window: u64,
+}
+
+impl OptionsMarket {
+    // Order book (Default 100). Supported limits are 10, 20, 50, 100, 500, 1000
+    pub fn get_depth<S1, S2>(&self, symbol: S1, limit: S2) -> Result<OrderBook>
+    where
+        S1: Into<String>,
+        S2: Into<Option<u16>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        if let Some(lt) = limit.into() {
+            parameters.insert("limit".into(), format!("{}", lt));
+        }
+        let request = build_request(parameters);
+        self.client.get(API::Options(Options::Depth), Some(request))
+    }
+
+    // Returns up to 'limit' klines for given symbol and interval ("1m", "5m", ...)
+    pub fn get_klines<S1, S2, S3, S4, S5>(
+        &self, symbol: S1, interval: S2, limit: S3, start_time: S4, end_time: S5,
+    ) -> Result<Vec<KlineSummary>>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<Option<u16>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("interval".into(), interval.into());
+
+        // Add three optional parameters
+        if let Some(lt) = limit.into() {
+            parameters.insert("limit".into(), format!("{}", lt));
+        }
+        if let Some(st) = start_time.into() {
+            parameters.insert("startTime".into(), format!("{}", st));
+        }
+        if let Some(et) = end_time.into() {
+            parameters.insert("endTime".into(), format!("{}", et));
+        }
+
+        let request = build_request(parameters);
+        self.client
+            .get(API::Options(Options::Klines), Some(request))
+    }
+
+    // Mark price, implied volatility and greeks of ONE symbol
+    pub fn get_mark_price<S>(&self, symbol: S) -> Result<MarkPrice>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        let request = build_request(parameters);
+        let mark_prices: Vec<MarkPrice> = self
+            .client
+            .get(API::Options(Options::Mark), Some(request))?;
+        mark_prices
+            .into_iter()
+            .next()
+            .ok_or_else(|| "Symbol not found".into())
+    }
+
+    // Mark price, implied volatility and greeks of all symbols
+    pub fn get_all_mark_prices(&self) -> Result<Vec<MarkPrice>> {
+        self.client.get(API::Options(Options::Mark), None)
+    }
+}
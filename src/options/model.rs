@@ -0,0 +1,369 @@
+use serde::{Deserialize, Serialize};
+use crate::model::{string_or_float, string_or_float_opt};
+
+pub use crate::model::{Asks, Bids, Filters, Number, RateLimit, ServerTime};
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum OptionSide {
+    Call,
+    Put,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ExchangeInformation {
+    pub timezone: String,
+    pub server_time: u64,
+    pub option_contracts: Vec<OptionContract>,
+    pub option_assets: Vec<OptionAsset>,
+    pub option_symbols: Vec<OptionSymbol>,
+    pub rate_limits: Vec<RateLimit>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionContract {
+    pub id: u64,
+    pub base_asset: String,
+    pub quote_asset: String,
+    pub underlying: String,
+    pub settle_asset: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionAsset {
+    pub id: u64,
+    pub name: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionSymbol {
+    pub contract_id: u64,
+    pub id: u64,
+    pub symbol: String,
+    pub side: OptionSide,
+    #[serde(with = "string_or_float")]
+    pub strike_price: Number,
+    pub underlying: String,
+    pub expiry_date: u64,
+    // Contract multiplier
+    pub unit: u64,
+    pub quote_asset: String,
+    #[serde(with = "string_or_float")]
+    pub min_qty: Number,
+    #[serde(with = "string_or_float")]
+    pub max_qty: Number,
+    #[serde(with = "string_or_float")]
+    pub maker_fee_rate: Number,
+    #[serde(with = "string_or_float")]
+    pub taker_fee_rate: Number,
+    pub price_scale: u16,
+    pub quantity_scale: u16,
+    pub filters: Vec<Filters>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderBook {
+    #[serde(rename = "T")]
+    pub transaction_time: u64,
+    #[serde(rename = "u")]
+    pub update_id: u64,
+    pub bids: Vec<Bids>,
+    pub asks: Vec<Asks>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct KlineSummary {
+    pub open_time: u64,
+    pub close_time: u64,
+    pub interval: String,
+    #[serde(with = "string_or_float")]
+    pub open: Number,
+    #[serde(with = "string_or_float")]
+    pub high: Number,
+    #[serde(with = "string_or_float")]
+    pub low: Number,
+    #[serde(with = "string_or_float")]
+    pub close: Number,
+    #[serde(with = "string_or_float")]
+    pub volume: Number,
+    #[serde(with = "string_or_float")]
+    pub amount: Number,
+    #[serde(with = "string_or_float")]
+    pub taker_volume: Number,
+    #[serde(with = "string_or_float")]
+    pub taker_amount: Number,
+    pub trade_count: u64,
+}
+
+/// Mark price, implied volatility and greeks of an option.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MarkPrice {
+    pub symbol: String,
+    #[serde(with = "string_or_float")]
+    pub mark_price: Number,
+    #[serde(rename = "bidIV", with = "string_or_float")]
+    pub bid_iv: Number,
+    #[serde(rename = "askIV", with = "string_or_float")]
+    pub ask_iv: Number,
+    #[serde(rename = "markIV", with = "string_or_float")]
+    pub mark_iv: Number,
+    #[serde(with = "string_or_float")]
+    pub delta: Number,
+    #[serde(with = "string_or_float")]
+    pub theta: Number,
+    #[serde(with = "string_or_float")]
+    pub gamma: Number,
+    #[serde(with = "string_or_float")]
+    pub vega: Number,
+    #[serde(with = "string_or_float")]
+    pub high_price_limit: Number,
+    #[serde(with = "string_or_float")]
+    pub low_price_limit: Number,
+    #[serde(default, with = "string_or_float_opt")]
+    pub risk_free_interest: Option<Number>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Order {
+    pub order_id: u64,
+    pub symbol: String,
+    #[serde(with = "string_or_float")]
+    pub price: Number,
+    #[serde(with = "string_or_float")]
+    pub quantity: Number,
+    #[serde(with = "string_or_float")]
+    pub executed_qty: Number,
+    #[serde(with = "string_or_float")]
+    pub fee: Number,
+    pub side: String,
+    #[serde(rename = "type")]
+    pub order_type: String,
+    pub time_in_force: String,
+    pub reduce_only: bool,
+    pub post_only: bool,
+    pub create_time: u64,
+    pub update_time: u64,
+    pub status: String,
+    #[serde(with = "string_or_float")]
+    pub avg_price: Number,
+    pub client_order_id: String,
+    pub price_scale: u16,
+    pub quantity_scale: u16,
+    pub option_side: OptionSide,
+    pub quote_asset: String,
+    #[serde(default)]
+    pub mmp: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Position {
+    pub symbol: String,
+    // LONG or SHORT
+    pub side: String,
+    pub option_side: OptionSide,
+    #[serde(with = "string_or_float")]
+    pub strike_price: Number,
+    pub expiry_date: u64,
+    #[serde(with = "string_or_float")]
+    pub quantity: Number,
+    #[serde(with = "string_or_float")]
+    pub reducible_qty: Number,
+    #[serde(with = "string_or_float")]
+    pub entry_price: Number,
+    #[serde(with = "string_or_float")]
+    pub mark_price: Number,
+    #[serde(with = "string_or_float")]
+    pub mark_value: Number,
+    #[serde(with = "string_or_float")]
+    pub position_cost: Number,
+    #[serde(rename = "unrealizedPNL", with = "string_or_float")]
+    pub unrealized_pnl: Number,
+    #[serde(with = "string_or_float")]
+    pub ror: Number,
+    pub price_scale: u16,
+    pub quantity_scale: u16,
+    pub quote_asset: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountInformation {
+    #[serde(rename = "asset")]
+    pub assets: Vec<AccountAsset>,
+    #[serde(rename = "greek")]
+    pub greeks: Vec<Greeks>,
+    pub time: u64,
+    pub can_trade: bool,
+    pub can_deposit: bool,
+    pub can_withdraw: bool,
+    pub reduce_only: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountAsset {
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub margin_balance: Number,
+    #[serde(with = "string_or_float")]
+    pub equity: Number,
+    #[serde(with = "string_or_float")]
+    pub available: Number,
+    #[serde(with = "string_or_float")]
+    pub initial_margin: Number,
+    #[serde(with = "string_or_float")]
+    pub maint_margin: Number,
+    #[serde(rename = "unrealizedPNL", with = "string_or_float")]
+    pub unrealized_pnl: Number,
+}
+
+/// Greeks of the positions on an underlying.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Greeks {
+    pub underlying: String,
+    #[serde(with = "string_or_float")]
+    pub delta: Number,
+    #[serde(with = "string_or_float")]
+    pub gamma: Number,
+    #[serde(with = "string_or_float")]
+    pub theta: Number,
+    #[serde(with = "string_or_float")]
+    pub vega: Number,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AccountUpdateEvent {
+    #[serde(rename = "e")]
+    pub event_type: String,
+
+    #[serde(rename = "E")]
+    pub event_time: u64,
+
+    #[serde(rename = "B")]
+    pub balances: Vec<BalanceUpdate>,
+
+    #[serde(rename = "G", default)]
+    pub greeks: Vec<GreeksUpdate>,
+
+    #[serde(rename = "P", default)]
+    pub positions: Vec<PositionUpdate>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct BalanceUpdate {
+    #[serde(rename = "a")]
+    pub asset: String,
+
+    #[serde(rename = "b", with = "string_or_float")]
+    pub balance: Number,
+
+    #[serde(rename = "m", with = "string_or_float")]
+    pub margin_balance: Number,
+
+    #[serde(rename = "u", with = "string_or_float")]
+    pub unrealized_pnl: Number,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct GreeksUpdate {
+    #[serde(rename = "ui")]
+    pub underlying: String,
+
+    #[serde(rename = "d", with = "string_or_float")]
+    pub delta: Number,
+
+    #[serde(rename = "t", with = "string_or_float")]
+    pub theta: Number,
+
+    #[serde(rename = "g", with = "string_or_float")]
+    pub gamma: Number,
+
+    #[serde(rename = "v", with = "string_or_float")]
+    pub vega: Number,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PositionUpdate {
+    #[serde(rename = "s")]
+    pub symbol: String,
+
+    #[serde(rename = "c", with = "string_or_float")]
+    pub quantity: Number,
+
+    #[serde(rename = "r", with = "string_or_float")]
+    pub reducible_qty: Number,
+
+    #[serde(rename = "p", with = "string_or_float")]
+    pub entry_price: Number,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct OrderTradeEvent {
+    #[serde(rename = "e")]
+    pub event_type: String,
+
+    #[serde(rename = "E")]
+    pub event_time: u64,
+
+    #[serde(rename = "o")]
+    pub orders: Vec<OrderUpdate>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct OrderUpdate {
+    #[serde(rename = "T")]
+    pub create_time: u64,
+
+    #[serde(rename = "t")]
+    pub update_time: u64,
+
+    #[serde(rename = "s")]
+    pub symbol: String,
+
+    #[serde(rename = "c")]
+    pub client_order_id: String,
+
+    #[serde(rename = "oid")]
+    pub order_id: String,
+
+    #[serde(rename = "p", with = "string_or_float")]
+    pub price: Number,
+
+    // Negative for sell orders
+    #[serde(rename = "q", with = "string_or_float")]
+    pub quantity: Number,
+
+    #[serde(rename = "r")]
+    pub reduce_only: bool,
+
+    #[serde(rename = "po")]
+    pub post_only: bool,
+
+    #[serde(rename = "S")]
+    pub status: String,
+
+    #[serde(rename = "e", with = "string_or_float")]
+    pub executed_qty: Number,
+
+    #[serde(rename = "ec", with = "string_or_float")]
+    pub executed_cost: Number,
+
+    #[serde(rename = "f", with = "string_or_float")]
+    pub fee: Number,
+
+    #[serde(rename = "tif")]
+    pub time_in_force: String,
+
+    #[serde(rename = "oty")]
+    pub order_type: String,
+}
@@ -0,0 +1,64 @@
+use crate::model::{Success, UserDataStream};
+use crate::client::Client;
+use crate::config::Config;
+use crate::errors::Result;
+use crate::options::websockets::{parse_msg, OptionsWebsocketEvent};
+use crate::userstream::UserDataStreamApi;
+use crate::websockets::ReconnectedEvent;
+use crate::api::API;
+use crate::api::Options;
+
+#[derive(Clone)]
+pub struct OptionsUserStream {
+    pub client: Client,
+    pub recv_window: u64,
+}
+
+impl OptionsUserStream {
+    // User Stream
+    pub fn start(&self) -> Result<UserDataStream> {
+        self.client.post(API::Options(Options::UserDataStream))
+    }
+
+    pub fn keep_alive(&self, listen_key: &str) -> Result<Success> {
+        self.client
+            .put(API::Options(Options::UserDataStream), listen_key)
+    }
+
+    pub fn close(&self, listen_key: &str) -> Result<Success> {
+        self.client
+            .delete(API::Options(Options::UserDataStream), listen_key)
+    }
+}
+
+impl UserDataStreamApi for OptionsUserStream {
+    type Event = OptionsWebsocketEvent;
+
+    fn start(&self) -> Result<UserDataStream> {
+        OptionsUserStream::start(self)
+    }
+
+    fn keep_alive(&self, listen_key: &str) -> Result<Success> {
+        OptionsUserStream::keep_alive(self, listen_key)
+    }
+
+    fn close(&self, listen_key: &str) -> Result<Success> {
+        OptionsUserStream::close(self, listen_key)
+    }
+
+    fn ws_endpoint(&self, config: &Config) -> String {
+        config.options_ws_endpoint.clone()
+    }
+
+    fn parse_event(msg: &str) -> Result<Option<OptionsWebsocketEvent>> {
+        parse_msg(msg)
+    }
+
+    fn is_expired(event: &OptionsWebsocketEvent) -> bool {
+        matches!(event, OptionsWebsocketEvent::UserDataStreamExpiredEvent(_))
+    }
+
+    fn reconnected(event: ReconnectedEvent) -> OptionsWebsocketEvent {
+        OptionsWebsocketEvent::Reconnected(event)
+    }
+}
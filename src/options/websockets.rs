@@ -0,0 +1,36 @@
+use crate::errors::Result;
+use crate::model::UserDataStreamExpiredEvent;
+use crate::options::model::{AccountUpdateEvent, OrderTradeEvent};
+use crate::websockets::ReconnectedEvent;
+use serde::{Deserialize, Serialize};
+
+/// Events of the options user data stream.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub enum OptionsWebsocketEvent {
+    AccountUpdate(AccountUpdateEvent),
+    OrderTrade(OrderTradeEvent),
+    UserDataStreamExpiredEvent(UserDataStreamExpiredEvent),
+    Reconnected(ReconnectedEvent),
+}
+
+pub(crate) fn parse_msg(msg: &str) -> Result<Option<OptionsWebsocketEvent>> {
+    let value: serde_json::Value = serde_json::from_str(msg)?;
+
+    if let Some(data) = value.get("data") {
+        return parse_msg(&data.to_string());
+    }
+
+    let event = match value.get("e").and_then(|e| e.as_str()) {
+        Some("ACCOUNT_UPDATE") => {
+            OptionsWebsocketEvent::AccountUpdate(serde_json::from_value(value)?)
+        }
+        Some("ORDER_TRADE_UPDATE") => {
+            OptionsWebsocketEvent::OrderTrade(serde_json::from_value(value)?)
+        }
+        Some("listenKeyExpired") => {
+            OptionsWebsocketEvent::UserDataStreamExpiredEvent(serde_json::from_value(value)?)
+        }
+        _ => return Ok(None),
+    };
+    Ok(Some(event))
+}
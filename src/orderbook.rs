@@ -0,0 +1,362 @@
+use crate::errors::Result;
+use crate::futures::market::FuturesMarket;
+use crate::market::Market;
+use crate::model::{Asks, Bids, DepthOrderBookEvent, Number};
+
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, VecDeque};
+
+const DEFAULT_DEPTH_LIMIT: u64 = 1000;
+// Events buffered after a snapshot attempt that failed or was too old, before the next one
+const SNAPSHOT_RETRY_EVENTS: u64 = 10;
+// Oldest buffered events are dropped past this, they would be older than the next snapshot
+const MAX_BUFFERED_EVENTS: usize = 1000;
+
+/// Local order book kept in sync from a REST snapshot and `@depth` diff events.
+///
+/// Feed every `DepthOrderBookEvent` of the symbol to `update`. The first event triggers the
+/// snapshot download; events are buffered until the snapshot lines up with the stream, and a
+/// sequence gap drops the book and fetches a fresh snapshot. When a snapshot fails or is older
+/// than the buffered events, the next one is only fetched after 10 more events.
+///
+/// ```no_run
+/// use binance::api::*;
+/// use binance::market::*;
+/// use binance::orderbook::*;
+/// use binance::websockets::*;
+/// use std::sync::atomic::AtomicBool;
+///
+/// let market: Market = Binance::new(None, None);
+/// let mut order_book = OrderBookManager::spot(market, "BTCUSDT").on_change(|book| {
+///     if let (Some(bid), Some(ask)) = (book.best_bid(), book.best_ask()) {
+///         println!("{} / {}", bid.price, ask.price);
+///     }
+/// });
+///
+/// let keep_running = AtomicBool::new(true);
+/// let mut web_socket = WebSockets::new(|event: WebsocketEvent| {
+///     if let WebsocketEvent::DepthOrderBook(depth) = event {
+///         order_book.update(depth)?;
+///     }
+///     Ok(())
+/// });
+/// web_socket.connect("btcusdt@depth@100ms").unwrap();
+/// web_socket.event_loop(&keep_running).unwrap();
+/// ```
+pub struct OrderBookManager<'a> {
+    symbol: String,
+    source: DepthSource,
+    depth_limit: u64,
+    last_update_id: u64,
+    synced: bool,
+    diff_applied: bool,
+    buffer: VecDeque<DepthOrderBookEvent>,
+    events_until_snapshot: u64,
+    bids: BTreeMap<Price, Number>,
+    asks: BTreeMap<Price, Number>,
+    resyncs: u64,
+    on_change: Option<ChangeCallback<'a>>,
+}
+
+type ChangeCallback<'a> = Box<dyn FnMut(&OrderBookManager) + 'a>;
+
+enum DepthSource {
+    Spot(Market),
+    UsdM(FuturesMarket),
+}
+
+enum Sequence {
+    Stale,
+    Apply,
+    Gap,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Price(Number);
+
+impl Eq for Price {}
+
+impl PartialOrd for Price {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Price {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Prices coming from the exchange are never NaN
+        self.0.partial_cmp(&other.0).unwrap_or(Ordering::Equal)
+    }
+}
+
+impl<'a> OrderBookManager<'a> {
+    /// Book for a spot symbol, synced with the `<symbol>@depth` stream.
+    pub fn spot<S>(market: Market, symbol: S) -> OrderBookManager<'a>
+    where
+        S: Into<String>,
+    {
+        Self::new(DepthSource::Spot(market), symbol.into())
+    }
+
+    /// Book for a USD-M futures symbol, synced with the `<symbol>@depth` stream.
+    pub fn usdm<S>(market: FuturesMarket, symbol: S) -> OrderBookManager<'a>
+    where
+        S: Into<String>,
+    {
+        Self::new(DepthSource::UsdM(market), symbol.into())
+    }
+
+    fn new(source: DepthSource, symbol: String) -> OrderBookManager<'a> {
+        OrderBookManager {
+            symbol: symbol.to_uppercase(),
+            source,
+            depth_limit: DEFAULT_DEPTH_LIMIT,
+            last_update_id: 0,
+            synced: false,
+            diff_applied: false,
+            buffer: VecDeque::new(),
+            events_until_snapshot: 0,
+            bids: BTreeMap::new(),
+            asks: BTreeMap::new(),
+            resyncs: 0,
+            on_change: None,
+        }
+    }
+
+    /// Number of levels requested for the REST snapshot (1000 by default).
+    pub fn set_depth_limit(mut self, depth_limit: u64) -> Self {
+        self.depth_limit = depth_limit;
+        self
+    }
+
+    /// Called every time the book changes, after a snapshot or an applied diff.
+    pub fn on_change<Callback>(mut self, callback: Callback) -> Self
+    where
+        Callback: FnMut(&OrderBookManager) + 'a,
+    {
+        self.on_change = Some(Box::new(callback));
+        self
+    }
+
+    /// Applies a depth diff event, fetching a snapshot first when the book is not in sync.
+    pub fn update(&mut self, event: DepthOrderBookEvent) -> Result<()> {
+        if event.symbol != self.symbol {
+            return Ok(());
+        }
+
+        if !self.synced {
+            self.buffer_event(event);
+            return self.sync();
+        }
+
+        match self.sequence(&event) {
+            Sequence::Stale => Ok(()),
+            Sequence::Apply => {
+                self.apply(event);
+                self.notify();
+                Ok(())
+            }
+            Sequence::Gap => {
+                self.resyncs += 1;
+                self.reset();
+                self.buffer_event(event);
+                self.sync()
+            }
+        }
+    }
+
+    /// Drops the book; the next event fetches a new snapshot.
+    pub fn reset(&mut self) {
+        self.synced = false;
+        self.diff_applied = false;
+        self.last_update_id = 0;
+        self.buffer.clear();
+        self.events_until_snapshot = 0;
+        self.bids.clear();
+        self.asks.clear();
+    }
+
+    pub fn symbol(&self) -> &str {
+        &self.symbol
+    }
+
+    pub fn is_synced(&self) -> bool {
+        self.synced
+    }
+
+    pub fn last_update_id(&self) -> u64 {
+        self.last_update_id
+    }
+
+    /// Number of times a sequence gap forced a new snapshot.
+    pub fn resyncs(&self) -> u64 {
+        self.resyncs
+    }
+
+    pub fn best_bid(&self) -> Option<Bids> {
+        self.bids
+            .iter()
+            .next_back()
+            .map(|(price, qty)| Bids::new(price.0, *qty))
+    }
+
+    pub fn best_ask(&self) -> Option<Asks> {
+        self.asks
+            .iter()
+            .next()
+            .map(|(price, qty)| Asks::new(price.0, *qty))
+    }
+
+    /// Best `depth` bids, highest price first.
+    pub fn bids(&self, depth: usize) -> Vec<Bids> {
+        self.bids
+            .iter()
+            .rev()
+            .take(depth)
+            .map(|(price, qty)| Bids::new(price.0, *qty))
+            .collect()
+    }
+
+    /// Best `depth` asks, lowest price first.
+    pub fn asks(&self, depth: usize) -> Vec<Asks> {
+        self.asks
+            .iter()
+            .take(depth)
+            .map(|(price, qty)| Asks::new(price.0, *qty))
+            .collect()
+    }
+
+    fn buffer_event(&mut self, event: DepthOrderBookEvent) {
+        if self.buffer.len() == MAX_BUFFERED_EVENTS {
+            self.buffer.pop_front();
+        }
+        self.buffer.push_back(event);
+    }
+
+    fn sync(&mut self) -> Result<()> {
+        // Snapshots weigh a lot, so a failed attempt waits for more events instead of the next one
+        if self.events_until_snapshot > 0 {
+            self.events_until_snapshot -= 1;
+            return Ok(());
+        }
+        self.events_until_snapshot = SNAPSHOT_RETRY_EVENTS;
+        let (last_update_id, bids, asks) = self.snapshot()?;
+
+        // The snapshot must not be older than the first buffered event, otherwise retry later
+        if let Some(first) = self.buffer.front() {
+            if first.first_update_id > self.first_update_limit(last_update_id) {
+                return Ok(());
+            }
+        }
+        self.events_until_snapshot = 0;
+
+        self.last_update_id = last_update_id;
+        self.diff_applied = false;
+        self.bids = bids
+            .into_iter()
+            .map(|bid| (Price(bid.price), bid.qty))
+            .collect();
+        self.asks = asks
+            .into_iter()
+            .map(|ask| (Price(ask.price), ask.qty))
+            .collect();
+        self.synced = true;
+
+        for event in std::mem::take(&mut self.buffer) {
+            match self.sequence(&event) {
+                Sequence::Stale => continue,
+                Sequence::Apply => self.apply(event),
+                Sequence::Gap => {
+                    // A diff went missing while buffering, start over with the next event
+                    self.resyncs += 1;
+                    self.reset();
+                    return Ok(());
+                }
+            }
+        }
+        self.notify();
+        Ok(())
+    }
+
+    fn snapshot(&self) -> Result<(u64, Vec<Bids>, Vec<Asks>)> {
+        match &self.source {
+            DepthSource::Spot(market) => {
+                let book = market.get_custom_depth(self.symbol.as_str(), self.depth_limit)?;
+                Ok((book.last_update_id, book.bids, book.asks))
+            }
+            DepthSource::UsdM(market) => {
+                let book = market.get_custom_depth(self.symbol.as_str(), self.depth_limit)?;
+                Ok((book.last_update_id, book.bids, book.asks))
+            }
+        }
+    }
+
+    // Spot diffs chain through `U == previous u + 1`, futures diffs through `pu == previous u`
+    fn sequence(&self, event: &DepthOrderBookEvent) -> Sequence {
+        let last_update_id = self.last_update_id;
+        let futures = matches!(self.source, DepthSource::UsdM(_));
+
+        if !self.diff_applied {
+            let stale = if futures {
+                event.final_update_id < last_update_id
+            } else {
+                event.final_update_id <= last_update_id
+            };
+            if stale {
+                return Sequence::Stale;
+            }
+            return if event.first_update_id <= self.first_update_limit(last_update_id) {
+                Sequence::Apply
+            } else {
+                Sequence::Gap
+            };
+        }
+
+        if event.final_update_id <= last_update_id {
+            return Sequence::Stale;
+        }
+        let in_sequence = match event.previous_final_update_id {
+            Some(previous_final_update_id) if futures => previous_final_update_id == last_update_id,
+            _ => event.first_update_id == last_update_id + 1,
+        };
+        if in_sequence {
+            Sequence::Apply
+        } else {
+            Sequence::Gap
+        }
+    }
+
+    // Highest `U` the first diff applied on top of a snapshot may have
+    fn first_update_limit(&self, last_update_id: u64) -> u64 {
+        match self.source {
+            DepthSource::Spot(_) => last_update_id + 1,
+            DepthSource::UsdM(_) => last_update_id,
+        }
+    }
+
+    fn apply(&mut self, event: DepthOrderBookEvent) {
+        for bid in event.bids {
+            update_level(&mut self.bids, bid.price, bid.qty);
+        }
+        for ask in event.asks {
+            update_level(&mut self.asks, ask.price, ask.qty);
+        }
+        self.last_update_id = event.final_update_id;
+        self.diff_applied = true;
+    }
+
+    fn notify(&mut self) {
+        if let Some(mut callback) = self.on_change.take() {
+            callback(self);
+            self.on_change = Some(callback);
+        }
+    }
+}
+
+fn update_level(levels: &mut BTreeMap<Price, Number>, price: Number, qty: Number) {
+    if qty == Number::default() {
+        levels.remove(&Price(price));
+    } else {
+        levels.insert(Price(price), qty);
+    }
+}
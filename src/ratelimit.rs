@@ -0,0 +1,269 @@
+use error_chain::bail;
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+
+use crate::errors::{ErrorKind, Result};
+use crate::model::RateLimit;
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+const USED_WEIGHT_HEADER: &str = "x-mbx-used-weight-";
+const ORDER_COUNT_HEADER: &str = "x-mbx-order-count-";
+
+/// Request weight and order count usage, tracked from the `X-MBX-USED-WEIGHT-*` and
+/// `X-MBX-ORDER-COUNT-*` response headers.
+///
+/// Clones share the same state, so a limiter set on a `Config` covers every client built
+/// from it. Once the `RateLimit` entries of `exchange_info` are loaded with `update_limits`,
+/// requests wait for the interval to reset instead of going over a limit. After a 429 or 418
+/// response, requests fail with `ErrorKind::RateLimited` until `Retry-After` has passed.
+///
+/// ```no_run
+/// use binance::api::*;
+/// use binance::config::*;
+/// use binance::general::*;
+/// use binance::ratelimit::*;
+///
+/// let rate_limiter = RateLimiter::new();
+/// let config = Config::default().set_rate_limiter(rate_limiter.clone());
+/// let general: General = Binance::new_with_config(None, None, &config);
+///
+/// let exchange_info = general.exchange_info().unwrap();
+/// rate_limiter.update_limits(&exchange_info.rate_limits);
+/// println!("{:?}", rate_limiter.used_weight("1m"));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct RateLimiter {
+    state: Arc<Mutex<RateLimitState>>,
+}
+
+/// Usage in the current window of each interval reported by the exchange, keyed by interval
+/// (`1m`, `10s`, `1d`...).
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RateLimitUsage {
+    pub used_weight: BTreeMap<String, u64>,
+    pub order_count: BTreeMap<String, u64>,
+    pub banned_until: Option<SystemTime>,
+}
+
+#[derive(Debug, Default)]
+struct RateLimitState {
+    used_weight: HashMap<String, Counter>,
+    order_count: HashMap<String, Counter>,
+    limits: Vec<Limit>,
+    ban: Option<(u16, SystemTime)>,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Counter {
+    count: u64,
+    updated_at: SystemTime,
+}
+
+#[derive(Clone, Debug)]
+struct Limit {
+    orders: bool,
+    interval: String,
+    limit: u64,
+}
+
+impl RateLimiter {
+    pub fn new() -> RateLimiter {
+        RateLimiter::default()
+    }
+
+    /// Enables throttling with the `rateLimits` of `exchange_info`, replacing any loaded before.
+    pub fn update_limits(&self, rate_limits: &[RateLimit]) {
+        let limits = rate_limits
+            .iter()
+            .filter_map(|rate_limit| {
+                let orders = match rate_limit.rate_limit_type.as_str() {
+                    "REQUEST_WEIGHT" => false,
+                    "ORDERS" => true,
+                    _ => return None,
+                };
+                let unit = match rate_limit.interval.as_str() {
+                    "SECOND" => "s",
+                    "MINUTE" => "m",
+                    "HOUR" => "h",
+                    "DAY" => "d",
+                    _ => return None,
+                };
+                Some(Limit {
+                    orders,
+                    interval: format!("{}{}", rate_limit.interval_num, unit),
+                    limit: rate_limit.limit,
+                })
+            })
+            .collect();
+        self.lock().limits = limits;
+    }
+
+    /// Weight used in the current `interval` window, e.g. `used_weight("1m")`.
+    pub fn used_weight(&self, interval: &str) -> Option<u64> {
+        let interval = interval.to_lowercase();
+        let state = self.lock();
+        current(
+            state.used_weight.get(&interval),
+            &interval,
+            SystemTime::now(),
+        )
+    }
+
+    /// Orders placed in the current `interval` window, e.g. `order_count("10s")`.
+    pub fn order_count(&self, interval: &str) -> Option<u64> {
+        let interval = interval.to_lowercase();
+        let state = self.lock();
+        current(
+            state.order_count.get(&interval),
+            &interval,
+            SystemTime::now(),
+        )
+    }
+
+    pub fn usage(&self) -> RateLimitUsage {
+        let state = self.lock();
+        let now = SystemTime::now();
+        let usage = |counters: &HashMap<String, Counter>| {
+            counters
+                .iter()
+                .filter_map(|(interval, counter)| {
+                    current(Some(counter), interval, now).map(|count| (interval.clone(), count))
+                })
+                .collect()
+        };
+
+        RateLimitUsage {
+            used_weight: usage(&state.used_weight),
+            order_count: usage(&state.order_count),
+            banned_until: state
+                .ban
+                .map(|(_, until)| until)
+                .filter(|until| *until > now),
+        }
+    }
+
+    // How long to wait before sending a request; fails while a 429/418 ban is running
+    pub(crate) fn delay(&self, orders: bool) -> Result<Option<Duration>> {
+        let state = self.lock();
+        let now = SystemTime::now();
+
+        if let Some((status, until)) = state.ban {
+            if let Ok(remaining) = until.duration_since(now) {
+                bail!(ErrorKind::RateLimited(
+                    status,
+                    Some(remaining),
+                    HeaderMap::new()
+                ));
+            }
+        }
+
+        let mut delay: Option<Duration> = None;
+        for limit in &state.limits {
+            if limit.orders && !orders {
+                continue;
+            }
+            let counters = if limit.orders {
+                &state.order_count
+            } else {
+                &state.used_weight
+            };
+            let used = current(counters.get(&limit.interval), &limit.interval, now);
+            if used.map_or(false, |used| used >= limit.limit) {
+                let wait = window_remaining(&limit.interval, now);
+                delay = delay.max(wait);
+            }
+        }
+        Ok(delay)
+    }
+
+    pub(crate) fn update(&self, headers: &HeaderMap) {
+        let mut state = self.lock();
+        let now = SystemTime::now();
+
+        for (name, value) in headers {
+            let count = match value.to_str().ok().and_then(|value| value.parse().ok()) {
+                Some(count) => count,
+                None => continue,
+            };
+            let counter = Counter {
+                count,
+                updated_at: now,
+            };
+            let name = name.as_str();
+            if let Some(interval) = name.strip_prefix(USED_WEIGHT_HEADER) {
+                state.used_weight.insert(interval.into(), counter);
+            } else if let Some(interval) = name.strip_prefix(ORDER_COUNT_HEADER) {
+                state.order_count.insert(interval.into(), counter);
+            }
+        }
+    }
+
+    // Records a 429/418 response, returning its `Retry-After`
+    pub(crate) fn ban(&self, status: u16, headers: &HeaderMap) -> Option<Duration> {
+        let retry_after = retry_after(headers);
+        if let Some(retry_after) = retry_after {
+            self.lock().ban = Some((status, SystemTime::now() + retry_after));
+        }
+        retry_after
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, RateLimitState> {
+        // The state stays consistent even if a thread panicked while holding the lock
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+pub(crate) fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    headers
+        .get(RETRY_AFTER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse().ok())
+        .map(Duration::from_secs)
+}
+
+// Count of the window `now` falls in; older windows have been reset by the exchange
+fn current(counter: Option<&Counter>, interval: &str, now: SystemTime) -> Option<u64> {
+    let counter = counter?;
+    let millis = interval_millis(interval)?;
+    if window_start(counter.updated_at, millis) == window_start(now, millis) {
+        Some(counter.count)
+    } else {
+        Some(0)
+    }
+}
+
+fn window_start(time: SystemTime, millis: u64) -> u64 {
+    let since_epoch = time
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis() as u64;
+    since_epoch - since_epoch % millis
+}
+
+fn window_remaining(interval: &str, now: SystemTime) -> Option<Duration> {
+    let millis = interval_millis(interval)?;
+    let since_epoch = now
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis() as u64;
+    Some(Duration::from_millis(millis - since_epoch % millis))
+}
+
+// `10s`, `1m`, `1h`, `1d`
+fn interval_millis(interval: &str) -> Option<u64> {
+    let interval = interval.to_lowercase();
+    let (number, unit) = interval.split_at(interval.len().checked_sub(1)?);
+    let unit_millis = match unit {
+        "s" => 1_000,
+        "m" => 60_000,
+        "h" => 3_600_000,
+        "d" => 86_400_000,
+        _ => return None,
+    };
+    let number: u64 = number.parse().ok()?;
+    Some(number * unit_millis)
+}
@@ -1,5 +1,10 @@
-use crate::util::build_signed_request;
-use crate::model::{AssetDetail, CoinInfo, DepositAddress, SpotFuturesTransferType, TransactionId};
+use crate::util::{build_signed_request, encode, insert_time_range};
+use crate::model::{
+    AccountSnapshot, AccountSnapshotType, AccountStatus, ApiTradingStatus, AssetDetail, CoinInfo,
+    DepositAddress, DepositRecord, DepositStatus, IntoNumber, Number, SpotFuturesTransferType,
+    RecordsQueryResult, SystemStatus, TransactionId, UniversalTransfer, UniversalTransferType,
+    WithdrawId, WithdrawRecord, WithdrawStatus,
+};
 use crate::client::Client;
 use crate::errors::Result;
 use std::collections::BTreeMap;
@@ -12,6 +17,20 @@ pub struct Savings {
     pub recv_window: u64,
 }
 
+/// Withdrawal to an external address.
+///
+/// `network` defaults to the coin's default network. Coins that need a memo take it as
+/// `address_tag`; `withdraw_order_id` is the client id of the withdrawal.
+pub struct WithdrawRequest {
+    pub coin: String,
+    pub address: String,
+    pub amount: Number,
+    pub network: Option<String>,
+    pub address_tag: Option<String>,
+    pub withdraw_order_id: Option<String>,
+    pub name: Option<String>,
+}
+
 impl Savings {
     /// Get all coins available for deposit and withdrawal
     pub fn get_all_coins(&self) -> Result<Vec<CoinInfo>> {
@@ -50,17 +69,159 @@ impl Savings {
     }
 
     pub fn transfer_funds<S>(
-        &self, asset: S, amount: f64, transfer_type: SpotFuturesTransferType,
+        &self, asset: S, amount: impl IntoNumber, transfer_type: SpotFuturesTransferType,
     ) -> Result<TransactionId>
     where
         S: Into<String>,
     {
         let mut parameters = BTreeMap::new();
         parameters.insert("asset".into(), asset.into());
-        parameters.insert("amount".into(), amount.to_string());
+        parameters.insert("amount".into(), amount.try_into_number()?.to_string());
         parameters.insert("type".into(), (transfer_type as u8).to_string());
         let request = build_signed_request(parameters, self.recv_window)?;
         self.client
             .post_signed(API::Savings(Sapi::SpotFuturesTransfer), request)
     }
+
+    /// Transfer between any two wallets; isolated margin wallets are named by their symbol
+    pub fn universal_transfer<S>(
+        &self, transfer_type: UniversalTransferType, asset: S, amount: impl IntoNumber,
+        from_symbol: Option<String>, to_symbol: Option<String>,
+    ) -> Result<TransactionId>
+    where
+        S: Into<String>,
+    {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("type".into(), transfer_type.to_string());
+        parameters.insert("asset".into(), asset.into());
+        parameters.insert("amount".into(), amount.try_into_number()?.to_string());
+        if let Some(from_symbol) = from_symbol {
+            parameters.insert("fromSymbol".into(), from_symbol);
+        }
+        if let Some(to_symbol) = to_symbol {
+            parameters.insert("toSymbol".into(), to_symbol);
+        }
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed(API::Savings(Sapi::UniversalTransfer), request)
+    }
+
+    /// Universal transfers of one type, `size` (10 by default) per `current` page
+    pub fn universal_transfer_history(
+        &self, transfer_type: UniversalTransferType, start_time: Option<u64>,
+        end_time: Option<u64>, current: Option<u32>, size: Option<u16>,
+    ) -> Result<RecordsQueryResult<UniversalTransfer>> {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("type".into(), transfer_type.to_string());
+        insert_time_range(&mut parameters, start_time, end_time);
+        if let Some(current) = current {
+            parameters.insert("current".into(), current.to_string());
+        }
+        if let Some(size) = size {
+            parameters.insert("size".into(), size.to_string());
+        }
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::UniversalTransfer), Some(request))
+    }
+
+    /// Submit a withdrawal
+    pub fn withdraw(&self, withdraw: WithdrawRequest) -> Result<WithdrawId> {
+        let parameters = build_withdraw(withdraw);
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed(API::Savings(Sapi::Withdraw), request)
+    }
+
+    /// Deposit history, the last 90 days when no time range is given
+    pub fn deposit_history(
+        &self, coin: Option<String>, status: Option<DepositStatus>, start_time: Option<u64>,
+        end_time: Option<u64>,
+    ) -> Result<Vec<DepositRecord>> {
+        let mut parameters = BTreeMap::new();
+        if let Some(coin) = coin {
+            parameters.insert("coin".into(), coin);
+        }
+        if let Some(status) = status {
+            parameters.insert("status".into(), u8::from(status).to_string());
+        }
+        insert_time_range(&mut parameters, start_time, end_time);
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::DepositHistory), Some(request))
+    }
+
+    /// Withdraw history, the last 90 days when no time range is given
+    pub fn withdraw_history(
+        &self, coin: Option<String>, status: Option<WithdrawStatus>, start_time: Option<u64>,
+        end_time: Option<u64>,
+    ) -> Result<Vec<WithdrawRecord>> {
+        let mut parameters = BTreeMap::new();
+        if let Some(coin) = coin {
+            parameters.insert("coin".into(), coin);
+        }
+        if let Some(status) = status {
+            parameters.insert("status".into(), u8::from(status).to_string());
+        }
+        insert_time_range(&mut parameters, start_time, end_time);
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::WithdrawHistory), Some(request))
+    }
+
+    /// System maintenance status, not signed
+    pub fn system_status(&self) -> Result<SystemStatus> {
+        self.client.get(API::Savings(Sapi::SystemStatus), None)
+    }
+
+    pub fn account_status(&self) -> Result<AccountStatus> {
+        let request = build_signed_request(BTreeMap::new(), self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::AccountStatus), Some(request))
+    }
+
+    /// Whether API trading is locked, and the order ratios that trigger the lock
+    pub fn api_trading_status(&self) -> Result<ApiTradingStatus> {
+        let request = build_signed_request(BTreeMap::new(), self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::ApiTradingStatus), Some(request))
+    }
+
+    /// Daily snapshots of the spot, margin or futures account, at most 30 days apart
+    pub fn account_snapshot(
+        &self, snapshot_type: AccountSnapshotType, start_time: Option<u64>, end_time: Option<u64>,
+        limit: Option<u16>,
+    ) -> Result<AccountSnapshot> {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("type".into(), snapshot_type.to_string());
+        insert_time_range(&mut parameters, start_time, end_time);
+        if let Some(limit) = limit {
+            parameters.insert("limit".into(), limit.to_string());
+        }
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed(API::Savings(Sapi::AccountSnapshot), Some(request))
+    }
+}
+
+pub(crate) fn build_withdraw(withdraw: WithdrawRequest) -> BTreeMap<String, String> {
+    let mut parameters = BTreeMap::new();
+    parameters.insert("coin".into(), withdraw.coin);
+    parameters.insert("address".into(), encode(&withdraw.address));
+    parameters.insert("amount".into(), withdraw.amount.to_string());
+
+    if let Some(network) = withdraw.network {
+        parameters.insert("network".into(), network);
+    }
+    if let Some(address_tag) = withdraw.address_tag {
+        parameters.insert("addressTag".into(), encode(&address_tag));
+    }
+    if let Some(withdraw_order_id) = withdraw.withdraw_order_id {
+        parameters.insert("withdrawOrderId".into(), encode(&withdraw_order_id));
+    }
+    if let Some(name) = withdraw.name {
+        parameters.insert("name".into(), encode(&name));
+    }
+
+    parameters
 }
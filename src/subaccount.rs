@@ -0,0 +1,121 @@
+use crate::util::{build_signed_request, encode, insert_time_range};
+use crate::model::{
+    Number, SubAccountBalance, SubAccountBalances, SubAccountInfo, SubAccountList,
+    SubAccountTransferHistory, SubAccountTransferId, SubAccountType,
+};
+use crate::client::Client;
+use crate::errors::Result;
+use std::collections::BTreeMap;
+use crate::api::API;
+use crate::api::Sapi;
+
+/// Sub-accounts of a master account, used with the master account's keys.
+#[derive(Clone)]
+pub struct SubAccount {
+    pub client: Client,
+    pub recv_window: u64,
+}
+
+/// Transfer between the wallets of the master account and its sub-accounts.
+///
+/// A side without email is the master account. `symbol` is the isolated margin pair when
+/// either side is `IsolatedMargin`.
+pub struct SubAccountTransferRequest {
+    pub from_email: Option<String>,
+    pub to_email: Option<String>,
+    pub from_account_type: SubAccountType,
+    pub to_account_type: SubAccountType,
+    pub asset: String,
+    pub amount: Number,
+    pub symbol: Option<String>,
+    pub client_tran_id: Option<String>,
+}
+
+impl SubAccount {
+    /// Sub-accounts, `limit` (1 by default) per `page`, or the one of `email`
+    pub fn get_sub_accounts(
+        &self, email: Option<String>, page: Option<u32>, limit: Option<u16>,
+    ) -> Result<Vec<SubAccountInfo>> {
+        let mut parameters = BTreeMap::new();
+        if let Some(email) = email {
+            parameters.insert("email".into(), encode(&email));
+        }
+        if let Some(page) = page {
+            parameters.insert("page".into(), page.to_string());
+        }
+        if let Some(limit) = limit {
+            parameters.insert("limit".into(), limit.to_string());
+        }
+        let request = build_signed_request(parameters, self.recv_window)?;
+        let list: SubAccountList = self
+            .client
+            .get_signed(API::Savings(Sapi::SubAccountList), Some(request))?;
+        Ok(list.sub_accounts)
+    }
+
+    /// Spot balances of a sub-account
+    pub fn get_balances<S>(&self, email: S) -> Result<Vec<SubAccountBalance>>
+    where
+        S: Into<String>,
+    {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("email".into(), encode(&email.into()));
+        let request = build_signed_request(parameters, self.recv_window)?;
+        let balances: SubAccountBalances = self
+            .client
+            .get_signed(API::Savings(Sapi::SubAccountAssets), Some(request))?;
+        Ok(balances.balances)
+    }
+
+    pub fn transfer(&self, transfer: SubAccountTransferRequest) -> Result<SubAccountTransferId> {
+        let mut parameters = BTreeMap::new();
+        if let Some(from_email) = transfer.from_email {
+            parameters.insert("fromEmail".into(), encode(&from_email));
+        }
+        if let Some(to_email) = transfer.to_email {
+            parameters.insert("toEmail".into(), encode(&to_email));
+        }
+        parameters.insert(
+            "fromAccountType".into(),
+            transfer.from_account_type.to_string(),
+        );
+        parameters.insert("toAccountType".into(), transfer.to_account_type.to_string());
+        parameters.insert("asset".into(), transfer.asset);
+        parameters.insert("amount".into(), transfer.amount.to_string());
+        if let Some(symbol) = transfer.symbol {
+            parameters.insert("symbol".into(), symbol);
+        }
+        if let Some(client_tran_id) = transfer.client_tran_id {
+            parameters.insert("clientTranId".into(), client_tran_id);
+        }
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed(API::Savings(Sapi::SubAccountUniversalTransfer), request)
+    }
+
+    /// Transfers from or to a sub-account, the last 30 days when no time range is given
+    pub fn transfer_history(
+        &self, from_email: Option<String>, to_email: Option<String>, start_time: Option<u64>,
+        end_time: Option<u64>, page: Option<u32>, limit: Option<u16>,
+    ) -> Result<SubAccountTransferHistory> {
+        let mut parameters = BTreeMap::new();
+        if let Some(from_email) = from_email {
+            parameters.insert("fromEmail".into(), encode(&from_email));
+        }
+        if let Some(to_email) = to_email {
+            parameters.insert("toEmail".into(), encode(&to_email));
+        }
+        insert_time_range(&mut parameters, start_time, end_time);
+        if let Some(page) = page {
+            parameters.insert("page".into(), page.to_string());
+        }
+        if let Some(limit) = limit {
+            parameters.insert("limit".into(), limit.to_string());
+        }
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client.get_signed(
+            API::Savings(Sapi::SubAccountUniversalTransfer),
+            Some(request),
+        )
+    }
+}
@@ -0,0 +1,139 @@
+use crate::errors::Result;
+use crate::model::ServerTime;
+
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// Offset between the local clock and the exchange, added to the `timestamp` of signed
+/// requests to avoid `-1021` "Timestamp for this request is outside of the recvWindow" errors.
+///
+/// Disabled by default. Once enabled, signed requests of the clients built from the `Config`
+/// measure the offset with the `time` endpoint of their API whenever it is older than the
+/// refresh interval. Clones share the same state.
+///
+/// ```no_run
+/// use binance::api::*;
+/// use binance::account::*;
+/// use binance::config::*;
+/// use binance::timesync::*;
+/// use std::time::Duration;
+///
+/// let time_sync = TimeSync::new(Duration::from_secs(600));
+/// let config = Config::default().set_time_sync(time_sync.clone());
+/// let account: Account = Binance::new_with_config(None, None, &config);
+///
+/// account.get_account().unwrap();
+/// println!("{:?}", time_sync.offset());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct TimeSync {
+    state: Arc<Mutex<TimeSyncState>>,
+}
+
+#[derive(Debug, Default)]
+struct TimeSyncState {
+    refresh_interval: Option<Duration>,
+    offset: Option<i64>,
+    synced_at: Option<Instant>,
+}
+
+impl TimeSync {
+    /// Syncs with the server time every `refresh_interval`.
+    pub fn new(refresh_interval: Duration) -> TimeSync {
+        let time_sync = TimeSync::default();
+        time_sync.lock().refresh_interval = Some(refresh_interval);
+        time_sync
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.lock().refresh_interval.is_some()
+    }
+
+    /// Milliseconds to add to the local clock, once measured.
+    pub fn offset(&self) -> Option<i64> {
+        self.lock().offset
+    }
+
+    /// Sets the offset directly, e.g. when it is measured elsewhere.
+    pub fn set_offset(&self, offset: i64) {
+        let mut state = self.lock();
+        state.offset = Some(offset);
+        state.synced_at = Some(Instant::now());
+    }
+
+    /// Measures the offset with `get_server_time`, e.g. `|| general.get_server_time()`.
+    ///
+    /// The server time is taken as the middle of the round trip.
+    pub fn sync<F>(&self, get_server_time: F) -> Result<i64>
+    where
+        F: FnOnce() -> Result<ServerTime>,
+    {
+        let sent_at = SystemTime::now();
+        let started = Instant::now();
+        let server_time = get_server_time()?;
+        self.update(server_time, sent_at, started.elapsed())
+    }
+
+    /// Same as `sync` for the async clients.
+    pub async fn sync_async<F, Fut>(&self, get_server_time: F) -> Result<i64>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<ServerTime>>,
+    {
+        let sent_at = SystemTime::now();
+        let started = Instant::now();
+        let server_time = get_server_time().await?;
+        self.update(server_time, sent_at, started.elapsed())
+    }
+
+    // Whether the offset has to be measured before signing a request
+    pub(crate) fn needs_sync(&self) -> bool {
+        let state = self.lock();
+        match (state.refresh_interval, state.synced_at) {
+            (None, _) => false,
+            (Some(_), None) => true,
+            (Some(refresh_interval), Some(synced_at)) => synced_at.elapsed() >= refresh_interval,
+        }
+    }
+
+    // Local time in milliseconds shifted by the offset
+    pub(crate) fn timestamp(&self) -> Result<u64> {
+        let local_time = SystemTime::now().duration_since(UNIX_EPOCH)?.as_millis() as i64;
+        Ok((local_time + self.offset().unwrap_or_default()) as u64)
+    }
+
+    // Shifts the `timestamp` parameter of a signed request by the offset, and by how long the
+    // request `waited` since it was stamped
+    pub(crate) fn apply(&self, request: String, waited: Duration) -> String {
+        let offset = self.offset().unwrap_or_default() + waited.as_millis() as i64;
+        if offset == 0 {
+            return request;
+        }
+
+        request
+            .split('&')
+            .map(|param| match param.strip_prefix("timestamp=") {
+                Some(timestamp) => match timestamp.parse::<i64>() {
+                    Ok(timestamp) => format!("timestamp={}", timestamp + offset),
+                    Err(_) => param.to_string(),
+                },
+                None => param.to_string(),
+            })
+            .collect::<Vec<String>>()
+            .join("&")
+    }
+
+    fn update(&self, server_time: ServerTime, sent_at: SystemTime, rtt: Duration) -> Result<i64> {
+        let local_time = (sent_at + rtt / 2).duration_since(UNIX_EPOCH)?.as_millis() as i64;
+        let offset = server_time.server_time as i64 - local_time;
+        self.set_offset(offset);
+        Ok(offset)
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, TimeSyncState> {
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
@@ -1,8 +1,20 @@
 use crate::model::{Success, UserDataStream};
 use crate::client::Client;
+use crate::config::Config;
 use crate::errors::Result;
 use crate::api::API;
 use crate::api::Spot;
+use crate::websockets::{
+    connect_socket, parse_msg, reconnect_with_backoff, sleep_while_running, ConnectionStats,
+    ReconnectConfig, ReconnectedEvent, Socket, WebsocketEvent,
+};
+use error_chain::bail;
+use tungstenite::Message;
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, SystemTime};
 
 #[derive(Clone)]
 pub struct UserStream {
@@ -26,3 +38,258 @@ impl UserStream {
             .delete(API::Spot(Spot::UserDataStream), listen_key)
     }
 }
+
+/// Listen key lifecycle and stream events of a user data stream, implemented by `UserStream`,
+/// `FuturesUserStream`, `OptionsUserStream` and `MarginUserStream` for `UserDataSession`.
+pub trait UserDataStreamApi: Clone + Send + 'static {
+    type Event;
+
+    fn start(&self) -> Result<UserDataStream>;
+    fn keep_alive(&self, listen_key: &str) -> Result<Success>;
+    fn close(&self, listen_key: &str) -> Result<Success>;
+
+    /// Websocket endpoint the listen key is appended to.
+    fn ws_endpoint(&self, config: &Config) -> String;
+    fn parse_event(msg: &str) -> Result<Option<Self::Event>>;
+    fn is_expired(event: &Self::Event) -> bool;
+    fn reconnected(event: ReconnectedEvent) -> Self::Event;
+}
+
+impl UserDataStreamApi for UserStream {
+    type Event = WebsocketEvent;
+
+    fn start(&self) -> Result<UserDataStream> {
+        UserStream::start(self)
+    }
+
+    fn keep_alive(&self, listen_key: &str) -> Result<Success> {
+        UserStream::keep_alive(self, listen_key)
+    }
+
+    fn close(&self, listen_key: &str) -> Result<Success> {
+        UserStream::close(self, listen_key)
+    }
+
+    fn ws_endpoint(&self, config: &Config) -> String {
+        config.ws_endpoint.clone()
+    }
+
+    fn parse_event(msg: &str) -> Result<Option<WebsocketEvent>> {
+        parse_msg(msg)
+    }
+
+    fn is_expired(event: &WebsocketEvent) -> bool {
+        matches!(event, WebsocketEvent::UserDataStreamExpiredEvent(_))
+    }
+
+    fn reconnected(event: ReconnectedEvent) -> WebsocketEvent {
+        WebsocketEvent::Reconnected(event)
+    }
+}
+
+/// User data stream that owns its listen key.
+///
+/// The key is kept alive from a background thread, and a new key and connection are set up
+/// with the `ReconnectConfig` backoff when the key expires or the connection drops, followed by
+/// a `Reconnected` event. The key is closed when the event loop ends.
+///
+/// ```no_run
+/// use binance::api::*;
+/// use binance::userstream::*;
+/// use binance::websockets::*;
+/// use std::sync::atomic::AtomicBool;
+///
+/// let user_stream: UserStream = Binance::new(Some("api_key".into()), None);
+/// let mut session = UserDataSession::new(user_stream, |event: WebsocketEvent| {
+///     println!("{:?}", event);
+///     Ok(())
+/// });
+/// session.event_loop(&AtomicBool::new(true)).unwrap();
+/// ```
+pub struct UserDataSession<'a, S: UserDataStreamApi> {
+    user_stream: S,
+    ws_endpoint: String,
+    handler: Box<dyn FnMut(S::Event) -> Result<()> + 'a>,
+    keep_alive_interval: Duration,
+    reconnect: ReconnectConfig,
+    stats: ConnectionStats,
+    listen_key: Arc<Mutex<Option<String>>>,
+}
+
+impl<'a, S: UserDataStreamApi> UserDataSession<'a, S> {
+    pub fn new<Callback>(user_stream: S, handler: Callback) -> UserDataSession<'a, S>
+    where
+        Callback: FnMut(S::Event) -> Result<()> + 'a,
+    {
+        Self::new_with_config(user_stream, &Config::default(), handler)
+    }
+
+    pub fn new_with_config<Callback>(
+        user_stream: S, config: &Config, handler: Callback,
+    ) -> UserDataSession<'a, S>
+    where
+        Callback: FnMut(S::Event) -> Result<()> + 'a,
+    {
+        UserDataSession {
+            ws_endpoint: user_stream.ws_endpoint(config),
+            user_stream,
+            handler: Box::new(handler),
+            keep_alive_interval: Duration::from_secs(30 * 60),
+            reconnect: ReconnectConfig::default(),
+            stats: ConnectionStats::default(),
+            listen_key: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    /// How often the listen key is kept alive, every 30 minutes by default.
+    pub fn with_keep_alive_interval(mut self, keep_alive_interval: Duration) -> Self {
+        self.keep_alive_interval = keep_alive_interval;
+        self
+    }
+
+    /// Backoff used to get a new listen key and connection.
+    pub fn with_reconnect(mut self, reconnect: ReconnectConfig) -> Self {
+        self.reconnect = reconnect;
+        self
+    }
+
+    pub fn stats(&self) -> &ConnectionStats {
+        &self.stats
+    }
+
+    /// Listen key currently in use, if the session is running.
+    pub fn listen_key(&self) -> Option<String> {
+        lock(&self.listen_key).clone()
+    }
+
+    pub fn event_loop(&mut self, running: &AtomicBool) -> Result<()> {
+        let mut socket = open(&self.user_stream, &self.ws_endpoint, &self.listen_key)?;
+        self.stats.connects += 1;
+        self.stats.last_connected_at = Some(SystemTime::now());
+        let _keep_alive = KeepAlive::spawn(
+            self.user_stream.clone(),
+            self.listen_key.clone(),
+            self.keep_alive_interval,
+        );
+
+        while running.load(Ordering::Relaxed) {
+            let message = match socket.0.read() {
+                Ok(message) => message,
+                Err(_) => match self.reopen(running)? {
+                    Some(reopened) => {
+                        socket = reopened;
+                        continue;
+                    }
+                    None => break,
+                },
+            };
+            let expired = match message {
+                Message::Text(msg) => {
+                    self.stats.messages_received += 1;
+                    match S::parse_event(&msg) {
+                        Ok(Some(event)) => {
+                            let expired = S::is_expired(&event);
+                            if let Err(e) = (self.handler)(event) {
+                                bail!(format!("Error on handling stream message: {}", e));
+                            }
+                            expired
+                        }
+                        Ok(None) => false,
+                        Err(e) => bail!(format!("Error on handling stream message: {}", e)),
+                    }
+                }
+                Message::Ping(payload) => {
+                    socket.0.send(Message::Pong(payload))?;
+                    false
+                }
+                Message::Pong(_) | Message::Binary(_) | Message::Frame(_) => false,
+                Message::Close(_) => true,
+            };
+            if expired {
+                let _ = socket.0.close(None);
+                match self.reopen(running)? {
+                    Some(reopened) => socket = reopened,
+                    None => break,
+                }
+            }
+        }
+
+        let _ = socket.0.close(None);
+        if let Some(listen_key) = lock(&self.listen_key).take() {
+            self.user_stream.close(&listen_key)?;
+        }
+        Ok(())
+    }
+
+    // New listen key and connection, or `None` once `running` is cleared
+    fn reopen(&mut self, running: &AtomicBool) -> Result<Option<Socket>> {
+        let (user_stream, ws_endpoint, listen_key) =
+            (&self.user_stream, &self.ws_endpoint, &self.listen_key);
+        let reopened = reconnect_with_backoff(&self.reconnect, &mut self.stats, running, || {
+            open(user_stream, ws_endpoint, listen_key)
+        })?;
+
+        match reopened {
+            Some((socket, attempts)) => {
+                (self.handler)(S::reconnected(ReconnectedEvent {
+                    attempts,
+                    reconnects: self.stats.reconnects,
+                }))?;
+                Ok(Some(socket))
+            }
+            None => Ok(None),
+        }
+    }
+}
+
+// Starts (or renews) the listen key and connects to its stream
+fn open<S: UserDataStreamApi>(
+    user_stream: &S, ws_endpoint: &str, listen_key: &Mutex<Option<String>>,
+) -> Result<Socket> {
+    let started = user_stream.start()?;
+    *lock(listen_key) = Some(started.listen_key.clone());
+    connect_socket(&format!("{}/{}", ws_endpoint, started.listen_key))
+}
+
+fn lock(listen_key: &Mutex<Option<String>>) -> MutexGuard<'_, Option<String>> {
+    listen_key
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+// Keeps the current listen key alive until dropped
+struct KeepAlive {
+    active: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl KeepAlive {
+    fn spawn<S: UserDataStreamApi>(
+        user_stream: S, listen_key: Arc<Mutex<Option<String>>>, interval: Duration,
+    ) -> KeepAlive {
+        let active = Arc::new(AtomicBool::new(true));
+        let running = active.clone();
+        let thread = thread::spawn(move || {
+            while sleep_while_running(interval, &running) {
+                let current = lock(&listen_key).clone();
+                if let Some(current) = current {
+                    // A lost key expires and is replaced by the event loop
+                    let _ = user_stream.keep_alive(&current);
+                }
+            }
+        });
+        KeepAlive {
+            active,
+            thread: Some(thread),
+        }
+    }
+}
+
+impl Drop for KeepAlive {
+    fn drop(&mut self) {
+        self.active.store(false, Ordering::Relaxed);
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
@@ -33,6 +33,23 @@ pub fn build_signed_request_custom(
     bail!("Failed to get timestamp")
 }
 
+// Free-form values are percent-encoded so the signature covers the exact query sent
+pub(crate) fn encode(value: &str) -> String {
+    url::form_urlencoded::byte_serialize(value.as_bytes()).collect()
+}
+
+// Optional `startTime` and `endTime` of history requests
+pub(crate) fn insert_time_range(
+    parameters: &mut BTreeMap<String, String>, start_time: Option<u64>, end_time: Option<u64>,
+) {
+    if let Some(start_time) = start_time {
+        parameters.insert("startTime".into(), start_time.to_string());
+    }
+    if let Some(end_time) = end_time {
+        parameters.insert("endTime".into(), end_time.to_string());
+    }
+}
+
 pub fn to_i64(v: &Value) -> i64 {
     v.as_i64().unwrap()
 }
@@ -47,11 +64,14 @@ fn get_timestamp(start: SystemTime) -> Result<u64> {
 }
 
 pub fn is_start_time_valid(start_time: &u64) -> bool {
-    let current_time = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs();
+    let current_time = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
 
     if start_time > &current_time {
         false
     } else {
         true
     }
-}
\ No newline at end of file
+}
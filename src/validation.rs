@@ -0,0 +1,454 @@
+use error_chain::bail;
+
+use crate::errors::{ErrorKind, Result};
+use crate::futures;
+use crate::model::{ExchangeInformation, Filters, Number};
+
+use std::collections::HashMap;
+use std::fmt;
+
+/// Client-side checks of order prices and quantities against the symbol filters returned by
+/// `exchange_info`, so an invalid order fails before it reaches the exchange.
+///
+/// Only filters that do not depend on market data are checked: `PRICE_FILTER`, `LOT_SIZE`,
+/// `MARKET_LOT_SIZE`, and `MIN_NOTIONAL`/`NOTIONAL` for orders with a price. With rounding
+/// enabled, prices and quantities are rounded down to the tick and step size instead of
+/// being rejected.
+///
+/// ```no_run
+/// use binance::api::*;
+/// use binance::account::*;
+/// use binance::general::*;
+/// use binance::validation::*;
+///
+/// let general: General = Binance::new(None, None);
+/// let validator = OrderValidator::spot(&general.exchange_info().unwrap())
+///     .unwrap()
+///     .set_rounding(true);
+///
+/// let account: Account = Binance::new(None, None);
+/// let account = account.with_validator(validator);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct OrderValidator {
+    symbols: HashMap<String, SymbolFilters>,
+    rounding: bool,
+}
+
+/// The filter an order failed, with the offending value and the filter bounds.
+///
+/// A bound of zero means the exchange does not enforce it.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FilterViolation {
+    UnknownSymbol(String),
+    PriceFilter {
+        price: Number,
+        min_price: Number,
+        max_price: Number,
+        tick_size: Number,
+    },
+    LotSize {
+        qty: Number,
+        min_qty: Number,
+        max_qty: Number,
+        step_size: Number,
+    },
+    MarketLotSize {
+        qty: Number,
+        min_qty: Number,
+        max_qty: Number,
+        step_size: Number,
+    },
+    MinNotional {
+        notional: Number,
+        min_notional: Number,
+    },
+    Notional {
+        notional: Number,
+        min_notional: Number,
+        max_notional: Number,
+    },
+}
+
+impl FilterViolation {
+    /// Name of the violated filter, as used by the exchange.
+    pub fn filter_type(&self) -> &'static str {
+        match self {
+            Self::UnknownSymbol(_) => "UNKNOWN_SYMBOL",
+            Self::PriceFilter { .. } => "PRICE_FILTER",
+            Self::LotSize { .. } => "LOT_SIZE",
+            Self::MarketLotSize { .. } => "MARKET_LOT_SIZE",
+            Self::MinNotional { .. } => "MIN_NOTIONAL",
+            Self::Notional { .. } => "NOTIONAL",
+        }
+    }
+}
+
+impl fmt::Display for FilterViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownSymbol(symbol) => {
+                write!(f, "Symbol {} not found in exchange info", symbol)
+            }
+            Self::PriceFilter {
+                price,
+                min_price,
+                max_price,
+                tick_size,
+            } => write!(
+                f,
+                "{}: price {} outside [{}, {}] or not a multiple of {}",
+                self.filter_type(),
+                price,
+                min_price,
+                max_price,
+                tick_size
+            ),
+            Self::LotSize {
+                qty,
+                min_qty,
+                max_qty,
+                step_size,
+            }
+            | Self::MarketLotSize {
+                qty,
+                min_qty,
+                max_qty,
+                step_size,
+            } => write!(
+                f,
+                "{}: quantity {} outside [{}, {}] or not a multiple of {}",
+                self.filter_type(),
+                qty,
+                min_qty,
+                max_qty,
+                step_size
+            ),
+            Self::MinNotional {
+                notional,
+                min_notional,
+            } => write!(
+                f,
+                "{}: notional {} below {}",
+                self.filter_type(),
+                notional,
+                min_notional
+            ),
+            Self::Notional {
+                notional,
+                min_notional,
+                max_notional,
+            } => write!(
+                f,
+                "{}: notional {} outside [{}, {}]",
+                self.filter_type(),
+                notional,
+                min_notional,
+                max_notional
+            ),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+struct SymbolFilters {
+    price: Option<Bounds>,
+    lot_size: Option<Bounds>,
+    market_lot_size: Option<Bounds>,
+    min_notional: Option<Number>,
+    notional: Option<(Number, Number)>,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Bounds {
+    min: Number,
+    max: Number,
+    step: Number,
+}
+
+/// Price, stop price and quantity of an order after validation and rounding.
+pub(crate) struct CheckedOrder {
+    pub price: Option<Number>,
+    pub stop_price: Option<Number>,
+    pub qty: Option<Number>,
+}
+
+impl OrderValidator {
+    /// Validator for the spot symbols of `General::exchange_info`.
+    pub fn spot(exchange_info: &ExchangeInformation) -> Result<OrderValidator> {
+        let mut symbols = HashMap::new();
+        for symbol in &exchange_info.symbols {
+            symbols.insert(symbol.symbol.clone(), SymbolFilters::new(&symbol.filters)?);
+        }
+        Ok(OrderValidator {
+            symbols,
+            rounding: false,
+        })
+    }
+
+    /// Validator for the USD-M symbols of `FuturesGeneral::exchange_info`.
+    pub fn usdm(exchange_info: &futures::model::ExchangeInformation) -> Result<OrderValidator> {
+        let mut symbols = HashMap::new();
+        for symbol in &exchange_info.symbols {
+            symbols.insert(symbol.symbol.clone(), SymbolFilters::new(&symbol.filters)?);
+        }
+        Ok(OrderValidator {
+            symbols,
+            rounding: false,
+        })
+    }
+
+    /// Round prices and quantities down to the tick and step size instead of rejecting them.
+    pub fn set_rounding(mut self, rounding: bool) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    /// Checks a limit or stop price against `PRICE_FILTER`, returning the price to send.
+    pub fn check_price<S>(&self, symbol: S, price: Number) -> Result<Number>
+    where
+        S: AsRef<str>,
+    {
+        let filters = self.filters(symbol.as_ref())?;
+        match filters.price {
+            Some(bounds) => {
+                self.check_bounds(bounds, price, |price| FilterViolation::PriceFilter {
+                    price,
+                    min_price: bounds.min,
+                    max_price: bounds.max,
+                    tick_size: bounds.step,
+                })
+            }
+            None => Ok(price),
+        }
+    }
+
+    /// Checks the quantity of a non-market order against `LOT_SIZE`, returning the quantity to send.
+    pub fn check_qty<S>(&self, symbol: S, qty: Number) -> Result<Number>
+    where
+        S: AsRef<str>,
+    {
+        let filters = self.filters(symbol.as_ref())?;
+        self.check_lot_size(filters, qty)
+    }
+
+    /// Checks the quantity of a market order against `LOT_SIZE` and `MARKET_LOT_SIZE`.
+    pub fn check_market_qty<S>(&self, symbol: S, qty: Number) -> Result<Number>
+    where
+        S: AsRef<str>,
+    {
+        let filters = self.filters(symbol.as_ref())?;
+        let qty = self.check_lot_size(filters, qty)?;
+        match filters.market_lot_size {
+            Some(bounds) => self.check_bounds(bounds, qty, |qty| FilterViolation::MarketLotSize {
+                qty,
+                min_qty: bounds.min,
+                max_qty: bounds.max,
+                step_size: bounds.step,
+            }),
+            None => Ok(qty),
+        }
+    }
+
+    /// Checks `price * qty` against `MIN_NOTIONAL` and `NOTIONAL`.
+    pub fn check_notional<S>(&self, symbol: S, price: Number, qty: Number) -> Result<()>
+    where
+        S: AsRef<str>,
+    {
+        let filters = self.filters(symbol.as_ref())?;
+        let notional = price * qty;
+
+        if let Some(min_notional) = filters.min_notional {
+            if notional < min_notional {
+                bail!(ErrorKind::FilterError(FilterViolation::MinNotional {
+                    notional,
+                    min_notional,
+                }));
+            }
+        }
+        if let Some((min_notional, max_notional)) = filters.notional {
+            if notional < min_notional || (is_set(max_notional) && notional > max_notional) {
+                bail!(ErrorKind::FilterError(FilterViolation::Notional {
+                    notional,
+                    min_notional,
+                    max_notional,
+                }));
+            }
+        }
+        Ok(())
+    }
+
+    pub(crate) fn check_order(
+        &self, symbol: &str, market: bool, price: Option<Number>, stop_price: Option<Number>,
+        qty: Option<Number>,
+    ) -> Result<CheckedOrder> {
+        let price = match price {
+            Some(price) => Some(self.check_price(symbol, price)?),
+            None => None,
+        };
+        let stop_price = match stop_price {
+            Some(stop_price) => Some(self.check_price(symbol, stop_price)?),
+            None => None,
+        };
+        let qty = match qty {
+            Some(qty) if market => Some(self.check_market_qty(symbol, qty)?),
+            Some(qty) => Some(self.check_qty(symbol, qty)?),
+            None => None,
+        };
+        if let (Some(price), Some(qty)) = (price, qty) {
+            self.check_notional(symbol, price, qty)?;
+        }
+
+        Ok(CheckedOrder {
+            price,
+            stop_price,
+            qty,
+        })
+    }
+
+    fn filters(&self, symbol: &str) -> Result<&SymbolFilters> {
+        match self.symbols.get(&symbol.to_uppercase()) {
+            Some(filters) => Ok(filters),
+            None => bail!(ErrorKind::FilterError(FilterViolation::UnknownSymbol(
+                symbol.into()
+            ))),
+        }
+    }
+
+    fn check_lot_size(&self, filters: &SymbolFilters, qty: Number) -> Result<Number> {
+        match filters.lot_size {
+            Some(bounds) => self.check_bounds(bounds, qty, |qty| FilterViolation::LotSize {
+                qty,
+                min_qty: bounds.min,
+                max_qty: bounds.max,
+                step_size: bounds.step,
+            }),
+            None => Ok(qty),
+        }
+    }
+
+    fn check_bounds<F>(&self, bounds: Bounds, value: Number, violation: F) -> Result<Number>
+    where
+        F: Fn(Number) -> FilterViolation,
+    {
+        let mut value = value;
+        if is_set(bounds.step) && !is_step_multiple(value, bounds.step) {
+            if !self.rounding {
+                bail!(ErrorKind::FilterError(violation(value)));
+            }
+            value = round_down_to_step(value, bounds.step);
+        }
+
+        let too_low = is_set(bounds.min) && value < bounds.min;
+        let too_high = is_set(bounds.max) && value > bounds.max;
+        if too_low || too_high {
+            bail!(ErrorKind::FilterError(violation(value)));
+        }
+        Ok(value)
+    }
+}
+
+impl SymbolFilters {
+    fn new(filters: &[Filters]) -> Result<SymbolFilters> {
+        let mut symbol_filters = SymbolFilters::default();
+        for filter in filters {
+            match filter {
+                Filters::PriceFilter {
+                    min_price,
+                    max_price,
+                    tick_size,
+                } => symbol_filters.price = Some(Bounds::new(min_price, max_price, tick_size)?),
+                Filters::LotSize {
+                    min_qty,
+                    max_qty,
+                    step_size,
+                } => symbol_filters.lot_size = Some(Bounds::new(min_qty, max_qty, step_size)?),
+                Filters::MarketLotSize {
+                    min_qty,
+                    max_qty,
+                    step_size,
+                } => {
+                    symbol_filters.market_lot_size =
+                        Some(Bounds::new(min_qty, max_qty, step_size)?);
+                }
+                // Spot names the bound `minNotional`, USD-M futures `notional`
+                Filters::MinNotional {
+                    notional,
+                    min_notional,
+                    ..
+                } => {
+                    if let Some(min_notional) = min_notional.as_ref().or(notional.as_ref()) {
+                        symbol_filters.min_notional = Some(parse(min_notional)?);
+                    }
+                }
+                Filters::Notional {
+                    min_notional,
+                    max_notional,
+                    ..
+                } => {
+                    let min_notional = match min_notional {
+                        Some(min_notional) => parse(min_notional)?,
+                        None => Number::default(),
+                    };
+                    let max_notional = match max_notional {
+                        Some(max_notional) => parse(max_notional)?,
+                        None => Number::default(),
+                    };
+                    symbol_filters.notional = Some((min_notional, max_notional));
+                }
+                _ => (),
+            }
+        }
+        Ok(symbol_filters)
+    }
+}
+
+impl Bounds {
+    fn new(min: &str, max: &str, step: &str) -> Result<Bounds> {
+        Ok(Bounds {
+            min: parse(min)?,
+            max: parse(max)?,
+            step: parse(step)?,
+        })
+    }
+}
+
+fn parse(value: &str) -> Result<Number> {
+    match value.parse::<Number>() {
+        Ok(number) => Ok(number),
+        Err(_) => bail!("Invalid filter value {}", value),
+    }
+}
+
+fn is_set(value: Number) -> bool {
+    value > Number::default()
+}
+
+#[cfg(not(feature = "decimal"))]
+fn is_step_multiple(value: Number, step: Number) -> bool {
+    let steps = (value / step).round();
+    (value - steps * step).abs() <= step * 1e-9
+}
+
+// Rounds through the step's decimal places so the result prints without float noise
+#[cfg(not(feature = "decimal"))]
+fn round_down_to_step(value: Number, step: Number) -> Number {
+    let steps = (value / step + 1e-9).floor();
+    let decimals = step
+        .to_string()
+        .split('.')
+        .nth(1)
+        .map_or(0, |decimals| decimals.len() as i32);
+    let factor = 10f64.powi(decimals);
+    (steps * step * factor).round() / factor
+}
+
+#[cfg(feature = "decimal")]
+fn is_step_multiple(value: Number, step: Number) -> bool {
+    (value % step).is_zero()
+}
+
+#[cfg(feature = "decimal")]
+fn round_down_to_step(value: Number, step: Number) -> Number {
+    ((value / step).floor() * step).normalize()
+}
@@ -3,27 +3,32 @@ use crate::config::Config;
 use crate::model::{
     AccountUpdateEvent, AggrTradesEvent, BalanceUpdateEvent, BookTickerEvent, DayTickerEvent,
     WindowTickerEvent, DepthOrderBookEvent, KlineEvent, OrderBook, OrderTradeEvent, TradeEvent,
+    UserDataStreamExpiredEvent,
 };
 use error_chain::bail;
 use url::Url;
 use serde::{Deserialize, Serialize};
 
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::net::TcpStream;
+use std::thread;
+use std::time::{Duration, SystemTime};
 use tungstenite::{connect, Message};
 use tungstenite::protocol::WebSocket;
 use tungstenite::stream::MaybeTlsStream;
 use tungstenite::handshake::client::Response;
 
 #[allow(clippy::all)]
-enum WebsocketAPI {
+pub(crate) enum WebsocketAPI {
     Default,
     MultiStream,
     Custom(String),
 }
 
 impl WebsocketAPI {
-    fn params(self, subscription: &str) -> String {
+    pub(crate) fn params(self, subscription: &str) -> String {
         match self {
             WebsocketAPI::Default => format!("wss://stream.binance.com/ws/{}", subscription),
             WebsocketAPI::MultiStream => {
@@ -50,11 +55,111 @@ pub enum WebsocketEvent {
     Kline(KlineEvent),
     DepthOrderBook(DepthOrderBookEvent),
     BookTicker(BookTickerEvent),
+    UserDataStreamExpiredEvent(UserDataStreamExpiredEvent),
+    Reconnected(ReconnectedEvent),
 }
 
+/// Emitted after the connection was re-established; events may have been missed meanwhile.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ReconnectedEvent {
+    /// Connection attempts it took to get back online.
+    pub attempts: u32,
+    /// Total reconnections since the socket was first opened.
+    pub reconnects: u64,
+}
+
+/// Backoff policy used when reconnection is enabled.
+///
+/// The delay before attempt `n` is `initial_backoff * multiplier^(n-1)`, capped at
+/// `max_backoff`, then randomly shortened by up to `jitter` (a fraction between 0 and 1). A
+/// negative or NaN delay, e.g. from a negative `multiplier`, waits `max_backoff`.
+#[derive(Clone, Debug)]
+pub struct ReconnectConfig {
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+    pub multiplier: f64,
+    pub jitter: f64,
+    /// Give up after this many consecutive failed attempts; `None` retries forever.
+    pub max_attempts: Option<u32>,
+}
+
+impl Default for ReconnectConfig {
+    fn default() -> Self {
+        Self {
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            multiplier: 2.0,
+            jitter: 0.2,
+            max_attempts: None,
+        }
+    }
+}
+
+impl ReconnectConfig {
+    pub fn set_initial_backoff(mut self, initial_backoff: Duration) -> Self {
+        self.initial_backoff = initial_backoff;
+        self
+    }
+
+    pub fn set_max_backoff(mut self, max_backoff: Duration) -> Self {
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    pub fn set_multiplier(mut self, multiplier: f64) -> Self {
+        self.multiplier = multiplier;
+        self
+    }
+
+    pub fn set_jitter(mut self, jitter: f64) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    pub fn set_max_attempts(mut self, max_attempts: Option<u32>) -> Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+
+    /// Delay to wait before the given (1-based) reconnection attempt.
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(i32::MAX as u32) as i32;
+        let max_backoff = self.max_backoff.as_secs_f64();
+        let delay = self.initial_backoff.as_secs_f64() * self.multiplier.powi(exponent);
+        // `Duration::from_secs_f64` panics on negative and NaN values
+        let delay = if delay >= 0.0 {
+            delay.min(max_backoff)
+        } else {
+            max_backoff
+        };
+        let jitter = if self.jitter.is_nan() {
+            0.0
+        } else {
+            self.jitter.clamp(0.0, 1.0) * random_fraction()
+        };
+        Duration::from_secs_f64(delay * (1.0 - jitter))
+    }
+}
+
+/// Connection counters kept by reconnecting websockets.
+#[derive(Clone, Debug, Default)]
+pub struct ConnectionStats {
+    pub connects: u64,
+    pub reconnects: u64,
+    pub failed_attempts: u64,
+    pub messages_received: u64,
+    pub last_connected_at: Option<SystemTime>,
+    pub last_disconnected_at: Option<SystemTime>,
+}
+
+pub(crate) type Socket = (WebSocket<MaybeTlsStream<TcpStream>>, Response);
+
 pub struct WebSockets<'a> {
-    pub socket: Option<(WebSocket<MaybeTlsStream<TcpStream>>, Response)>,
+    pub socket: Option<Socket>,
     handler: Box<dyn FnMut(WebsocketEvent) -> Result<()> + 'a>,
+    wss: Option<String>,
+    reconnect: Option<ReconnectConfig>,
+    stats: ConnectionStats,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -73,6 +178,7 @@ enum Events {
     KlineEvent(KlineEvent),
     OrderBook(OrderBook),
     DepthOrderBookEvent(DepthOrderBookEvent),
+    UserDataStreamExpiredEvent(UserDataStreamExpiredEvent),
 }
 
 impl<'a> WebSockets<'a> {
@@ -83,9 +189,24 @@ impl<'a> WebSockets<'a> {
         WebSockets {
             socket: None,
             handler: Box::new(handler),
+            wss: None,
+            reconnect: None,
+            stats: ConnectionStats::default(),
         }
     }
 
+    /// Reconnect and resubscribe with the given backoff whenever the connection drops.
+    ///
+    /// A `WebsocketEvent::Reconnected` is delivered to the handler after every reconnection.
+    pub fn with_reconnect(mut self, reconnect: ReconnectConfig) -> Self {
+        self.reconnect = Some(reconnect);
+        self
+    }
+
+    pub fn stats(&self) -> &ConnectionStats {
+        &self.stats
+    }
+
     pub fn connect(&mut self, subscription: &str) -> Result<()> {
         self.connect_wss(&WebsocketAPI::Default.params(subscription))
     }
@@ -99,14 +220,30 @@ impl<'a> WebSockets<'a> {
     }
 
     fn connect_wss(&mut self, wss: &str) -> Result<()> {
-        let url = Url::parse(wss)?;
-        match connect(url) {
-            Ok(answer) => {
-                self.socket = Some(answer);
-                Ok(())
-            }
-            Err(e) => bail!(format!("Error during handshake {}", e)),
+        self.socket = Some(connect_socket(wss)?);
+        self.wss = Some(wss.to_string());
+        self.stats.connects += 1;
+        self.stats.last_connected_at = Some(SystemTime::now());
+        Ok(())
+    }
+
+    fn reconnect(&mut self, running: &AtomicBool) -> Result<()> {
+        let (reconnect, wss) = match (&self.reconnect, &self.wss) {
+            (Some(reconnect), Some(wss)) => (reconnect.clone(), wss.clone()),
+            _ => bail!("Not able to reconnect"),
+        };
+        self.socket = None;
+        let reconnected = reconnect_with_backoff(&reconnect, &mut self.stats, running, || {
+            connect_socket(&wss)
+        })?;
+        if let Some((socket, attempts)) = reconnected {
+            self.socket = Some(socket);
+            (self.handler)(WebsocketEvent::Reconnected(ReconnectedEvent {
+                attempts,
+                reconnects: self.stats.reconnects,
+            }))?;
         }
+        Ok(())
     }
 
     pub fn disconnect(&mut self) -> Result<()> {
@@ -122,29 +259,7 @@ impl<'a> WebSockets<'a> {
     }
 
     pub fn handle_msg(&mut self, msg: &str) -> Result<()> {
-        let value: serde_json::Value = serde_json::from_str(msg)?;
-
-        if let Some(data) = value.get("data") {
-            self.handle_msg(&data.to_string())?;
-            return Ok(());
-        }
-
-        if let Ok(events) = serde_json::from_value::<Events>(value) {
-            let action = match events {
-                Events::DayTickerEventAll(v) => WebsocketEvent::DayTickerAll(v),
-                Events::WindowTickerEventAll(v) => WebsocketEvent::WindowTickerAll(v),
-                Events::BookTickerEvent(v) => WebsocketEvent::BookTicker(v),
-                Events::BalanceUpdateEvent(v) => WebsocketEvent::BalanceUpdate(v),
-                Events::AccountUpdateEvent(v) => WebsocketEvent::AccountUpdate(v),
-                Events::OrderTradeEvent(v) => WebsocketEvent::OrderTrade(v),
-                Events::AggrTradesEvent(v) => WebsocketEvent::AggrTrades(v),
-                Events::TradeEvent(v) => WebsocketEvent::Trade(v),
-                Events::DayTickerEvent(v) => WebsocketEvent::DayTicker(v),
-                Events::WindowTickerEvent(v) => WebsocketEvent::WindowTicker(v),
-                Events::KlineEvent(v) => WebsocketEvent::Kline(v),
-                Events::OrderBook(v) => WebsocketEvent::OrderBook(v),
-                Events::DepthOrderBookEvent(v) => WebsocketEvent::DepthOrderBook(v),
-            };
+        if let Some(action) = parse_msg(msg)? {
             (self.handler)(action)?;
         }
         Ok(())
@@ -153,9 +268,17 @@ impl<'a> WebSockets<'a> {
     pub fn event_loop(&mut self, running: &AtomicBool) -> Result<()> {
         while running.load(Ordering::Relaxed) {
             if let Some(ref mut socket) = self.socket {
-                let message = socket.0.read_message()?;
+                let message = match socket.0.read_message() {
+                    Ok(message) => message,
+                    Err(e) if self.reconnect.is_none() => return Err(e.into()),
+                    Err(_) => {
+                        self.reconnect(running)?;
+                        continue;
+                    }
+                };
                 match message {
                     Message::Text(msg) => {
+                        self.stats.messages_received += 1;
                         if let Err(e) = self.handle_msg(&msg) {
                             bail!(format!("Error on handling stream message: {}", e));
                         }
@@ -164,10 +287,114 @@ impl<'a> WebSockets<'a> {
                         socket.0.write_message(Message::Pong(payload)).unwrap();
                     }
                     Message::Pong(_) | Message::Binary(_) | Message::Frame(_) => (),
-                    Message::Close(e) => bail!(format!("Disconnected {:?}", e)),
+                    Message::Close(e) => {
+                        if self.reconnect.is_none() {
+                            bail!(format!("Disconnected {:?}", e));
+                        }
+                        self.reconnect(running)?;
+                    }
                 }
             }
         }
         Ok(())
     }
 }
+
+/// Decodes a raw stream message, unwrapping combined stream payloads.
+///
+/// Returns `None` for messages that do not map to a known `WebsocketEvent`.
+pub(crate) fn parse_msg(msg: &str) -> Result<Option<WebsocketEvent>> {
+    let value: serde_json::Value = serde_json::from_str(msg)?;
+
+    if let Some(data) = value.get("data") {
+        return parse_msg(&data.to_string());
+    }
+
+    if let Ok(events) = serde_json::from_value::<Events>(value) {
+        let action = match events {
+            Events::DayTickerEventAll(v) => WebsocketEvent::DayTickerAll(v),
+            Events::WindowTickerEventAll(v) => WebsocketEvent::WindowTickerAll(v),
+            Events::BookTickerEvent(v) => WebsocketEvent::BookTicker(v),
+            Events::BalanceUpdateEvent(v) => WebsocketEvent::BalanceUpdate(v),
+            Events::AccountUpdateEvent(v) => WebsocketEvent::AccountUpdate(v),
+            Events::OrderTradeEvent(v) => WebsocketEvent::OrderTrade(v),
+            Events::AggrTradesEvent(v) => WebsocketEvent::AggrTrades(v),
+            Events::TradeEvent(v) => WebsocketEvent::Trade(v),
+            Events::DayTickerEvent(v) => WebsocketEvent::DayTicker(v),
+            Events::WindowTickerEvent(v) => WebsocketEvent::WindowTicker(v),
+            Events::KlineEvent(v) => WebsocketEvent::Kline(v),
+            Events::OrderBook(v) => WebsocketEvent::OrderBook(v),
+            Events::DepthOrderBookEvent(v) => WebsocketEvent::DepthOrderBook(v),
+            // Any other event type would match its two fields
+            Events::UserDataStreamExpiredEvent(v) if v.event_type == "listenKeyExpired" => {
+                WebsocketEvent::UserDataStreamExpiredEvent(v)
+            }
+            Events::UserDataStreamExpiredEvent(_) => return Ok(None),
+        };
+        return Ok(Some(action));
+    }
+    Ok(None)
+}
+
+pub(crate) fn connect_socket(wss: &str) -> Result<Socket> {
+    let url = Url::parse(wss)?;
+    match connect(url) {
+        Ok(answer) => Ok(answer),
+        Err(e) => bail!(format!("Error during handshake {}", e)),
+    }
+}
+
+/// Retries `connect` with backoff until it succeeds, `running` is cleared or attempts run out.
+///
+/// Returns the new socket with the number of attempts it took, or `None` when stopped.
+pub(crate) fn reconnect_with_backoff<S>(
+    reconnect: &ReconnectConfig, stats: &mut ConnectionStats, running: &AtomicBool,
+    mut connect: impl FnMut() -> Result<S>,
+) -> Result<Option<(S, u32)>> {
+    stats.last_disconnected_at = Some(SystemTime::now());
+
+    let mut attempt = 0;
+    loop {
+        attempt += 1;
+        if !sleep_while_running(reconnect.backoff(attempt), running) {
+            return Ok(None);
+        }
+        match connect() {
+            Ok(socket) => {
+                stats.connects += 1;
+                stats.reconnects += 1;
+                stats.last_connected_at = Some(SystemTime::now());
+                return Ok(Some((socket, attempt)));
+            }
+            Err(e) => {
+                stats.failed_attempts += 1;
+                if reconnect.max_attempts.map_or(false, |max| attempt >= max) {
+                    bail!(format!(
+                        "Reconnect failed after {} attempts: {}",
+                        attempt, e
+                    ));
+                }
+            }
+        }
+    }
+}
+
+// Sleeps in short slices so a cleared `running` flag is noticed promptly
+pub(crate) fn sleep_while_running(duration: Duration, running: &AtomicBool) -> bool {
+    let slice = Duration::from_millis(50);
+    let mut remaining = duration;
+    while running.load(Ordering::Relaxed) {
+        if remaining.is_zero() {
+            return true;
+        }
+        let step = remaining.min(slice);
+        thread::sleep(step);
+        remaining -= step;
+    }
+    false
+}
+
+fn random_fraction() -> f64 {
+    let random = RandomState::new().build_hasher().finish();
+    (random >> 11) as f64 / (1u64 << 53) as f64
+}
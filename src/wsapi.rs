@@ -0,0 +1,381 @@
+use crate::account::{
+    build_cancel_replace_order, build_order, CancelReplaceRequest, OrderRequest, OrderSide,
+    OrderType, TimeInForce,
+};
+use crate::config::Config;
+use crate::errors::{BinanceContentError, ErrorKind, Result};
+use crate::model::{
+    AccountInformation, AveragePrice, CancelReplaceData, CancelReplaceFailure,
+    CancelReplaceResponse, Empty, IntoNumber, Number, Order, OrderBook, OrderCanceled, ServerTime,
+    SessionStatus, SymbolPrice, Transaction,
+};
+use crate::signer::Signer;
+use crate::timesync::TimeSync;
+use crate::util::build_request;
+use crate::websockets::{connect_socket, Socket};
+use error_chain::bail;
+use reqwest::header::HeaderMap;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde_json::{json, Map, Value};
+
+use std::collections::{BTreeMap, HashMap};
+use std::io;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use tungstenite::stream::MaybeTlsStream;
+use tungstenite::Message;
+
+/// Client for the spot WebSocket API, placing orders and querying the exchange over a single
+/// connection instead of one HTTP round trip per request.
+///
+/// Requests block until the response with the same id arrives. Use `send` and `response` to
+/// have several requests in flight at once.
+///
+/// ```no_run
+/// use binance::account::*;
+/// use binance::config::*;
+/// use binance::signer::*;
+/// use binance::wsapi::*;
+///
+/// let pem = std::fs::read_to_string("private_key.pem").unwrap();
+/// let config = Config::default().set_signer(Signer::ed25519_from_pem(&pem).unwrap());
+/// let mut ws_api = WsApi::connect_with_config(Some("api_key".into()), None, &config).unwrap();
+///
+/// // Ed25519 keys can authenticate the session once instead of signing every request
+/// ws_api.logon().unwrap();
+/// let transaction = ws_api
+///     .place_order("BNBUSDT", 1, 300.0, None, OrderSide::Buy, OrderType::Limit, TimeInForce::GTC, None)
+///     .unwrap();
+/// println!("{:?}", ws_api.order_status("BNBUSDT", transaction.order_id));
+/// ```
+pub struct WsApi {
+    socket: Socket,
+    api_key: String,
+    secret_key: String,
+    signer: Signer,
+    time_sync: TimeSync,
+    recv_window: u64,
+    timeout: Duration,
+    logged_on: bool,
+    next_id: u64,
+    responses: HashMap<String, WsApiResponse>,
+}
+
+#[derive(Debug, Deserialize)]
+struct WsApiResponse {
+    id: Value,
+    status: u16,
+    result: Option<Value>,
+    error: Option<WsApiError>,
+}
+
+#[derive(Debug, Deserialize)]
+struct WsApiError {
+    code: i16,
+    msg: String,
+    data: Option<Value>,
+}
+
+impl WsApi {
+    pub fn connect(api_key: Option<String>, secret_key: Option<String>) -> Result<WsApi> {
+        Self::connect_with_config(api_key, secret_key, &Config::default())
+    }
+
+    pub fn connect_with_config(
+        api_key: Option<String>, secret_key: Option<String>, config: &Config,
+    ) -> Result<WsApi> {
+        Ok(WsApi {
+            socket: connect_socket(&config.ws_api_endpoint)?,
+            api_key: api_key.unwrap_or_default(),
+            secret_key: secret_key.unwrap_or_default(),
+            signer: config.signer.clone(),
+            time_sync: config.time_sync.clone(),
+            recv_window: config.recv_window,
+            timeout: config.ws_api_timeout,
+            logged_on: false,
+            next_id: 0,
+            responses: HashMap::new(),
+        })
+    }
+
+    /// Authenticates the connection with the API key, so signed requests no longer carry a
+    /// signature. Requires an Ed25519 signer.
+    pub fn logon(&mut self) -> Result<SessionStatus> {
+        if !matches!(self.signer, Signer::Ed25519(_)) {
+            bail!("session.logon requires an Ed25519 key");
+        }
+        let status = self.request("session.logon", BTreeMap::new(), true)?;
+        self.logged_on = true;
+        Ok(status)
+    }
+
+    pub fn session_status(&mut self) -> Result<SessionStatus> {
+        self.request("session.status", BTreeMap::new(), false)
+    }
+
+    pub fn logout(&mut self) -> Result<SessionStatus> {
+        let status = self.request("session.logout", BTreeMap::new(), false)?;
+        self.logged_on = false;
+        Ok(status)
+    }
+
+    pub fn ping(&mut self) -> Result<()> {
+        self.request::<Empty>("ping", BTreeMap::new(), false)
+            .map(|_| ())
+    }
+
+    pub fn server_time(&mut self) -> Result<ServerTime> {
+        self.request("time", BTreeMap::new(), false)
+    }
+
+    // Order book at the default depth limit (100)
+    pub fn depth<S>(&mut self, symbol: S, limit: Option<u64>) -> Result<OrderBook>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        if let Some(limit) = limit {
+            parameters.insert("limit".into(), limit.to_string());
+        }
+        self.request("depth", parameters, false)
+    }
+
+    // Latest price for ONE symbol
+    pub fn ticker_price<S>(&mut self, symbol: S) -> Result<SymbolPrice>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        self.request("ticker.price", parameters, false)
+    }
+
+    // Average price for ONE symbol
+    pub fn avg_price<S>(&mut self, symbol: S) -> Result<AveragePrice>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        self.request("avgPrice", parameters, false)
+    }
+
+    // Account Information
+    pub fn account_status(&mut self) -> Result<AccountInformation> {
+        self.request("account.status", BTreeMap::new(), true)
+    }
+
+    /// Place an order, with the same parameters as `Account::custom_order`
+    #[allow(clippy::too_many_arguments)]
+    pub fn place_order<S, F>(
+        &mut self, symbol: S, qty: F, price: impl IntoNumber, stop_price: Option<Number>,
+        order_side: OrderSide, order_type: OrderType, time_in_force: TimeInForce,
+        new_client_order_id: Option<String>,
+    ) -> Result<Transaction>
+    where
+        S: Into<String>,
+        F: IntoNumber,
+    {
+        let order = OrderRequest {
+            symbol: symbol.into(),
+            qty: qty.try_into_number()?,
+            price: price.try_into_number()?,
+            stop_price,
+            order_side,
+            order_type,
+            time_in_force,
+            new_client_order_id,
+        };
+        let mut parameters = build_order(order);
+        parameters.insert("newOrderRespType".into(), "FULL".into());
+        self.request("order.place", parameters, true)
+    }
+
+    // Check an order's status
+    pub fn order_status<S>(&mut self, symbol: S, order_id: u64) -> Result<Order>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("orderId".into(), order_id.to_string());
+        self.request("order.status", parameters, true)
+    }
+
+    pub fn cancel_order<S>(&mut self, symbol: S, order_id: u64) -> Result<OrderCanceled>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("orderId".into(), order_id.to_string());
+        self.request("order.cancel", parameters, true)
+    }
+
+    /// Cancel an order and place a new one in a single request
+    pub fn cancel_replace_order(
+        &mut self, order: CancelReplaceRequest,
+    ) -> Result<CancelReplaceResponse> {
+        let parameters = build_cancel_replace_order(order);
+        self.request("order.cancelReplace", parameters, true)
+    }
+
+    /// Sends `method` and waits for its response.
+    ///
+    /// `signed` requests get a timestamp, and the API key and a signature unless the session
+    /// is logged on.
+    pub fn request<T: DeserializeOwned>(
+        &mut self, method: &str, parameters: BTreeMap<String, String>, signed: bool,
+    ) -> Result<T> {
+        let id = self.send(method, parameters, signed)?;
+        self.response(&id)
+    }
+
+    /// Sends `method` without waiting, returning the request id to pass to `response`.
+    pub fn send(
+        &mut self, method: &str, mut parameters: BTreeMap<String, String>, signed: bool,
+    ) -> Result<String> {
+        if signed {
+            self.sign(&mut parameters)?;
+        }
+        self.next_id += 1;
+        let id = self.next_id.to_string();
+
+        let mut request = Map::new();
+        request.insert("id".into(), json!(id));
+        request.insert("method".into(), json!(method));
+        if !parameters.is_empty() {
+            request.insert("params".into(), json!(parameters));
+        }
+        self.socket
+            .0
+            .send(Message::Text(Value::Object(request).to_string()))?;
+        Ok(id)
+    }
+
+    /// Waits for the response of the request `id`, keeping responses to other requests that
+    /// arrive meanwhile. Fails once the `ws_api_timeout` of the config runs out.
+    pub fn response<T: DeserializeOwned>(&mut self, id: &str) -> Result<T> {
+        let deadline = Instant::now() + self.timeout;
+        loop {
+            if let Some(response) = self.responses.remove(id) {
+                return handle_response(response);
+            }
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                bail!("No response to request {} within {:?}", id, self.timeout);
+            }
+            set_read_timeout(&self.socket, remaining)?;
+            let message = match self.socket.0.read() {
+                Ok(message) => message,
+                // The frame read so far is kept for the next read
+                Err(tungstenite::Error::Io(e))
+                    if matches!(
+                        e.kind(),
+                        io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut
+                    ) =>
+                {
+                    continue
+                }
+                Err(e) => return Err(e.into()),
+            };
+            match message {
+                Message::Text(msg) => {
+                    let response: WsApiResponse = serde_json::from_str(&msg)?;
+                    let response_id = match &response.id {
+                        Value::String(id) => id.clone(),
+                        id => id.to_string(),
+                    };
+                    self.responses.insert(response_id, response);
+                }
+                Message::Ping(payload) => {
+                    self.socket.0.send(Message::Pong(payload))?;
+                }
+                Message::Pong(_) | Message::Binary(_) | Message::Frame(_) => (),
+                Message::Close(e) => {
+                    bail!(format!("Disconnected {:?}", e));
+                }
+            }
+        }
+    }
+
+    pub fn disconnect(&mut self) -> Result<()> {
+        self.socket.0.close(None)?;
+        Ok(())
+    }
+
+    fn sign(&mut self, parameters: &mut BTreeMap<String, String>) -> Result<()> {
+        if self.time_sync.needs_sync() {
+            // Measured over this connection with the `time` method
+            let time_sync = self.time_sync.clone();
+            time_sync.sync(|| self.server_time())?;
+        }
+        if self.recv_window > 0 {
+            parameters.insert("recvWindow".into(), self.recv_window.to_string());
+        }
+        parameters.insert("timestamp".into(), self.time_sync.timestamp()?.to_string());
+        if self.logged_on {
+            return Ok(());
+        }
+
+        parameters.insert("apiKey".into(), self.api_key.clone());
+        let payload = build_request(parameters.clone());
+        let signature = self.signer.sign(&self.secret_key, &payload);
+        parameters.insert("signature".into(), signature);
+        Ok(())
+    }
+}
+
+// Bounds how long the next read of the socket blocks
+fn set_read_timeout(socket: &Socket, timeout: Duration) -> Result<()> {
+    let stream = match socket.0.get_ref() {
+        MaybeTlsStream::Plain(stream) => stream,
+        MaybeTlsStream::NativeTls(stream) => stream.get_ref(),
+        _ => return Ok(()),
+    };
+    stream.set_read_timeout(Some(timeout))?;
+    Ok(())
+}
+
+fn handle_response<T: DeserializeOwned>(response: WsApiResponse) -> Result<T> {
+    if let Some(error) = response.error {
+        let status = response.status;
+        return Err(match status {
+            429 | 418 => {
+                let retry_after = error
+                    .data
+                    .as_ref()
+                    .and_then(|data| data.get("retryAfter"))
+                    .and_then(Value::as_u64)
+                    .and_then(|retry_after| {
+                        let now = SystemTime::now().duration_since(UNIX_EPOCH).ok()?;
+                        Duration::from_millis(retry_after).checked_sub(now)
+                    });
+                ErrorKind::RateLimited(status, retry_after, HeaderMap::new()).into()
+            }
+            500..=599 => ErrorKind::ServerError(status, None, HeaderMap::new()).into(),
+            // Failed cancel-replaces also report what happened to either order
+            _ => match error
+                .data
+                .and_then(|data| serde_json::from_value::<CancelReplaceData>(data).ok())
+            {
+                Some(data) => ErrorKind::CancelReplaceFailed(CancelReplaceFailure {
+                    code: error.code,
+                    msg: error.msg,
+                    data,
+                })
+                .into(),
+                None => ErrorKind::BinanceError(BinanceContentError {
+                    code: error.code,
+                    msg: error.msg,
+                })
+                .into(),
+            },
+        });
+    }
+
+    Ok(serde_json::from_value(
+        response.result.unwrap_or(Value::Null),
+    )?)
+}
@@ -105,7 +105,7 @@ mod tests {
         assert_eq!(open_order.order_id, 1);
         assert_eq!(open_order.order_list_id, -1);
         assert_eq!(open_order.client_order_id, "myOrder1");
-        assert!(approx_eq!(f64, open_order.price, 0.1, ulps = 2));
+        assert_eq!(open_order.price, 0.1.into_number());
         assert_eq!(open_order.orig_qty, "1.0");
         assert_eq!(open_order.executed_qty, "0.0");
         assert_eq!(open_order.cummulative_quote_qty, "0.0");
@@ -113,7 +113,7 @@ mod tests {
         assert_eq!(open_order.time_in_force, "GTC"); //Migrate to TimeInForce enum
         assert_eq!(open_order.type_name, "LIMIT");
         assert_eq!(open_order.side, "BUY");
-        assert!(approx_eq!(f64, open_order.stop_price, 0.0, ulps = 2));
+        assert_eq!(open_order.stop_price, 0.0.into_number());
         assert_eq!(open_order.iceberg_qty, "0.0");
         assert_eq!(open_order.time, 1499827319559);
         assert_eq!(open_order.update_time, 1499827319559);
@@ -147,7 +147,7 @@ mod tests {
         assert_eq!(open_order.order_id, 1);
         assert_eq!(open_order.order_list_id, -1);
         assert_eq!(open_order.client_order_id, "myOrder1");
-        assert!(approx_eq!(f64, open_order.price, 0.1, ulps = 2));
+        assert_eq!(open_order.price, 0.1.into_number());
         assert_eq!(open_order.orig_qty, "1.0");
         assert_eq!(open_order.executed_qty, "0.0");
         assert_eq!(open_order.cummulative_quote_qty, "0.0");
@@ -155,7 +155,7 @@ mod tests {
         assert_eq!(open_order.time_in_force, "GTC"); //Migrate to TimeInForce enum
         assert_eq!(open_order.type_name, "LIMIT");
         assert_eq!(open_order.side, "BUY");
-        assert!(approx_eq!(f64, open_order.stop_price, 0.0, ulps = 2));
+        assert_eq!(open_order.stop_price, 0.0.into_number());
         assert_eq!(open_order.iceberg_qty, "0.0");
         assert_eq!(open_order.time, 1499827319559);
         assert_eq!(open_order.update_time, 1499827319559);
@@ -236,7 +236,7 @@ mod tests {
         assert_eq!(order_status.order_id, 1);
         assert_eq!(order_status.order_list_id, -1);
         assert_eq!(order_status.client_order_id, "myOrder1");
-        assert!(approx_eq!(f64, order_status.price, 0.1, ulps = 2));
+        assert_eq!(order_status.price, 0.1.into_number());
         assert_eq!(order_status.orig_qty, "1.0");
         assert_eq!(order_status.executed_qty, "0.0");
         assert_eq!(order_status.cummulative_quote_qty, "0.0");
@@ -244,7 +244,7 @@ mod tests {
         assert_eq!(order_status.time_in_force, "GTC"); //Migrate to TimeInForce enum
         assert_eq!(order_status.type_name, "LIMIT");
         assert_eq!(order_status.side, "BUY");
-        assert!(approx_eq!(f64, order_status.stop_price, 0.0, ulps = 2));
+        assert_eq!(order_status.stop_price, 0.0.into_number());
         assert_eq!(order_status.iceberg_qty, "0.0");
         assert_eq!(order_status.time, 1499827319559);
         assert_eq!(order_status.update_time, 1499827319559);
@@ -297,21 +297,37 @@ mod tests {
         assert_eq!(transaction.order_list_id.unwrap(), -1);
         assert_eq!(transaction.client_order_id, "6gCrw2kRUAF9CvJDGP16IP");
         assert_eq!(transaction.transact_time, 1507725176595);
-        assert!(approx_eq!(f64, transaction.price, 0.1, ulps = 2));
-        assert!(approx_eq!(f64, transaction.orig_qty, 1.0, ulps = 2));
-        assert!(approx_eq!(f64, transaction.executed_qty, 1.0, ulps = 2));
-        assert!(approx_eq!(
-            f64,
-            transaction.cummulative_quote_qty,
-            0.0,
-            ulps = 2
-        ));
+        assert_eq!(transaction.price, 0.1.into_number());
+        assert_eq!(transaction.orig_qty, 1.0.into_number());
+        assert_eq!(transaction.executed_qty, 1.0.into_number());
+        assert_eq!(transaction.cummulative_quote_qty, 0.0.into_number());
         assert_eq!(transaction.status, "NEW");
         assert_eq!(transaction.time_in_force, "GTC"); //Migrate to TimeInForce enum
         assert_eq!(transaction.type_name, "LIMIT");
         assert_eq!(transaction.side, "BUY");
     }
 
+    #[test]
+    fn limit_buy_non_finite_price() {
+        let mut server = Server::new();
+        let mock_limit_buy = server
+            .mock("POST", "/api/v3/order")
+            .match_query(Matcher::Any)
+            .expect(0)
+            .create();
+
+        let config = Config::default().set_rest_api_endpoint(server.url());
+        let account: Account = Binance::new_with_config(None, None, &config);
+
+        // Rejected instead of sent as a market-priced or zero-priced order
+        assert!(account.limit_buy("LTCBTC", 1, f64::NAN).is_err());
+        assert!(account.limit_buy("LTCBTC", 1, f64::INFINITY).is_err());
+        assert!(account.limit_buy("LTCBTC", f64::NEG_INFINITY, 0.1).is_err());
+        assert!(1.5.try_into_number().is_ok());
+
+        mock_limit_buy.assert();
+    }
+
     #[test]
     fn test_limit_buy() {
         let mut server = Server::new();
@@ -354,15 +370,10 @@ mod tests {
         assert_eq!(transaction.order_list_id.unwrap(), -1);
         assert_eq!(transaction.client_order_id, "6gCrw2kRUAF9CvJDGP16IP");
         assert_eq!(transaction.transact_time, 1507725176595);
-        assert!(approx_eq!(f64, transaction.price, 0.1, ulps = 2));
-        assert!(approx_eq!(f64, transaction.orig_qty, 1.0, ulps = 2));
-        assert!(approx_eq!(f64, transaction.executed_qty, 1.0, ulps = 2));
-        assert!(approx_eq!(
-            f64,
-            transaction.cummulative_quote_qty,
-            0.0,
-            ulps = 2
-        ));
+        assert_eq!(transaction.price, 0.1.into_number());
+        assert_eq!(transaction.orig_qty, 1.0.into_number());
+        assert_eq!(transaction.executed_qty, 1.0.into_number());
+        assert_eq!(transaction.cummulative_quote_qty, 0.0.into_number());
         assert_eq!(transaction.status, "NEW");
         assert_eq!(transaction.time_in_force, "GTC"); //Migrate to TimeInForce enum
         assert_eq!(transaction.type_name, "LIMIT");
@@ -415,15 +426,10 @@ mod tests {
         assert_eq!(transaction.order_list_id.unwrap(), -1);
         assert_eq!(transaction.client_order_id, "6gCrw2kRUAF9CvJDGP16IP");
         assert_eq!(transaction.transact_time, 1507725176595);
-        assert!(approx_eq!(f64, transaction.price, 0.1, ulps = 2));
-        assert!(approx_eq!(f64, transaction.orig_qty, 1.0, ulps = 2));
-        assert!(approx_eq!(f64, transaction.executed_qty, 1.0, ulps = 2));
-        assert!(approx_eq!(
-            f64,
-            transaction.cummulative_quote_qty,
-            0.0,
-            ulps = 2
-        ));
+        assert_eq!(transaction.price, 0.1.into_number());
+        assert_eq!(transaction.orig_qty, 1.0.into_number());
+        assert_eq!(transaction.executed_qty, 1.0.into_number());
+        assert_eq!(transaction.cummulative_quote_qty, 0.0.into_number());
         assert_eq!(transaction.status, "NEW");
         assert_eq!(transaction.time_in_force, "GTC"); //Migrate to TimeInForce enum
         assert_eq!(transaction.type_name, "MARKET");
@@ -525,15 +531,10 @@ mod tests {
         assert_eq!(transaction.order_list_id.unwrap(), -1);
         assert_eq!(transaction.client_order_id, "6gCrw2kRUAF9CvJDGP16IP");
         assert_eq!(transaction.transact_time, 1507725176595);
-        assert!(approx_eq!(f64, transaction.price, 0.1, ulps = 2));
-        assert!(approx_eq!(f64, transaction.orig_qty, 1.0, ulps = 2));
-        assert!(approx_eq!(f64, transaction.executed_qty, 1.0, ulps = 2));
-        assert!(approx_eq!(
-            f64,
-            transaction.cummulative_quote_qty,
-            0.0,
-            ulps = 2
-        ));
+        assert_eq!(transaction.price, 0.1.into_number());
+        assert_eq!(transaction.orig_qty, 1.0.into_number());
+        assert_eq!(transaction.executed_qty, 1.0.into_number());
+        assert_eq!(transaction.cummulative_quote_qty, 0.0.into_number());
         assert_eq!(transaction.status, "NEW");
         assert_eq!(transaction.time_in_force, "GTC"); //Migrate to TimeInForce enum
         assert_eq!(transaction.type_name, "MARKET");
@@ -633,16 +634,11 @@ mod tests {
         assert_eq!(transaction.order_list_id.unwrap(), -1);
         assert_eq!(transaction.client_order_id, "6gCrw2kRUAF9CvJDGP16IP");
         assert_eq!(transaction.transact_time, 1507725176595);
-        assert!(approx_eq!(f64, transaction.price, 0.1, ulps = 2));
-        assert!(approx_eq!(f64, transaction.orig_qty, 1.0, ulps = 2));
-        assert!(approx_eq!(f64, transaction.executed_qty, 1.0, ulps = 2));
-        assert!(approx_eq!(
-            f64,
-            transaction.cummulative_quote_qty,
-            0.0,
-            ulps = 2
-        ));
-        assert!(approx_eq!(f64, transaction.stop_price, 0.09, ulps = 2));
+        assert_eq!(transaction.price, 0.1.into_number());
+        assert_eq!(transaction.orig_qty, 1.0.into_number());
+        assert_eq!(transaction.executed_qty, 1.0.into_number());
+        assert_eq!(transaction.cummulative_quote_qty, 0.0.into_number());
+        assert_eq!(transaction.stop_price, 0.09.into_number());
         assert_eq!(transaction.status, "NEW");
         assert_eq!(transaction.time_in_force, "GTC"); //Migrate to TimeInForce enum
         assert_eq!(transaction.type_name, "STOP_LOSS_LIMIT");
@@ -695,16 +691,11 @@ mod tests {
         assert_eq!(transaction.order_list_id.unwrap(), -1);
         assert_eq!(transaction.client_order_id, "6gCrw2kRUAF9CvJDGP16IP");
         assert_eq!(transaction.transact_time, 1507725176595);
-        assert!(approx_eq!(f64, transaction.price, 0.1, ulps = 2));
-        assert!(approx_eq!(f64, transaction.orig_qty, 1.0, ulps = 2));
-        assert!(approx_eq!(f64, transaction.executed_qty, 1.0, ulps = 2));
-        assert!(approx_eq!(
-            f64,
-            transaction.cummulative_quote_qty,
-            0.0,
-            ulps = 2
-        ));
-        assert!(approx_eq!(f64, transaction.stop_price, 0.09, ulps = 2));
+        assert_eq!(transaction.price, 0.1.into_number());
+        assert_eq!(transaction.orig_qty, 1.0.into_number());
+        assert_eq!(transaction.executed_qty, 1.0.into_number());
+        assert_eq!(transaction.cummulative_quote_qty, 0.0.into_number());
+        assert_eq!(transaction.stop_price, 0.09.into_number());
         assert_eq!(transaction.status, "NEW");
         assert_eq!(transaction.time_in_force, "GTC"); //Migrate to TimeInForce enum
         assert_eq!(transaction.type_name, "STOP_LOSS_LIMIT");
@@ -766,16 +757,11 @@ mod tests {
         assert_eq!(transaction.order_list_id.unwrap(), -1);
         assert_eq!(transaction.client_order_id, "6gCrw2kRUAF9CvJDGP16IP");
         assert_eq!(transaction.transact_time, 1507725176595);
-        assert!(approx_eq!(f64, transaction.price, 0.1, ulps = 2));
-        assert!(approx_eq!(f64, transaction.orig_qty, 1.0, ulps = 2));
-        assert!(approx_eq!(f64, transaction.executed_qty, 1.0, ulps = 2));
-        assert!(approx_eq!(
-            f64,
-            transaction.cummulative_quote_qty,
-            0.0,
-            ulps = 2
-        ));
-        assert!(approx_eq!(f64, transaction.stop_price, 0.09, ulps = 2));
+        assert_eq!(transaction.price, 0.1.into_number());
+        assert_eq!(transaction.orig_qty, 1.0.into_number());
+        assert_eq!(transaction.executed_qty, 1.0.into_number());
+        assert_eq!(transaction.cummulative_quote_qty, 0.0.into_number());
+        assert_eq!(transaction.stop_price, 0.09.into_number());
         assert_eq!(transaction.status, "NEW");
         assert_eq!(transaction.time_in_force, "GTC"); //Migrate to TimeInForce enum
         assert_eq!(transaction.type_name, "STOP_LOSS_LIMIT");
@@ -861,6 +847,148 @@ mod tests {
         mock_test_cancel_order.assert();
     }
 
+    #[test]
+    fn oco_order() {
+        let mut server = Server::new();
+        let mock_oco_order = server
+            .mock("POST", "/api/v3/order/oco")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("listClientOrderId=myList&price=0.036435&quantity=0.624363&recvWindow=1234&side=BUY&stopLimitPrice=0.97&stopLimitTimeInForce=GTC&stopPrice=0.960664&symbol=LTCBTC&timestamp=\\d+".into()))
+            .with_body_from_file("tests/mocks/account/oco_order.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: Account = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let order_list = account
+            .oco_order(OcoOrderRequest {
+                symbol: "LTCBTC".into(),
+                side: OrderSide::Buy,
+                qty: 0.624363.into_number(),
+                price: 0.036435.into_number(),
+                stop_price: 0.960664.into_number(),
+                stop_limit_price: Some(0.97.into_number()),
+                stop_limit_time_in_force: None,
+                list_client_order_id: Some("myList".into()),
+                limit_client_order_id: None,
+                stop_client_order_id: None,
+            })
+            .unwrap();
+
+        mock_oco_order.assert();
+
+        assert_eq!(order_list.order_list_id, 0);
+        assert_eq!(order_list.contingency_type, "OCO");
+        assert_eq!(order_list.list_status_type, "EXEC_STARTED");
+        assert_eq!(order_list.list_order_status, "EXECUTING");
+        assert_eq!(order_list.transaction_time, 1563417480525);
+        assert_eq!(order_list.orders.len(), 2);
+        assert_eq!(order_list.orders[1].order_id, 3);
+        assert_eq!(order_list.order_reports.len(), 2);
+
+        let stop_loss = &order_list.order_reports[0];
+        assert_eq!(stop_loss.type_name, "STOP_LOSS");
+        assert_eq!(stop_loss.transact_time, Some(1563417480525));
+        assert_eq!(stop_loss.stop_price, 0.960664.into_number());
+        assert_eq!(stop_loss.orig_qty, 0.624363.into_number());
+
+        let limit_maker = &order_list.order_reports[1];
+        assert_eq!(limit_maker.type_name, "LIMIT_MAKER");
+        assert_eq!(limit_maker.price, 0.036435.into_number());
+        assert_eq!(limit_maker.stop_price, 0.0.into_number());
+    }
+
+    #[test]
+    fn cancel_order_list() {
+        let mut server = Server::new();
+        let mock_cancel_order_list = server
+            .mock("DELETE", "/api/v3/orderList")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "orderListId=0&recvWindow=1234&symbol=LTCBTC&timestamp=\\d+".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/cancel_order_list.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: Account = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let order_list = account.cancel_order_list("LTCBTC", 0).unwrap();
+
+        mock_cancel_order_list.assert();
+
+        assert_eq!(order_list.list_status_type, "ALL_DONE");
+        assert_eq!(order_list.order_reports.len(), 2);
+        assert_eq!(order_list.order_reports[0].status, "CANCELED");
+        assert_eq!(
+            order_list.order_reports[0].orig_client_order_id.as_deref(),
+            Some("pO9ufTiFGg3nw2fOdgeOXa")
+        );
+        assert_eq!(order_list.order_reports[0].transact_time, None);
+    }
+
+    #[test]
+    fn order_list_status() {
+        let mut server = Server::new();
+        let mock_order_list_status = server
+            .mock("GET", "/api/v3/orderList")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "orderListId=27&recvWindow=1234&timestamp=\\d+".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/order_list_status.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: Account = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let order_list = account.order_list_status(27).unwrap();
+
+        mock_order_list_status.assert();
+
+        assert_eq!(order_list.order_list_id, 27);
+        assert_eq!(order_list.list_client_order_id, "h2USkA5YQpaXHPIrkd96xE");
+        assert_eq!(
+            order_list.orders[0].client_order_id,
+            "qD1gy3kc3Gx0rihm9Y3xwS"
+        );
+        assert!(order_list.order_reports.is_empty());
+    }
+
+    #[test]
+    fn get_all_order_lists() {
+        let mut server = Server::new();
+        let mock_get_all_order_lists = server
+            .mock("GET", "/api/v3/allOrderList")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "fromId=28&limit=10&recvWindow=1234&timestamp=\\d+".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/all_order_lists.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: Account = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let order_lists = account
+            .get_all_order_lists(Some(28), None, None, Some(10))
+            .unwrap();
+
+        mock_get_all_order_lists.assert();
+
+        assert_eq!(order_lists.len(), 2);
+        assert_eq!(order_lists[0].order_list_id, 29);
+        assert_eq!(order_lists[1].list_order_status, "ALL_DONE");
+    }
+
     #[test]
     fn trade_history() {
         let mut server = Server::new();
@@ -887,8 +1015,8 @@ mod tests {
         let history: TradeHistory = histories[0].clone();
 
         assert_eq!(history.id, 28457);
-        assert!(approx_eq!(f64, history.price, 4.00000100, ulps = 2));
-        assert!(approx_eq!(f64, history.qty, 12.00000000, ulps = 2));
+        assert_eq!(history.price, 4.00000100.into_number());
+        assert_eq!(history.qty, 12.00000000.into_number());
         assert_eq!(history.commission, "10.10000000");
         assert_eq!(history.commission_asset, "BNB");
         assert_eq!(history.time, 1499865549590);
@@ -896,4 +1024,130 @@ mod tests {
         assert!(!history.is_maker);
         assert!(history.is_best_match);
     }
+
+    #[test]
+    fn cancel_replace_order() {
+        let mut server = Server::new();
+        let mock_cancel_replace = server
+            .mock("POST", "/api/v3/order/cancelReplace")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^cancelOrderId=9&cancelReplaceMode=STOP_ON_FAILURE&newOrderRespType=FULL&price=0.02&quantity=0.04&recvWindow=1234&side=BUY&symbol=BTCUSDT&timeInForce=GTC&timestamp=\\d+&type=LIMIT&signature=.*"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/account/cancel_replace.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: Account = Binance::new_with_config(None, None, &config);
+        let response = account
+            .cancel_replace_order(CancelReplaceRequest {
+                symbol: "BTCUSDT".into(),
+                cancel_replace_mode: CancelReplaceMode::StopOnFailure,
+                cancel_order_id: Some(9),
+                cancel_orig_client_order_id: None,
+                side: OrderSide::Buy,
+                order_type: OrderType::Limit,
+                time_in_force: None,
+                qty: 0.04.into_number(),
+                price: Some(0.02.into_number()),
+                stop_price: None,
+                new_client_order_id: None,
+            })
+            .unwrap();
+
+        mock_cancel_replace.assert();
+
+        assert_eq!(response.cancel_result, "SUCCESS");
+        assert_eq!(response.cancel_response.unwrap().order_id, Some(9));
+        assert_eq!(response.new_order_response.unwrap().order_id, 10);
+    }
+
+    #[test]
+    fn cancel_replace_order_partially_failed() {
+        let mut server = Server::new();
+        let mock_cancel_replace = server
+            .mock("POST", "/api/v3/order/cancelReplace")
+            .with_status(409)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("cancelReplaceMode=ALLOW_FAILURE".into()))
+            .with_body_from_file("tests/mocks/account/cancel_replace_partially_failed.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: Account = Binance::new_with_config(None, None, &config);
+        let error = account
+            .cancel_replace_order(CancelReplaceRequest {
+                symbol: "BTCUSDT".into(),
+                cancel_replace_mode: CancelReplaceMode::AllowFailure,
+                cancel_order_id: None,
+                cancel_orig_client_order_id: Some("86M8erehfExV8z2RC8Zo8k".into()),
+                side: OrderSide::Sell,
+                order_type: OrderType::Limit,
+                time_in_force: None,
+                qty: 10000.into_number(),
+                price: Some(0.006.into_number()),
+                stop_price: None,
+                new_client_order_id: None,
+            })
+            .unwrap_err();
+
+        mock_cancel_replace.assert();
+
+        assert_eq!(
+            error.binance_code(),
+            Some(binance::errors::BinanceErrorCode::CancelReplacePartiallyFailed)
+        );
+        match error.0 {
+            binance::errors::ErrorKind::CancelReplaceFailed(failure) => {
+                assert_eq!(failure.data.cancel_result, "SUCCESS");
+                assert_eq!(failure.data.new_order_result, "FAILURE");
+                match failure.data.cancel_response {
+                    Some(CancelReplaceResult::Success(canceled)) => {
+                        assert_eq!(canceled.order_id, Some(3))
+                    }
+                    other => panic!("Unexpected cancel response {:?}", other),
+                }
+                match failure.data.new_order_response {
+                    Some(CancelReplaceResult::Failure(error)) => assert_eq!(error.code, -2010),
+                    other => panic!("Unexpected new order response {:?}", other),
+                }
+            }
+            other => panic!("Unexpected error {:?}", other),
+        }
+    }
+
+    #[test]
+    fn amend_order_keep_priority() {
+        let mut server = Server::new();
+        let mock_amend = server
+            .mock("PUT", "/api/v3/order/amend/keepPriority")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^newQty=5&orderId=33&recvWindow=1234&symbol=BTCUSDT&timestamp=\\d+&signature=.*"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/account/amend_keep_priority.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: Account = Binance::new_with_config(None, None, &config);
+        let amended = account.amend_order_keep_priority("BTCUSDT", 33, 5).unwrap();
+
+        mock_amend.assert();
+
+        assert_eq!(amended.execution_id, 75);
+        assert_eq!(amended.amended_order.order_id, 33);
+        assert_eq!(amended.amended_order.qty, 5.into_number());
+        assert_eq!(
+            amended.amended_order.client_order_id,
+            "PFaq6hIHxqFENGfdtn4J6Q"
+        );
+    }
 }
@@ -0,0 +1,207 @@
+use binance::api::*;
+use binance::errors::Result;
+use binance::config::*;
+use binance::futures::account::*;
+use binance::futures::general::*;
+use binance::futures::market::*;
+use binance::futures::userstream::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{Matcher, Server};
+    use serde_json::Value;
+    use binance::futures::model::{CoinM, MarkPrices};
+    use binance::model::IntoNumber;
+
+    fn config(server: &Server) -> Config {
+        Config::default()
+            .set_coin_futures_rest_api_endpoint(server.url())
+            .set_recv_window(1234)
+    }
+
+    #[test]
+    fn exchange_info() {
+        let mut server = Server::new();
+        let mock = server
+            .mock("GET", "/dapi/v1/exchangeInfo")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("tests/mocks/coin_futures/exchange_info.json")
+            .create();
+
+        let general = FuturesGeneral::new_coin_futures_with_config(None, None, &config(&server));
+        let symbol = general.get_symbol_info("btcusd_200925").unwrap();
+
+        mock.assert();
+
+        assert_eq!(symbol.status, "TRADING");
+        assert_eq!(symbol.pair, Some("BTCUSD".into()));
+        assert_eq!(symbol.contract_type, Some("CURRENT_QUARTER".into()));
+        assert_eq!(symbol.delivery_date, Some(1601020800000));
+        assert_eq!(symbol.contract_size, Some(100));
+        assert_eq!(symbol.margin_asset, Some("BTC".into()));
+    }
+
+    #[test]
+    fn market_data() {
+        let mut server = Server::new();
+        let mock_24hr = server
+            .mock("GET", "/dapi/v1/ticker/24hr")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("symbol=BTCUSD_200925".into()))
+            .with_body_from_file("tests/mocks/coin_futures/ticker_24hr.json")
+            .create();
+        let mock_price = server
+            .mock("GET", "/dapi/v1/ticker/price")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("symbol=BTCUSD_200626".into()))
+            .with_body_from_file("tests/mocks/coin_futures/ticker_price.json")
+            .create();
+        let mock_premium_index = server
+            .mock("GET", "/dapi/v1/premiumIndex")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("tests/mocks/coin_futures/premium_index.json")
+            .create();
+
+        let market: FuturesMarket<CoinM> =
+            BinanceCoinFutures::new_coin_futures_with_config(None, None, &config(&server));
+
+        let stats = market.get_24h_price_stats("BTCUSD_200925").unwrap();
+        assert_eq!(stats.pair, "BTCUSD");
+        assert_eq!(stats.volume, 494109.into_number());
+        assert_eq!(stats.base_volume, 5192.94797687.into_number());
+
+        let price = market.get_price("BTCUSD_200626").unwrap();
+        assert_eq!(price.symbol, "BTCUSD_200626");
+        assert_eq!(price.price, 9647.8.into_number());
+
+        let MarkPrices::AllMarkPrices(mark_prices) = market.get_mark_prices().unwrap();
+        assert_eq!(mark_prices.len(), 2);
+        assert_eq!(mark_prices[0].last_funding_rate, 0.00071003.into_number());
+        // Delivery contracts have no funding
+        assert_eq!(mark_prices[1].last_funding_rate, 0.into_number());
+
+        mock_24hr.assert();
+        mock_price.assert();
+        mock_premium_index.assert();
+    }
+
+    #[test]
+    fn usdm_only_routes() {
+        let mut server = Server::new();
+        let mock = server
+            .mock("GET", Matcher::Any)
+            .match_query(Matcher::Any)
+            .expect(0)
+            .create();
+
+        let market: FuturesMarket<CoinM> =
+            BinanceCoinFutures::new_coin_futures_with_config(None, None, &config(&server));
+
+        // Refused instead of sent to a `/dapi` path that does not exist
+        assert!(market.get_funding_info().is_err());
+        assert!(market
+            .get_lvt_klines("BTCDOWNUSDT", "1h", 10, None, None)
+            .is_err());
+
+        // Also when the COIN-M route is built by the caller
+        let answer: Result<Value> = market
+            .client
+            .get(API::CoinFutures(Futures::IndexInfo), None);
+        assert!(answer.is_err());
+        assert!(String::try_from(API::CoinFutures(Futures::FundingInfo)).is_err());
+
+        mock.assert();
+    }
+
+    #[test]
+    fn limit_buy() {
+        let mut server = Server::new();
+        let mock = server
+            .mock("POST", "/dapi/v1/order")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "price=9000&quantity=10&recvWindow=1234&side=BUY&symbol=BTCUSD_200925&timeInForce=GTC&timestamp=\\d+&type=LIMIT&signature=.*"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/coin_futures/new_order.json")
+            .create();
+
+        let account: FuturesAccount<CoinM> =
+            BinanceCoinFutures::new_coin_futures_with_config(None, None, &config(&server));
+        let transaction = account
+            .limit_buy("BTCUSD_200925", 10, 9000, TimeInForce::GTC)
+            .unwrap();
+
+        mock.assert();
+
+        assert_eq!(transaction.order_id, 22542179);
+        assert_eq!(transaction.pair, "BTCUSD");
+        assert_eq!(transaction.orig_qty, 10.into_number());
+        assert_eq!(transaction.cum_base, 0.into_number());
+    }
+
+    #[test]
+    fn positions_and_balances() {
+        let mut server = Server::new();
+        let mock_position_risk = server
+            .mock("GET", "/dapi/v1/positionRisk")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^pair=BTCUSD&recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/coin_futures/position_risk.json")
+            .create();
+        let mock_account = server
+            .mock("GET", "/dapi/v1/account")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Any)
+            .with_body_from_file("tests/mocks/coin_futures/account.json")
+            .create();
+        let mock_balance = server
+            .mock("GET", "/dapi/v1/balance")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Any)
+            .with_body_from_file("tests/mocks/coin_futures/balance.json")
+            .create();
+
+        let account: FuturesAccount<CoinM> =
+            BinanceCoinFutures::new_coin_futures_with_config(None, None, &config(&server));
+
+        let positions = account.position_information("BTCUSD").unwrap();
+        assert_eq!(positions[0].max_qty, 50.into_number());
+        assert_eq!(positions[0].notional_value, 0.00848286.into_number());
+
+        let information = account.account_information().unwrap();
+        assert_eq!(information.assets[0].asset, "BTC");
+        assert_eq!(
+            information.assets[0].available_balance,
+            0.00241969.into_number()
+        );
+        assert_eq!(information.positions[0].max_qty, 50.into_number());
+
+        let balances = account.account_balance().unwrap();
+        assert_eq!(balances[0].withdraw_available, 0.0025.into_number());
+
+        mock_position_risk.assert();
+        mock_account.assert();
+        mock_balance.assert();
+    }
+
+    #[test]
+    fn listen_key() {
+        let mut server = Server::new();
+        let mock = server
+            .mock("POST", "/dapi/v1/listenKey")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body(r#"{"listenKey":"coin_futures_listen_key"}"#)
+            .create();
+
+        let user_stream =
+            FuturesUserStream::new_coin_futures_with_config(None, None, &config(&server));
+        let answer = user_stream.start().unwrap();
+
+        mock.assert();
+        assert_eq!(answer.listen_key, "coin_futures_listen_key");
+    }
+}
@@ -0,0 +1,105 @@
+use binance::api::*;
+use binance::config::*;
+use binance::errors::*;
+use binance::general::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::Server;
+    use std::time::Duration;
+
+    fn ping_error(status: usize, body: &str) -> Error {
+        let mut server = Server::new();
+        let mock_ping = server
+            .mock("GET", "/api/v3/ping")
+            .with_status(status)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_header("retry-after", "5")
+            .with_body(body)
+            .create();
+
+        let config = Config::default().set_rest_api_endpoint(server.url());
+        let general: General = Binance::new_with_config(None, None, &config);
+        let error = general.ping().unwrap_err();
+        mock_ping.assert();
+        error
+    }
+
+    #[test]
+    fn error_codes() {
+        assert_eq!(
+            BinanceErrorCode::from(-1021),
+            BinanceErrorCode::InvalidTimestamp
+        );
+        assert_eq!(
+            BinanceErrorCode::from(-2010),
+            BinanceErrorCode::NewOrderRejected
+        );
+        assert_eq!(BinanceErrorCode::from(-2011).code(), -2011);
+        assert_eq!(
+            BinanceErrorCode::from(-9999),
+            BinanceErrorCode::Other(-9999)
+        );
+        assert_eq!(BinanceErrorCode::Other(-9999).code(), -9999);
+
+        assert!(BinanceErrorCode::InvalidTimestamp.is_retryable());
+        assert!(!BinanceErrorCode::NewOrderRejected.is_retryable());
+    }
+
+    #[test]
+    fn unauthorized() {
+        let error = ping_error(
+            401,
+            r#"{"code":-2015,"msg":"Invalid API-key, IP, or permissions for action."}"#,
+        );
+
+        assert_eq!(error.binance_code(), Some(BinanceErrorCode::RejectedApiKey));
+        assert!(!error.is_retryable());
+        match error.0 {
+            ErrorKind::BinanceError(response) => assert_eq!(response.code, -2015),
+            other => panic!("Unexpected error {:?}", other),
+        }
+    }
+
+    #[test]
+    fn retryable_error_code() {
+        let error = ping_error(
+            400,
+            r#"{"code":-1021,"msg":"Timestamp for this request is outside of the recvWindow."}"#,
+        );
+
+        assert_eq!(
+            error.binance_code(),
+            Some(BinanceErrorCode::InvalidTimestamp)
+        );
+        assert!(error.is_retryable());
+    }
+
+    #[test]
+    fn server_error() {
+        let error = ping_error(503, "Service Unavailable");
+
+        assert!(error.is_retryable());
+        assert_eq!(error.retry_after(), Some(Duration::from_secs(5)));
+        assert!(error.headers().unwrap().contains_key("retry-after"));
+        match error.0 {
+            ErrorKind::ServerError(status, _, _) => assert_eq!(status, 503),
+            other => panic!("Unexpected error {:?}", other),
+        }
+    }
+
+    #[test]
+    fn unexpected_status() {
+        let error = ping_error(404, "Not Found");
+
+        assert!(!error.is_retryable());
+        match error.0 {
+            ErrorKind::HttpError(status, body, _) => {
+                assert_eq!(status, 404);
+                assert_eq!(body, "Not Found");
+            }
+            other => panic!("Unexpected error {:?}", other),
+        }
+    }
+}
@@ -6,9 +6,14 @@ use binance::futures::account::*;
 mod tests {
     use super::*;
     use mockito::{Server, Matcher};
-    use float_cmp::*;
     use binance::account::OrderSide;
+    use binance::errors::ErrorKind as BinanceLibErrorKind;
     use binance::futures::model::Transaction;
+    use binance::model::IntoNumber;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+    use std::thread;
+    use std::time::Duration;
 
     #[test]
     fn change_initial_leverage() {
@@ -33,12 +38,10 @@ mod tests {
 
         assert_eq!(response.leverage, 2);
         assert_eq!(response.symbol, "LTCUSDT");
-        assert!(approx_eq!(
-            f64,
+        assert_eq!(
             response.max_notional_value,
-            9223372036854776000.0,
-            ulps = 2
-        ));
+            9223372036854776000.0.into_number()
+        );
     }
 
     #[test]
@@ -155,7 +158,7 @@ mod tests {
         assert_eq!(transaction.side, "BUY");
         assert_eq!(transaction.orig_type, "STOP_MARKET");
         assert!(transaction.close_position);
-        assert!(approx_eq!(f64, transaction.stop_price, 10.5, ulps = 2));
+        assert_eq!(transaction.stop_price, 10.5.into_number());
     }
 
     #[test]
@@ -180,7 +183,7 @@ mod tests {
         assert_eq!(transaction.side, "SELL");
         assert_eq!(transaction.orig_type, "STOP_MARKET");
         assert!(transaction.close_position);
-        assert!(approx_eq!(f64, transaction.stop_price, 7.4, ulps = 2));
+        assert_eq!(transaction.stop_price, 7.4.into_number());
     }
 
     #[test]
@@ -206,7 +209,7 @@ mod tests {
             qty: None,
             reduce_only: None,
             price: None,
-            stop_price: Some(7.4),
+            stop_price: Some(7.4.into_number()),
             close_position: Some(true),
             activation_price: None,
             callback_rate: None,
@@ -221,7 +224,146 @@ mod tests {
         assert_eq!(transaction.side, "SELL");
         assert_eq!(transaction.orig_type, "STOP_MARKET");
         assert!(transaction.close_position);
-        assert!(approx_eq!(f64, transaction.stop_price, 7.4, ulps = 2));
+        assert_eq!(transaction.stop_price, 7.4.into_number());
+    }
+
+    #[test]
+    fn custom_batch_orders() {
+        let mut server = Server::new();
+        let batch_orders = "[{\"price\":\"20000\",\"quantity\":\"0.01\",\"side\":\"BUY\",\"symbol\":\"BTCUSDT\",\"timeInForce\":\"GTC\",\"type\":\"LIMIT\"},\
+            {\"quantity\":\"0.01\",\"reduceOnly\":\"TRUE\",\"side\":\"SELL\",\"symbol\":\"BTCUSDT\",\"type\":\"MARKET\"}]";
+        let mock_batch_orders = server
+            .mock("POST", "/fapi/v1/batchOrders")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("batchOrders".into(), batch_orders.into()),
+                Matcher::UrlEncoded("recvWindow".into(), "1234".into()),
+                Matcher::Regex("timestamp=\\d+&signature=.*".into()),
+            ]))
+            .with_body_from_file("tests/mocks/futures/account/batch_orders.json")
+            .create();
+
+        let config = Config::default()
+            .set_futures_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: FuturesAccount = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let limit_order = CustomOrderRequest {
+            symbol: "BTCUSDT".into(),
+            side: OrderSide::Buy,
+            position_side: None,
+            order_type: OrderType::Limit,
+            time_in_force: Some(TimeInForce::GTC),
+            qty: Some(0.01.into_number()),
+            reduce_only: None,
+            price: Some(20000.0.into_number()),
+            stop_price: None,
+            close_position: None,
+            activation_price: None,
+            callback_rate: None,
+            working_type: None,
+            price_protect: None,
+        };
+        let reduce_order = CustomOrderRequest {
+            symbol: "BTCUSDT".into(),
+            side: OrderSide::Sell,
+            position_side: None,
+            order_type: OrderType::Market,
+            time_in_force: None,
+            qty: Some(0.01.into_number()),
+            reduce_only: Some(true),
+            price: None,
+            stop_price: None,
+            close_position: None,
+            activation_price: None,
+            callback_rate: None,
+            working_type: None,
+            price_protect: None,
+        };
+        let results = account
+            .custom_batch_orders(vec![limit_order, reduce_order])
+            .unwrap();
+
+        mock_batch_orders.assert();
+
+        assert_eq!(results.len(), 2);
+        let transaction = results[0].as_ref().unwrap();
+        assert_eq!(transaction.order_id, 22542179);
+        assert_eq!(transaction.client_order_id, "testOrder");
+        assert_eq!(transaction.orig_qty, 0.01.into_number());
+        let error = results[1].as_ref().unwrap_err();
+        assert_eq!(error.code, -2022);
+        assert_eq!(error.msg, "ReduceOnly Order is rejected.");
+    }
+
+    #[test]
+    fn custom_batch_orders_rejects_empty_batch() {
+        let config = Config::default().set_recv_window(1234);
+        let account: FuturesAccount = Binance::new_with_config(None, None, &config);
+        assert!(account.custom_batch_orders(vec![]).is_err());
+    }
+
+    #[test]
+    fn cancel_multiple_orders() {
+        let mut server = Server::new();
+        let mock_cancel_multiple_orders = server
+            .mock("DELETE", "/fapi/v1/batchOrders")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "orderIdList=%5B283194212%2C283194213%5D&recvWindow=1234&symbol=BTCUSDT&timestamp=\\d+"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/account/cancel_multiple_orders.json")
+            .create();
+
+        let config = Config::default()
+            .set_futures_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: FuturesAccount = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let results = account
+            .cancel_multiple_orders("BTCUSDT", vec![283194212, 283194213])
+            .unwrap();
+
+        mock_cancel_multiple_orders.assert();
+
+        assert_eq!(results.len(), 2);
+        let canceled = results[0].as_ref().unwrap();
+        assert_eq!(canceled.order_id, 283194212);
+        assert_eq!(canceled.status, "CANCELED");
+        assert_eq!(results[1].as_ref().unwrap_err().code, -2011);
+    }
+
+    #[test]
+    fn cancel_multiple_orders_with_client_ids() {
+        let mut server = Server::new();
+        let mock_cancel_multiple_orders = server
+            .mock("DELETE", "/fapi/v1/batchOrders")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "origClientOrderIdList=%5B%22myOrder1%22%2C%22myOrder2%22%5D&recvWindow=1234\
+                &symbol=BTCUSDT&timestamp=\\d+"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/account/cancel_multiple_orders.json")
+            .create();
+
+        let config = Config::default()
+            .set_futures_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: FuturesAccount = Binance::new_with_config(None, None, &config);
+        let _ = env_logger::try_init();
+        let results = account
+            .cancel_multiple_orders_with_client_ids(
+                "BTCUSDT",
+                vec!["myOrder1".into(), "myOrder2".into()],
+            )
+            .unwrap();
+
+        mock_cancel_multiple_orders.assert();
+
+        assert_eq!(results[0].as_ref().unwrap().client_order_id, "myOrder1");
+        assert!(results[1].is_err());
     }
 
     #[test]
@@ -254,4 +396,234 @@ mod tests {
 
         mock.assert();
     }
+
+    #[test]
+    fn leverage_brackets_and_position_sizing() {
+        let mut server = Server::new();
+        let mock = server
+            .mock("GET", "/fapi/v1/leverageBracket")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^recvWindow=1234&symbol=BTCUSDT&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/account/leverage_bracket.json")
+            .expect(4)
+            .create();
+
+        let config = Config::default()
+            .set_futures_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: FuturesAccount = Binance::new_with_config(None, None, &config);
+
+        let brackets = account
+            .get_leverage_brackets(Some("BTCUSDT".into()))
+            .unwrap();
+        assert_eq!(brackets[0].brackets.len(), 3);
+        assert_eq!(brackets[0].brackets[1].initial_leverage, 100);
+        assert_eq!(brackets[0].brackets[1].notional_floor, 50000.into_number());
+        assert_eq!(brackets[0].max_notional(50), Some(3000000.into_number()));
+        assert_eq!(brackets[0].max_notional(126), None);
+
+        // The whole balance fits in the first bracket
+        let long = account
+            .position_sizing("BTCUSDT", 20, 1000, 25000, OrderSide::Buy)
+            .unwrap();
+        assert_eq!(long.notional, 20000.into_number());
+        assert_eq!(long.qty, 0.8.into_number());
+        assert_eq!(long.initial_margin, 1000.into_number());
+        assert_eq!(long.maint_margin_ratio, 0.004.into_number());
+        assert!((long.liquidation_price - 23845.3815.into_number()).abs() < 0.01.into_number());
+
+        // 100x is capped at the notional of the second bracket
+        let short = account
+            .position_sizing("BTCUSDT", 100, 10000, 25000, OrderSide::Sell)
+            .unwrap();
+        assert_eq!(short.notional, 250000.into_number());
+        assert_eq!(short.qty, 10.into_number());
+        assert_eq!(short.initial_margin, 2500.into_number());
+        assert_eq!(short.maint_amount, 50.into_number());
+        assert!((short.liquidation_price - 25875.6219.into_number()).abs() < 0.01.into_number());
+
+        assert!(account
+            .position_sizing("BTCUSDT", 126, 1000, 25000, OrderSide::Buy)
+            .is_err());
+
+        mock.assert();
+    }
+
+    #[test]
+    fn query_and_modify_order() {
+        let mut server = Server::new();
+        let mock_status = server
+            .mock("GET", "/fapi/v1/order")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^origClientOrderId=abc&recvWindow=1234&symbol=BTCUSDT&timestamp=\\d+&signature=.*"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/account/order.json")
+            .create();
+        let mock_modify = server
+            .mock("PUT", "/fapi/v1/order")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^orderId=1917641&price=9100&quantity=0.5&recvWindow=1234&side=BUY&symbol=BTCUSDT&timestamp=\\d+&signature=.*"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/account/modify_order.json")
+            .create();
+        let mock_amendments = server
+            .mock("GET", "/fapi/v1/orderAmendment")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^orderId=1917641&recvWindow=1234&symbol=BTCUSDT&timestamp=\\d+&signature=.*"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/account/order_amendments.json")
+            .create();
+
+        let config = Config::default()
+            .set_futures_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: FuturesAccount = Binance::new_with_config(None, None, &config);
+
+        let order = account
+            .order_status_with_client_id("BTCUSDT", "abc".into())
+            .unwrap();
+        assert_eq!(order.order_id, 1917641);
+        assert_eq!(order.price, 9000.into_number());
+
+        let modified = account
+            .modify_order(ModifyOrderRequest {
+                symbol: "BTCUSDT".into(),
+                order_id: Some(order.order_id),
+                orig_client_order_id: None,
+                side: OrderSide::Buy,
+                qty: 0.5.into_number(),
+                price: 9100.into_number(),
+            })
+            .unwrap();
+        assert_eq!(modified.order_id, order.order_id);
+        assert_eq!(modified.orig_qty, 0.5.into_number());
+
+        let amendments = account
+            .get_order_amendments("BTCUSDT", Some(1917641), None, None, None, None)
+            .unwrap();
+        assert_eq!(amendments[0].amendment.price.before, 9000.into_number());
+        assert_eq!(amendments[0].amendment.price.after, 9100.into_number());
+        assert_eq!(amendments[0].amendment.count, 1);
+
+        // Orders can only be found by one of their ids
+        assert!(account
+            .modify_order(ModifyOrderRequest {
+                symbol: "BTCUSDT".into(),
+                order_id: None,
+                orig_client_order_id: None,
+                side: OrderSide::Buy,
+                qty: 0.5.into_number(),
+                price: 9100.into_number(),
+            })
+            .is_err());
+
+        mock_status.assert();
+        mock_modify.assert();
+        mock_amendments.assert();
+    }
+
+    #[test]
+    fn countdown_guard() {
+        let mut server = Server::new();
+        let mock_armed = server
+            .mock("POST", "/fapi/v1/countdownCancelAll")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^countdownTime=300&recvWindow=1234&symbol=BTCUSDT&timestamp=\\d+&signature=.*"
+                    .into(),
+            ))
+            .with_body(r#"{"symbol":"BTCUSDT","countdownTime":"300"}"#)
+            .expect_at_least(3)
+            .create();
+        let mock_disarmed = server
+            .mock("POST", "/fapi/v1/countdownCancelAll")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("countdownTime=0&".into()))
+            .with_body(r#"{"symbol":"BTCUSDT","countdownTime":"0"}"#)
+            .expect(1)
+            .create();
+
+        let config = Config::default()
+            .set_futures_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: FuturesAccount = Binance::new_with_config(None, None, &config);
+
+        // Refreshed every 100ms while healthy, disarmed when dropped
+        let guard = account
+            .countdown_guard("BTCUSDT", Duration::from_millis(300), || true)
+            .unwrap();
+        thread::sleep(Duration::from_millis(350));
+        assert!(!guard.is_tripped());
+        drop(guard);
+
+        // A failed health check stops the refreshes and leaves the countdown armed
+        let healthy = Arc::new(AtomicBool::new(true));
+        let check = healthy.clone();
+        let guard = account
+            .countdown_guard("BTCUSDT", Duration::from_millis(300), move || {
+                check.load(Ordering::Relaxed)
+            })
+            .unwrap();
+        healthy.store(false, Ordering::Relaxed);
+        thread::sleep(Duration::from_millis(200));
+        assert!(guard.is_tripped());
+        drop(guard);
+
+        mock_armed.assert();
+        mock_disarmed.assert();
+    }
+
+    #[test]
+    fn countdown_guard_refresh_error() {
+        let mut server = Server::new();
+        let mock_armed = server
+            .mock("POST", "/fapi/v1/countdownCancelAll")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("countdownTime=300&".into()))
+            .with_body(r#"{"symbol":"BTCUSDT","countdownTime":"300"}"#)
+            .create();
+        let mock_disarmed = server
+            .mock("POST", "/fapi/v1/countdownCancelAll")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("countdownTime=0&".into()))
+            .with_body(r#"{"symbol":"BTCUSDT","countdownTime":"0"}"#)
+            .expect(1)
+            .create();
+
+        let config = Config::default().set_futures_rest_api_endpoint(server.url());
+        let account: FuturesAccount = Binance::new_with_config(None, None, &config);
+
+        let guard = account
+            .countdown_guard("BTCUSDT", Duration::from_millis(300), || true)
+            .unwrap();
+        mock_armed.assert();
+        mock_armed.remove();
+        let mock_failed = server
+            .mock("POST", "/fapi/v1/countdownCancelAll")
+            .with_status(400)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("countdownTime=300&".into()))
+            .with_body(r#"{"code":-1001,"msg":"Internal error; unable to process your request."}"#)
+            .create();
+
+        // Failed refreshes are reported on the guard, which stays armed
+        thread::sleep(Duration::from_millis(150));
+        assert!(!guard.is_tripped());
+        match guard.take_refresh_error().unwrap().0 {
+            BinanceLibErrorKind::BinanceError(response) => assert_eq!(response.code, -1001),
+            _ => panic!("Unexpected error"),
+        }
+        drop(guard);
+
+        mock_failed.assert();
+        mock_disarmed.assert();
+    }
 }
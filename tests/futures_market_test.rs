@@ -1,7 +1,9 @@
 use binance::api::*;
 use binance::config::*;
+use binance::futures::account::ContractType;
 use binance::futures::market::FuturesMarket;
-use binance::futures::model::OpenInterestHist;
+use binance::futures::model::{LongShortRatio, OpenInterestHist, TakerBuySellVolume};
+use binance::model::{IntoNumber, KlineSummaries};
 
 #[cfg(test)]
 mod tests {
@@ -43,4 +45,272 @@ mod tests {
 
         assert_eq!(open_interest_hists, expectation);
     }
+
+    #[test]
+    fn long_short_ratios() {
+        let mut server = Server::new();
+        let mock_top_accounts = server
+            .mock("GET", "/futures/data/topLongShortAccountRatio")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("^period=1h&symbol=BTCUSDT$".into()))
+            .with_body_from_file("tests/mocks/futures/market/long_short_ratio.json")
+            .create();
+        let mock_top_positions = server
+            .mock("GET", "/futures/data/topLongShortPositionRatio")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("^limit=2&period=1h&symbol=BTCUSDT$".into()))
+            .with_body_from_file("tests/mocks/futures/market/long_short_ratio.json")
+            .create();
+        let mock_global = server
+            .mock("GET", "/futures/data/globalLongShortAccountRatio")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^endTime=1583140000000&period=5m&startTime=1583139600000&symbol=BTCUSDT$".into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/market/long_short_ratio.json")
+            .create();
+
+        let config = Config::default().set_futures_rest_api_endpoint(server.url());
+        let market: FuturesMarket = Binance::new_with_config(None, None, &config);
+
+        let top_accounts = market
+            .top_long_short_account_ratio("BTCUSDT", "1h", None, None, None)
+            .unwrap();
+        assert_eq!(
+            top_accounts[0],
+            LongShortRatio {
+                symbol: "BTCUSDT".into(),
+                long_short_ratio: "1.8105".into(),
+                long_account: "0.6442".into(),
+                short_account: "0.3558".into(),
+                timestamp: 1583139600000,
+            }
+        );
+
+        let top_positions = market
+            .top_long_short_position_ratio("BTCUSDT", "1h", 2, None, None)
+            .unwrap();
+        assert_eq!(top_positions.len(), 2);
+
+        let global = market
+            .global_long_short_account_ratio("BTCUSDT", "5m", None, 1583139600000, 1583140000000)
+            .unwrap();
+        assert_eq!(global[1].long_short_ratio, "0.5576");
+
+        mock_top_accounts.assert();
+        mock_top_positions.assert();
+        mock_global.assert();
+    }
+
+    #[test]
+    fn taker_buy_sell_volume() {
+        let mut server = Server::new();
+        let mock_taker_volume = server
+            .mock("GET", "/futures/data/takerlongshortRatio")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("^limit=1&period=15m&symbol=BTCUSDT$".into()))
+            .with_body_from_file("tests/mocks/futures/market/taker_buy_sell_volume.json")
+            .create();
+
+        let config = Config::default().set_futures_rest_api_endpoint(server.url());
+        let market: FuturesMarket = Binance::new_with_config(None, None, &config);
+
+        let volumes = market
+            .taker_buy_sell_volume("BTCUSDT", "15m", 1, None, None)
+            .unwrap();
+        mock_taker_volume.assert();
+
+        assert_eq!(
+            volumes,
+            vec![TakerBuySellVolume {
+                buy_sell_ratio: "1.5586".into(),
+                buy_vol: "387.3300".into(),
+                sell_vol: "248.5030".into(),
+                timestamp: 1585614900000,
+            }]
+        );
+    }
+
+    #[test]
+    fn premium_index() {
+        let mut server = Server::new();
+        let mock_premium_index = server
+            .mock("GET", "/fapi/v1/premiumIndex")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("^symbol=BTCUSDT$".into()))
+            .with_body_from_file("tests/mocks/futures/market/premium_index.json")
+            .create();
+
+        let config = Config::default().set_futures_rest_api_endpoint(server.url());
+        let market: FuturesMarket = Binance::new_with_config(None, None, &config);
+
+        let premium_index = market.get_premium_index("BTCUSDT").unwrap();
+        mock_premium_index.assert();
+
+        assert_eq!(premium_index.mark_price, 11793.63104562.into_number());
+        assert_eq!(premium_index.index_price, 11781.8049597.into_number());
+        assert_eq!(premium_index.last_funding_rate, 0.00038246.into_number());
+        assert_eq!(premium_index.interest_rate, 0.0001.into_number());
+        assert_eq!(premium_index.next_funding_time, 1597392000000);
+    }
+
+    #[test]
+    fn funding_rate_history() {
+        let start_time = 1570608000000u64;
+        let interval = 8 * 60 * 60 * 1000;
+        let rate = |i: u64| {
+            format!(
+                r#"{{"symbol":"BTCUSDT","fundingRate":"-0.00037500","fundingTime":{},"markPrice":"{}"}}"#,
+                start_time + i * interval,
+                if i == 0 { "" } else { "34287.54619963" }
+            )
+        };
+        let first_page: Vec<String> = (0..1000).map(rate).collect();
+        let second_page: Vec<String> = (1000..1002).map(rate).collect();
+
+        let mut server = Server::new();
+        let mock_first_page = server
+            .mock("GET", "/fapi/v1/fundingRate")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(format!(
+                "^limit=1000&startTime={}&symbol=BTCUSDT$",
+                start_time
+            )))
+            .with_body(format!("[{}]", first_page.join(",")))
+            .create();
+        let mock_second_page = server
+            .mock("GET", "/fapi/v1/fundingRate")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(format!(
+                "^limit=500&startTime={}&symbol=BTCUSDT$",
+                start_time + 999 * interval + 1
+            )))
+            .with_body(format!("[{}]", second_page.join(",")))
+            .create();
+
+        let config = Config::default().set_futures_rest_api_endpoint(server.url());
+        let market: FuturesMarket = Binance::new_with_config(None, None, &config);
+
+        let rates = market
+            .get_funding_rate_history("BTCUSDT", Some(start_time), None, Some(1500))
+            .unwrap();
+        mock_first_page.assert();
+        mock_second_page.assert();
+
+        assert_eq!(rates.len(), 1002);
+        assert_eq!(rates[0].funding_rate, (-0.000375).into_number());
+        // The oldest rates have no mark price
+        assert_eq!(rates[0].mark_price, 0.into_number());
+        assert_eq!(rates[1].mark_price, 34287.54619963.into_number());
+        assert_eq!(rates[1001].funding_time, start_time + 1001 * interval);
+    }
+
+    #[test]
+    fn latest_funding_rates() {
+        let mut server = Server::new();
+        // Without a start time a single page is requested, however large the limit
+        let mock_latest = server
+            .mock("GET", "/fapi/v1/fundingRate")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("^limit=1000&symbol=BTCUSDT$".into()))
+            .with_body(
+                r#"[{"symbol":"BTCUSDT","fundingRate":"0.00010000","fundingTime":1570608000000,"markPrice":"34287.54619963"}]"#,
+            )
+            .expect(1)
+            .create();
+
+        let config = Config::default().set_futures_rest_api_endpoint(server.url());
+        let market: FuturesMarket = Binance::new_with_config(None, None, &config);
+
+        let rates = market
+            .get_funding_rate_history("BTCUSDT", None, None, Some(1500))
+            .unwrap();
+        assert_eq!(rates.len(), 1);
+        // Nothing to request
+        let rates = market
+            .get_funding_rate_history("BTCUSDT", None, None, Some(0))
+            .unwrap();
+        assert!(rates.is_empty());
+
+        mock_latest.assert();
+    }
+
+    #[test]
+    fn funding_info() {
+        let mut server = Server::new();
+        let mock_funding_info = server
+            .mock("GET", "/fapi/v1/fundingInfo")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("tests/mocks/futures/market/funding_info.json")
+            .create();
+
+        let config = Config::default().set_futures_rest_api_endpoint(server.url());
+        let market: FuturesMarket = Binance::new_with_config(None, None, &config);
+
+        let funding_info = market.get_funding_info().unwrap();
+        mock_funding_info.assert();
+
+        assert_eq!(funding_info[0].symbol, "BLZUSDT");
+        assert_eq!(
+            funding_info[0].adjusted_funding_rate_cap,
+            0.025.into_number()
+        );
+        assert_eq!(
+            funding_info[0].adjusted_funding_rate_floor,
+            (-0.025).into_number()
+        );
+        assert_eq!(funding_info[0].funding_interval_hours, 8);
+    }
+
+    #[test]
+    fn price_klines() {
+        let mut server = Server::new();
+        let mock_continuous = server
+            .mock("GET", "/fapi/v1/continuousKlines")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^contractType=PERPETUAL&interval=1m&limit=1&pair=BTCUSDT$".into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/market/continuous_klines.json")
+            .create();
+        let mock_mark_price = server
+            .mock("GET", "/fapi/v1/markPriceKlines")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^interval=1m&startTime=1591256400000&symbol=BTCUSDT$".into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/market/mark_price_klines.json")
+            .create();
+        let mock_premium_index = server
+            .mock("GET", "/fapi/v1/premiumIndexKlines")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("^interval=1h&symbol=BTCUSDT$".into()))
+            .with_body("[]")
+            .create();
+
+        let config = Config::default().set_futures_rest_api_endpoint(server.url());
+        let market: FuturesMarket = Binance::new_with_config(None, None, &config);
+
+        let KlineSummaries::AllKlineSummaries(continuous) = market
+            .get_continuous_klines("BTCUSDT", ContractType::Perpetual, "1m", 1, None, None)
+            .unwrap();
+        assert_eq!(continuous[0].open_time, 1607444700000);
+        assert_eq!(continuous[0].close, "18896.13");
+        assert_eq!(continuous[0].number_of_trades, 1874);
+
+        let KlineSummaries::AllKlineSummaries(mark_price) = market
+            .get_mark_price_klines("BTCUSDT", "1m", None, 1591256400000, None)
+            .unwrap();
+        assert_eq!(mark_price[0].high, "9653.69640000");
+        assert_eq!(mark_price[0].volume, "0");
+
+        let KlineSummaries::AllKlineSummaries(premium_index) = market
+            .get_premium_index_klines("BTCUSDT", "1h", None, None, None)
+            .unwrap();
+        assert!(premium_index.is_empty());
+
+        mock_continuous.assert();
+        mock_mark_price.assert();
+        mock_premium_index.assert();
+    }
 }
@@ -0,0 +1,270 @@
+use binance::api::*;
+use binance::config::*;
+use binance::margin::account::*;
+use binance::margin::userstream::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{Matcher, Server};
+    use binance::account::{OcoOrderRequest, OrderSide, OrderType};
+    use binance::model::IntoNumber;
+
+    fn config(server: &Server) -> Config {
+        Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234)
+    }
+
+    #[test]
+    fn borrow_and_loan_history() {
+        let mut server = Server::new();
+        let mock_borrow = server
+            .mock("POST", "/sapi/v1/margin/borrow-repay")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^amount=14&asset=BNB&isIsolated=TRUE&recvWindow=1234&symbol=BNBUSDT&timestamp=\\d+&type=BORROW&signature=.*"
+                    .into(),
+            ))
+            .with_body(r#"{"tranId":100000001}"#)
+            .create();
+        let mock_repay = server
+            .mock("POST", "/sapi/v1/margin/borrow-repay")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^amount=1.5&asset=BTC&isIsolated=FALSE&recvWindow=1234&timestamp=\\d+&type=REPAY&signature=.*"
+                    .into(),
+            ))
+            .with_body(r#"{"tranId":100000002}"#)
+            .create();
+        let mock_history = server
+            .mock("GET", "/sapi/v1/margin/borrow-repay")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^asset=BNB&isolatedSymbol=BNBUSDT&recvWindow=1234&startTime=1563438000000&timestamp=\\d+&type=BORROW&signature=.*"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/margin/loan_history.json")
+            .create();
+
+        let margin: MarginAccount = Binance::new_with_config(None, None, &config(&server));
+
+        let borrow = margin.borrow("BNB", 14, Some("BNBUSDT".into())).unwrap();
+        assert_eq!(borrow.tran_id, 100000001);
+
+        let repay = margin.repay("BTC", 1.5, None).unwrap();
+        assert_eq!(repay.tran_id, 100000002);
+
+        let history = margin
+            .borrow_repay_history(
+                BorrowRepayType::Borrow,
+                Some("BNB".into()),
+                Some("BNBUSDT".into()),
+                Some(1563438000000),
+                None,
+            )
+            .unwrap();
+        assert_eq!(history.total, 1);
+        assert_eq!(history.rows[0].isolated_symbol, "BNBUSDT");
+        assert_eq!(history.rows[0].amount, 14.into_number());
+        assert_eq!(history.rows[0].principal, Some(13.98133333.into_number()));
+        assert_eq!(history.rows[0].tx_id, 2970933056);
+
+        mock_borrow.assert();
+        mock_repay.assert();
+        mock_history.assert();
+    }
+
+    #[test]
+    fn auto_borrow_repay_order() {
+        let mut server = Server::new();
+        let mock = server
+            .mock("POST", "/sapi/v1/margin/order")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^newOrderRespType=FULL&quantity=10&recvWindow=1234&side=BUY&sideEffectType=AUTO_BORROW_REPAY&symbol=BTCUSDT&timestamp=\\d+&type=MARKET&signature=.*"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/margin/new_order.json")
+            .create();
+
+        let margin: MarginAccount = Binance::new_with_config(None, None, &config(&server));
+        let transaction = margin
+            .custom_order(OrderRequest {
+                symbol: "BTCUSDT".into(),
+                is_isolated: false,
+                side: OrderSide::Buy,
+                order_type: OrderType::Market,
+                qty: Some(10.into_number()),
+                quote_order_qty: None,
+                price: None,
+                stop_price: None,
+                time_in_force: None,
+                new_client_order_id: None,
+                side_effect_type: Some(SideEffectType::AutoBorrowRepay),
+            })
+            .unwrap();
+
+        mock.assert();
+
+        assert_eq!(transaction.order_id, 28);
+        assert!(!transaction.is_isolated);
+        assert_eq!(transaction.margin_buy_borrow_amount, Some(5.into_number()));
+        assert_eq!(transaction.margin_buy_borrow_asset, Some("BTC".into()));
+        assert_eq!(transaction.fills[0].price, 4000.into_number());
+    }
+
+    #[test]
+    fn isolated_oco_order() {
+        let mut server = Server::new();
+        let mock = server
+            .mock("POST", "/sapi/v1/margin/order/oco")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^isIsolated=TRUE&price=0.01&quantity=1&recvWindow=1234&side=SELL&sideEffectType=AUTO_REPAY&stopPrice=0.005&symbol=LTCBTC&timestamp=\\d+&signature=.*"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/margin/oco_order.json")
+            .create();
+
+        let margin: MarginAccount = Binance::new_with_config(None, None, &config(&server));
+        let order_list = margin
+            .oco_order(
+                OcoOrderRequest {
+                    symbol: "LTCBTC".into(),
+                    side: OrderSide::Sell,
+                    qty: 1.into_number(),
+                    price: 0.01.into_number(),
+                    stop_price: 0.005.into_number(),
+                    stop_limit_price: None,
+                    stop_limit_time_in_force: None,
+                    list_client_order_id: None,
+                    limit_client_order_id: None,
+                    stop_client_order_id: None,
+                },
+                true,
+                Some(SideEffectType::AutoRepay),
+            )
+            .unwrap();
+
+        mock.assert();
+
+        assert_eq!(order_list.contingency_type, "OCO");
+        assert_eq!(order_list.orders.len(), 2);
+    }
+
+    #[test]
+    fn max_amounts_and_interest() {
+        let mut server = Server::new();
+        let mock_borrowable = server
+            .mock("GET", "/sapi/v1/margin/maxBorrowable")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^asset=BTC&isolatedSymbol=BTCUSDT&recvWindow=1234&timestamp=\\d+&signature=.*"
+                    .into(),
+            ))
+            .with_body(r#"{"amount":"1.69248805","borrowLimit":"60"}"#)
+            .create();
+        let mock_transferable = server
+            .mock("GET", "/sapi/v1/margin/maxTransferable")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^asset=BTC&recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body(r#"{"amount":"3.59498107"}"#)
+            .create();
+        let mock_interest = server
+            .mock("GET", "/sapi/v1/margin/interestHistory")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^isolatedSymbol=BNBUSDT&recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/margin/interest_history.json")
+            .create();
+
+        let margin: MarginAccount = Binance::new_with_config(None, None, &config(&server));
+
+        let borrowable = margin
+            .max_borrowable("BTC", Some("BTCUSDT".into()))
+            .unwrap();
+        assert_eq!(borrowable.amount, 1.69248805.into_number());
+        assert_eq!(borrowable.borrow_limit, 60.into_number());
+
+        let transferable = margin.max_transferable("BTC", None).unwrap();
+        assert_eq!(transferable.amount, 3.59498107.into_number());
+
+        let interest = margin
+            .interest_history(None, Some("BNBUSDT".into()), None, None)
+            .unwrap();
+        assert_eq!(interest.rows[0].interest, 0.00024995.into_number());
+        assert_eq!(interest.rows[0].interest_type, "ON_BORROW");
+
+        mock_borrowable.assert();
+        mock_transferable.assert();
+        mock_interest.assert();
+    }
+
+    #[test]
+    fn isolated_pair_and_listen_keys() {
+        let mut server = Server::new();
+        let mock_enable = server
+            .mock("POST", "/sapi/v1/margin/isolated/account")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^recvWindow=1234&symbol=BTCUSDT&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body(r#"{"success":true,"symbol":"BTCUSDT"}"#)
+            .create();
+        let mock_disable = server
+            .mock("DELETE", "/sapi/v1/margin/isolated/account")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^recvWindow=1234&symbol=BTCUSDT&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body(r#"{"success":true,"symbol":"BTCUSDT"}"#)
+            .create();
+        let mock_cross_key = server
+            .mock("POST", "/sapi/v1/userDataStream")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body(r#"{"listenKey":"cross_margin_listen_key"}"#)
+            .create();
+        let mock_isolated_key = server
+            .mock("POST", "/sapi/v1/userDataStream/isolated")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::UrlEncoded("symbol".into(), "BTCUSDT".into()))
+            .with_body(r#"{"listenKey":"isolated_margin_listen_key"}"#)
+            .create();
+        let mock_isolated_keep_alive = server
+            .mock("PUT", "/sapi/v1/userDataStream/isolated")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("symbol".into(), "BTCUSDT".into()),
+                Matcher::UrlEncoded("listenKey".into(), "isolated_margin_listen_key".into()),
+            ]))
+            .with_body("{}")
+            .create();
+
+        let margin: MarginAccount = Binance::new_with_config(None, None, &config(&server));
+        let user_stream: MarginUserStream = Binance::new_with_config(None, None, &config(&server));
+
+        let enabled = margin.enable_isolated_pair("BTCUSDT").unwrap();
+        assert!(enabled.success);
+        let disabled = margin.disable_isolated_pair("BTCUSDT").unwrap();
+        assert_eq!(disabled.symbol, "BTCUSDT");
+
+        let cross = user_stream.start().unwrap();
+        assert_eq!(cross.listen_key, "cross_margin_listen_key");
+
+        let isolated = user_stream.start_isolated("BTCUSDT").unwrap();
+        assert_eq!(isolated.listen_key, "isolated_margin_listen_key");
+        user_stream
+            .keep_alive_isolated("BTCUSDT", &isolated.listen_key)
+            .unwrap();
+
+        mock_enable.assert();
+        mock_disable.assert();
+        mock_cross_key.assert();
+        mock_isolated_key.assert();
+        mock_isolated_keep_alive.assert();
+    }
+}
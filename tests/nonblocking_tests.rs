@@ -0,0 +1,158 @@
+#![cfg(feature = "async")]
+
+use binance::api::*;
+use binance::config::*;
+use binance::nonblocking::account::*;
+use binance::nonblocking::futures::account::*;
+use binance::nonblocking::general::*;
+use binance::nonblocking::market::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{Server, Matcher};
+    use binance::model::IntoNumber;
+    use binance::errors::ErrorKind as BinanceLibErrorKind;
+
+    #[tokio::test]
+    async fn ping() {
+        let mut server = Server::new_async().await;
+        let mock_ping = server
+            .mock("GET", "/api/v3/ping")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body("{}")
+            .create_async()
+            .await;
+
+        let config = Config::default().set_rest_api_endpoint(server.url());
+        let general: General = Binance::new_with_config(None, None, &config);
+
+        let pong = general.ping().await.unwrap();
+        mock_ping.assert_async().await;
+
+        assert_eq!(pong, "pong");
+    }
+
+    #[tokio::test]
+    async fn get_price() {
+        let mut server = Server::new_async().await;
+        let mock_get_price = server
+            .mock("GET", "/api/v3/ticker/price")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("symbol=LTCBTC".into()))
+            .with_body_from_file("tests/mocks/market/get_price.json")
+            .create_async()
+            .await;
+
+        let config = Config::default().set_rest_api_endpoint(server.url());
+        let market: Market = Binance::new_with_config(None, None, &config);
+
+        let symbol = market.get_price("LTCBTC").await.unwrap();
+        mock_get_price.assert_async().await;
+
+        assert_eq!(symbol.symbol, "LTCBTC");
+        assert_eq!(symbol.price, 4.000_002.into_number());
+    }
+
+    #[tokio::test]
+    async fn get_account() {
+        let mut server = Server::new_async().await;
+        let mock_get_account = server
+            .mock("GET", "/api/v3/account")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/account/get_account.json")
+            .create_async()
+            .await;
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: Account = Binance::new_with_config(None, None, &config);
+        let account = account.get_account().await.unwrap();
+
+        mock_get_account.assert_async().await;
+
+        assert!(account.can_trade);
+        assert_eq!(account.balances[0].asset, "BTC");
+        assert_eq!(account.balances[0].free, "4723846.89208129");
+    }
+
+    #[tokio::test]
+    async fn limit_buy() {
+        let mut server = Server::new_async().await;
+        let mock_limit_buy = server
+            .mock("POST", "/api/v3/order")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "price=0.1&quantity=1&recvWindow=1234&side=BUY&symbol=LTCBTC&timeInForce=GTC&timestamp=\\d+&type=LIMIT"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/account/limit_buy.json")
+            .create_async()
+            .await;
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: Account = Binance::new_with_config(None, None, &config);
+        let transaction = account.limit_buy("LTCBTC", 1, 0.1).await.unwrap();
+
+        mock_limit_buy.assert_async().await;
+
+        assert_eq!(transaction.symbol, "LTCBTC");
+        assert_eq!(transaction.side, "BUY");
+        assert_eq!(transaction.type_name, "LIMIT");
+    }
+
+    #[tokio::test]
+    async fn change_initial_leverage() {
+        let mut server = Server::new_async().await;
+        let mock_change_leverage = server
+            .mock("POST", "/fapi/v1/leverage")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "leverage=2&recvWindow=1234&symbol=LTCUSDT&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/futures/account/change_initial_leverage.json")
+            .create_async()
+            .await;
+
+        let config = Config::default()
+            .set_futures_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: FuturesAccount = Binance::new_with_config(None, None, &config);
+        let response = account.change_initial_leverage("LTCUSDT", 2).await.unwrap();
+
+        mock_change_leverage.assert_async().await;
+
+        assert_eq!(response.leverage, 2);
+        assert_eq!(response.symbol, "LTCUSDT");
+    }
+
+    #[tokio::test]
+    async fn binance_error() {
+        let mut server = Server::new_async().await;
+        let mock_order_status = server
+            .mock("GET", "/api/v3/order")
+            .with_status(400)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Any)
+            .with_body(r#"{"code": -2013, "msg": "Order does not exist."}"#)
+            .create_async()
+            .await;
+
+        let config = Config::default().set_rest_api_endpoint(server.url());
+        let account: Account = Binance::new_with_config(None, None, &config);
+        let err = account.order_status("LTCBTC", 1).await.unwrap_err();
+
+        mock_order_status.assert_async().await;
+
+        match err.0 {
+            BinanceLibErrorKind::BinanceError(response) => assert_eq!(response.code, -2013),
+            _ => panic!("Expected a BinanceError"),
+        }
+    }
+}
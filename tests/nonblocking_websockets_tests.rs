@@ -0,0 +1,103 @@
+#![cfg(feature = "async")]
+
+use binance::config::*;
+use binance::futures::websockets::{FuturesMarket, FuturesWebsocketEvent};
+use binance::nonblocking::futures::websockets::*;
+use binance::nonblocking::websockets::*;
+use binance::websockets::WebsocketEvent;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_util::{SinkExt, StreamExt};
+    use tokio::net::TcpListener;
+    use tokio_tungstenite::tungstenite::Message;
+
+    const TRADE_EVENT: &str = r#"{"e":"trade","E":1672515782136,"s":"BNBBTC","t":12345,"p":"0.001","q":"100","b":88,"a":50,"T":1672515782136,"m":true,"M":true}"#;
+
+    // Serves the given text frames to the first client, then waits for it to hang up.
+    async fn serve(frames: Vec<String>) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let endpoint = format!("ws://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut socket = tokio_tungstenite::accept_async(stream).await.unwrap();
+            for frame in frames {
+                socket.send(Message::Text(frame)).await.unwrap();
+            }
+            while let Some(Ok(message)) = socket.next().await {
+                if message.is_close() {
+                    break;
+                }
+            }
+        });
+        endpoint
+    }
+
+    #[tokio::test]
+    async fn stream_events() {
+        let combined = format!(r#"{{"stream":"bnbbtc@trade","data":{}}}"#, TRADE_EVENT);
+        let endpoint = serve(vec![
+            TRADE_EVENT.to_string(),
+            r#"{"result":null,"id":1}"#.to_string(),
+            combined,
+        ])
+        .await;
+
+        let config = Config::default().set_ws_endpoint(endpoint);
+        let mut web_socket = WebSockets::connect_with_config("bnbbtc@trade", &config)
+            .await
+            .unwrap();
+
+        for _ in 0..2 {
+            match web_socket.next().await {
+                Some(Ok(WebsocketEvent::Trade(trade))) => {
+                    assert_eq!(trade.symbol, "BNBBTC");
+                    assert_eq!(trade.trade_id, 12345);
+                    assert_eq!(trade.price, "0.001");
+                }
+                other => panic!("Unexpected event {:?}", other),
+            }
+        }
+
+        web_socket.shutdown_handle().shutdown();
+        assert!(web_socket.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn shutdown_wakes_pending_stream() {
+        let endpoint = serve(vec![]).await;
+
+        let config = Config::default().set_ws_endpoint(endpoint);
+        let mut web_socket = WebSockets::connect_with_config("bnbbtc@trade", &config)
+            .await
+            .unwrap();
+
+        let handle = web_socket.shutdown_handle();
+        tokio::spawn(async move {
+            tokio::task::yield_now().await;
+            handle.shutdown();
+        });
+
+        assert!(web_socket.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn futures_stream_events() {
+        let endpoint = serve(vec![TRADE_EVENT.to_string()]).await;
+
+        // The custom futures endpoint is used as the full stream url
+        let config = Config::default().set_ws_endpoint(endpoint);
+        let mut web_socket =
+            FuturesWebSockets::connect_with_config(&FuturesMarket::USDM, "bnbbtc@trade", &config)
+                .await
+                .unwrap();
+
+        match web_socket.next().await {
+            Some(Ok(FuturesWebsocketEvent::Trade(trade))) => assert_eq!(trade.symbol, "BNBBTC"),
+            other => panic!("Unexpected event {:?}", other),
+        }
+
+        web_socket.disconnect().await.unwrap();
+    }
+}
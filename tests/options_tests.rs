@@ -0,0 +1,180 @@
+use binance::api::*;
+use binance::config::*;
+use binance::options::account::*;
+use binance::options::general::*;
+use binance::options::market::*;
+use binance::options::model::*;
+use binance::options::userstream::*;
+use binance::options::websockets::*;
+use binance::userstream::UserDataStreamApi;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{Matcher, Server};
+    use binance::account::TimeInForce;
+    use binance::model::IntoNumber;
+
+    fn config(server: &Server) -> Config {
+        Config::default()
+            .set_options_rest_api_endpoint(server.url())
+            .set_recv_window(1234)
+    }
+
+    #[test]
+    fn exchange_info() {
+        let mut server = Server::new();
+        let mock = server
+            .mock("GET", "/eapi/v1/exchangeInfo")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("tests/mocks/options/exchange_info.json")
+            .create();
+
+        let general: OptionsGeneral = Binance::new_with_config(None, None, &config(&server));
+        let symbol = general.get_symbol_info("btc-220815-50000-c").unwrap();
+
+        mock.assert();
+
+        assert_eq!(symbol.side, OptionSide::Call);
+        assert_eq!(symbol.strike_price, 50000.into_number());
+        assert_eq!(symbol.expiry_date, 1660521600000);
+        assert_eq!(symbol.underlying, "BTCUSDT");
+        assert_eq!(symbol.filters.len(), 2);
+    }
+
+    #[test]
+    fn market_data() {
+        let mut server = Server::new();
+        let mock_mark = server
+            .mock("GET", "/eapi/v1/mark")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("symbol=BTC-200730-9000-C".into()))
+            .with_body_from_file("tests/mocks/options/mark.json")
+            .create();
+        let mock_depth = server
+            .mock("GET", "/eapi/v1/depth")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("limit=10&symbol=BTC-200730-9000-C".into()))
+            .with_body_from_file("tests/mocks/options/depth.json")
+            .create();
+        let mock_klines = server
+            .mock("GET", "/eapi/v1/klines")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "interval=5m&limit=1&symbol=BTC-200730-9000-C".into(),
+            ))
+            .with_body_from_file("tests/mocks/options/klines.json")
+            .create();
+
+        let market: OptionsMarket = Binance::new_with_config(None, None, &config(&server));
+
+        let mark = market.get_mark_price("BTC-200730-9000-C").unwrap();
+        assert_eq!(mark.mark_price, 1343.2883.into_number());
+        assert_eq!(mark.mark_iv, 1.45.into_number());
+        assert_eq!(mark.delta, 0.55937056.into_number());
+        assert_eq!(mark.vega, 978.58874732.into_number());
+
+        let order_book = market.get_depth("BTC-200730-9000-C", 10).unwrap();
+        assert_eq!(order_book.update_id, 37461);
+        assert_eq!(order_book.bids[0].price, 1000.into_number());
+        assert_eq!(order_book.asks[0].qty, 0.1.into_number());
+
+        let klines = market
+            .get_klines("BTC-200730-9000-C", "5m", 1, None, None)
+            .unwrap();
+        assert_eq!(klines[0].close, 1100.into_number());
+        assert_eq!(klines[0].trade_count, 2);
+
+        mock_mark.assert();
+        mock_depth.assert();
+        mock_klines.assert();
+    }
+
+    #[test]
+    fn limit_buy() {
+        let mut server = Server::new();
+        let mock = server
+            .mock("POST", "/eapi/v1/order")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "newOrderRespType=RESULT&price=100&quantity=1&recvWindow=1234&side=BUY&symbol=BTC-200730-9000-C&timeInForce=GTC&timestamp=\\d+&type=LIMIT&signature=.*"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/options/new_order.json")
+            .create();
+
+        let account: OptionsAccount = Binance::new_with_config(None, None, &config(&server));
+        let order = account
+            .limit_buy("BTC-200730-9000-C", 1, 100, TimeInForce::GTC)
+            .unwrap();
+
+        mock.assert();
+
+        assert_eq!(order.order_id, 4611875134427365377);
+        assert_eq!(order.option_side, OptionSide::Call);
+        assert_eq!(order.status, "ACCEPTED");
+    }
+
+    #[test]
+    fn positions_and_greeks() {
+        let mut server = Server::new();
+        let mock_position = server
+            .mock("GET", "/eapi/v1/position")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^recvWindow=1234&symbol=BTC-200730-9000-C&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/options/position.json")
+            .create();
+        let mock_account = server
+            .mock("GET", "/eapi/v1/marginAccount")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Any)
+            .with_body_from_file("tests/mocks/options/margin_account.json")
+            .create();
+
+        let account: OptionsAccount = Binance::new_with_config(None, None, &config(&server));
+
+        let positions = account
+            .position_information(Some("BTC-200730-9000-C".into()))
+            .unwrap();
+        assert_eq!(positions[0].side, "SHORT");
+        assert_eq!(positions[0].quantity, (-0.1).into_number());
+        assert_eq!(positions[0].strike_price, 9000.into_number());
+        assert_eq!(positions[0].expiry_date, 1621000000000);
+
+        let information = account.account_information().unwrap();
+        assert_eq!(information.assets[0].equity, 10094.44662.into_number());
+        assert_eq!(information.greeks[0].underlying, "BTCUSDT");
+        assert_eq!(information.greeks[0].delta, (-0.05).into_number());
+
+        mock_position.assert();
+        mock_account.assert();
+    }
+
+    #[test]
+    fn user_data_events() {
+        let account_update = r#"{"e":"ACCOUNT_UPDATE","E":1591161121111,"B":[{"b":"100.0","m":"99.0","u":"-1.0","U":1,"M":"0.3","i":"0.1","a":"USDT"}],"G":[{"ui":"BTCUSDT","d":-0.01,"t":-0.1,"g":0.001,"v":0.01}],"P":[{"s":"BTC-220930-18000-C","c":"-18","r":"-18","p":"-18"}],"uid":1000006559949}"#;
+        match OptionsUserStream::parse_event(account_update).unwrap() {
+            Some(OptionsWebsocketEvent::AccountUpdate(event)) => {
+                assert_eq!(event.balances[0].asset, "USDT");
+                assert_eq!(event.greeks[0].delta, (-0.01).into_number());
+                assert_eq!(event.positions[0].quantity, (-18).into_number());
+            }
+            other => panic!("Unexpected event {:?}", other),
+        }
+
+        let order_trade = r#"{"e":"ORDER_TRADE_UPDATE","E":1657613775883,"o":[{"T":1657613342918,"t":1657613342918,"s":"BTC-220930-18000-C","c":"","oid":"4611869636869226548","p":"1993","q":"1","stp":0,"r":false,"po":true,"S":"PARTIALLY_FILLED","e":"0.1","ec":"199.3","f":"2","tif":"GTC","oty":"LIMIT","fi":[]}]}"#;
+        match OptionsUserStream::parse_event(order_trade).unwrap() {
+            Some(OptionsWebsocketEvent::OrderTrade(event)) => {
+                assert_eq!(event.orders[0].status, "PARTIALLY_FILLED");
+                assert_eq!(event.orders[0].executed_qty, 0.1.into_number());
+            }
+            other => panic!("Unexpected event {:?}", other),
+        }
+
+        let expired = r#"{"e":"listenKeyExpired","E":1576653824250}"#;
+        let event = OptionsUserStream::parse_event(expired).unwrap().unwrap();
+        assert!(OptionsUserStream::is_expired(&event));
+    }
+}
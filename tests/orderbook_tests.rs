@@ -0,0 +1,164 @@
+use binance::api::*;
+use binance::config::*;
+use binance::futures::market::FuturesMarket;
+use binance::market::*;
+use binance::model::*;
+use binance::orderbook::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{Server, Matcher};
+    use std::cell::Cell;
+
+    fn depth(
+        first_update_id: u64, final_update_id: u64, previous_final_update_id: Option<u64>,
+        bids: Vec<(f64, f64)>, asks: Vec<(f64, f64)>,
+    ) -> DepthOrderBookEvent {
+        DepthOrderBookEvent {
+            event_type: "depthUpdate".into(),
+            event_time: 1672515782136,
+            symbol: "BTCUSDT".into(),
+            first_update_id,
+            final_update_id,
+            previous_final_update_id,
+            bids: bids
+                .into_iter()
+                .map(|(price, qty)| bid(price, qty))
+                .collect(),
+            asks: asks
+                .into_iter()
+                .map(|(price, qty)| Asks::new(price.into_number(), qty.into_number()))
+                .collect(),
+        }
+    }
+
+    fn bid(price: f64, qty: f64) -> Bids {
+        Bids::new(price.into_number(), qty.into_number())
+    }
+
+    #[test]
+    fn spot_order_book() {
+        let mut server = Server::new();
+        let mock_depth = server
+            .mock("GET", "/api/v3/depth")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("limit=1000&symbol=BTCUSDT".into()))
+            .with_body_from_file("tests/mocks/orderbook/spot_depth.json")
+            .expect(2)
+            .create();
+
+        let config = Config::default().set_rest_api_endpoint(server.url());
+        let market: Market = Binance::new_with_config(None, None, &config);
+        let changes = Cell::new(0);
+        let mut order_book = OrderBookManager::spot(market, "btcusdt").on_change(|book| {
+            changes.set(changes.get() + 1);
+            assert!(book.is_synced());
+        });
+
+        // Older than the snapshot, dropped once it arrives
+        order_book
+            .update(depth(95, 99, None, vec![(10.0, 5.0)], vec![]))
+            .unwrap();
+        assert!(order_book.is_synced());
+        assert_eq!(order_book.last_update_id(), 100);
+
+        order_book
+            .update(depth(
+                100,
+                102,
+                None,
+                vec![(10.0, 0.0), (9.5, 4.0)],
+                vec![(11.0, 2.0)],
+            ))
+            .unwrap();
+        order_book
+            .update(depth(103, 105, None, vec![], vec![(10.5, 0.5)]))
+            .unwrap();
+        // Already applied
+        order_book
+            .update(depth(103, 105, None, vec![(20.0, 1.0)], vec![]))
+            .unwrap();
+
+        assert_eq!(order_book.last_update_id(), 105);
+        assert_eq!(changes.get(), 3);
+
+        let best_bid = order_book.best_bid().unwrap();
+        assert_eq!(best_bid.price, 9.5.into_number());
+        assert_eq!(best_bid.qty, 4.0.into_number());
+        let best_ask = order_book.best_ask().unwrap();
+        assert_eq!(best_ask.price, 10.5.into_number());
+
+        let bids = order_book.bids(5);
+        assert_eq!(bids, vec![bid(9.5, 4.0), bid(9.0, 2.0)]);
+        let asks: Vec<Number> = order_book.asks(2).iter().map(|ask| ask.price).collect();
+        assert_eq!(asks, vec![10.5.into_number(), 11.0.into_number()]);
+
+        // Skipping 106..=109 forces a new snapshot, which is too old to resume from
+        order_book
+            .update(depth(110, 112, None, vec![], vec![]))
+            .unwrap();
+
+        mock_depth.assert();
+        assert_eq!(order_book.resyncs(), 1);
+        assert!(!order_book.is_synced());
+        assert!(order_book.best_bid().is_none());
+
+        // The next 10 events are only buffered, the one after fetches a snapshot again
+        for id in 113..123 {
+            order_book
+                .update(depth(id, id, None, vec![], vec![]))
+                .unwrap();
+        }
+        mock_depth.assert();
+        mock_depth.remove();
+        let mock_retry = server
+            .mock("GET", "/api/v3/depth")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("limit=1000&symbol=BTCUSDT".into()))
+            .with_body_from_file("tests/mocks/orderbook/spot_depth.json")
+            .expect(1)
+            .create();
+        order_book
+            .update(depth(123, 123, None, vec![], vec![]))
+            .unwrap();
+        mock_retry.assert();
+        assert!(!order_book.is_synced());
+    }
+
+    #[test]
+    fn usdm_order_book() {
+        let mut server = Server::new();
+        let mock_depth = server
+            .mock("GET", "/fapi/v1/depth")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("limit=500&symbol=BTCUSDT".into()))
+            .with_body_from_file("tests/mocks/orderbook/usdm_depth.json")
+            .expect(2)
+            .create();
+
+        let config = Config::default().set_futures_rest_api_endpoint(server.url());
+        let market: FuturesMarket = Binance::new_with_config(None, None, &config);
+        let mut order_book = OrderBookManager::usdm(market, "BTCUSDT").set_depth_limit(500);
+
+        order_book
+            .update(depth(98, 103, Some(97), vec![(9.0, 0.0)], vec![]))
+            .unwrap();
+        order_book
+            .update(depth(104, 106, Some(103), vec![(10.2, 1.5)], vec![]))
+            .unwrap();
+
+        assert!(order_book.is_synced());
+        assert_eq!(order_book.last_update_id(), 106);
+        assert_eq!(order_book.bids(5), vec![bid(10.2, 1.5), bid(10.0, 1.0)]);
+
+        // `pu` does not match the last applied `u`
+        order_book
+            .update(depth(108, 110, Some(107), vec![], vec![]))
+            .unwrap();
+
+        mock_depth.assert();
+        assert_eq!(order_book.resyncs(), 1);
+        assert!(!order_book.is_synced());
+    }
+}
@@ -0,0 +1,201 @@
+use binance::account::*;
+use binance::api::*;
+use binance::config::*;
+use binance::general::*;
+use binance::model::RateLimit;
+use binance::ratelimit::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use binance::errors::ErrorKind as BinanceLibErrorKind;
+    use mockito::{Matcher, Server};
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    fn seconds(time: SystemTime) -> u64 {
+        time.duration_since(UNIX_EPOCH).unwrap().as_secs()
+    }
+
+    #[test]
+    fn usage_from_headers() {
+        let mut server = Server::new();
+        let mock_ping = server
+            .mock("GET", "/api/v3/ping")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_header("x-mbx-used-weight", "12")
+            .with_header("x-mbx-used-weight-1m", "12")
+            .with_header("x-mbx-order-count-10s", "3")
+            .with_header("x-mbx-order-count-1d", "45")
+            .with_body("{}")
+            .create();
+
+        let rate_limiter = RateLimiter::new();
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_rate_limiter(rate_limiter.clone());
+        let general: General = Binance::new_with_config(None, None, &config);
+        general.ping().unwrap();
+        mock_ping.assert();
+
+        assert_eq!(rate_limiter.used_weight("1m"), Some(12));
+        assert_eq!(rate_limiter.used_weight("1M"), Some(12));
+        assert_eq!(rate_limiter.order_count("1d"), Some(45));
+        assert_eq!(rate_limiter.used_weight("1d"), None);
+
+        let usage = rate_limiter.usage();
+        assert_eq!(usage.used_weight.len(), 1);
+        assert_eq!(usage.order_count.get("1d"), Some(&45));
+        assert!(usage.banned_until.is_none());
+    }
+
+    #[test]
+    fn retry_after() {
+        let mut server = Server::new();
+        let mock_ping = server
+            .mock("GET", "/api/v3/ping")
+            .with_status(429)
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_header("retry-after", "60")
+            .with_body(r#"{"code":-1003,"msg":"Too many requests."}"#)
+            .expect(1)
+            .create();
+
+        let config = Config::default().set_rest_api_endpoint(server.url());
+        let general: General = Binance::new_with_config(None, None, &config);
+
+        match general.ping().unwrap_err().0 {
+            BinanceLibErrorKind::RateLimited(status, retry_after, headers) => {
+                assert_eq!(status, 429);
+                assert_eq!(retry_after, Some(Duration::from_secs(60)));
+                assert_eq!(headers["retry-after"], "60");
+            }
+            other => panic!("Unexpected error {:?}", other),
+        }
+
+        // Not sent until Retry-After has passed
+        match general.ping().unwrap_err().0 {
+            BinanceLibErrorKind::RateLimited(status, retry_after, _) => {
+                assert_eq!(status, 429);
+                assert!(retry_after.unwrap() <= Duration::from_secs(60));
+            }
+            other => panic!("Unexpected error {:?}", other),
+        }
+        mock_ping.assert();
+        assert!(config.rate_limiter.usage().banned_until.is_some());
+    }
+
+    #[test]
+    fn throttle_until_window_resets() {
+        let mut server = Server::new();
+        let mock_ping = server
+            .mock("GET", "/api/v3/ping")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_header("x-mbx-used-weight-1s", "10")
+            .with_body("{}")
+            .expect(2)
+            .create();
+
+        let rate_limiter = RateLimiter::new();
+        rate_limiter.update_limits(&[RateLimit {
+            rate_limit_type: "REQUEST_WEIGHT".into(),
+            interval: "SECOND".into(),
+            interval_num: 1,
+            limit: 10,
+        }]);
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_rate_limiter(rate_limiter);
+        let general: General = Binance::new_with_config(None, None, &config);
+
+        let started = SystemTime::now();
+        general.ping().unwrap();
+        general.ping().unwrap();
+        mock_ping.assert();
+
+        // The second request waited for the next one second window
+        assert!(seconds(SystemTime::now()) > seconds(started));
+    }
+
+    fn orders_limit(interval: &str) -> RateLimiter {
+        let rate_limiter = RateLimiter::new();
+        rate_limiter.update_limits(&[RateLimit {
+            rate_limit_type: "ORDERS".into(),
+            interval: interval.into(),
+            interval_num: 1,
+            limit: 1,
+        }]);
+        rate_limiter
+    }
+
+    #[test]
+    fn orders_limit_only_throttles_orders() {
+        let mut server = Server::new();
+        let mock_order = server
+            .mock("POST", "/api/v3/order")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Any)
+            .with_header("x-mbx-order-count-1m", "1")
+            .with_body_from_file("tests/mocks/account/market_buy.json")
+            .create();
+        let mock_test_order = server
+            .mock("POST", "/api/v3/order/test")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Any)
+            .with_body("{}")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_rate_limiter(orders_limit("MINUTE"));
+        let account: Account = Binance::new_with_config(None, None, &config);
+
+        account.market_buy("LTCBTC", 1).unwrap();
+        let started = SystemTime::now();
+        account.test_market_buy("LTCBTC", 1).unwrap();
+        mock_order.assert();
+        mock_test_order.assert();
+
+        // Test orders do not count as orders, so they skip the rest of the minute
+        assert!(started.elapsed().unwrap() < Duration::from_secs(1));
+    }
+
+    #[test]
+    fn throttled_request_is_stamped_after_the_wait() {
+        let mut server = Server::new();
+        let mock_order = server
+            .mock("POST", "/api/v3/order")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Any)
+            .with_header("x-mbx-order-count-1s", "1")
+            .with_body_from_file("tests/mocks/account/market_buy.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_rate_limiter(orders_limit("SECOND"));
+        let account: Account = Binance::new_with_config(None, None, &config);
+
+        account.market_buy("LTCBTC", 1).unwrap();
+        mock_order.assert();
+        mock_order.remove();
+        let window_end = (seconds(SystemTime::now()) + 1) * 1000;
+        let mock_stamped = server
+            .mock("POST", "/api/v3/order")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Any)
+            .match_request(move |request| {
+                let timestamp = request
+                    .path_and_query()
+                    .split(&['?', '&'][..])
+                    .find_map(|param| param.strip_prefix("timestamp="))
+                    .and_then(|timestamp| timestamp.parse::<u64>().ok());
+                timestamp.map_or(false, |timestamp| timestamp + 100 >= window_end)
+            })
+            .with_body_from_file("tests/mocks/account/market_buy.json")
+            .create();
+        account.market_buy("LTCBTC", 1).unwrap();
+
+        // The second order waited for the next window and was signed with a timestamp from then
+        mock_stamped.assert();
+    }
+}
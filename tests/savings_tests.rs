@@ -0,0 +1,297 @@
+use binance::api::*;
+use binance::config::*;
+use binance::model::*;
+use binance::savings::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{Matcher, Server};
+
+    fn config(server: &Server) -> Config {
+        Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234)
+    }
+
+    #[test]
+    fn withdraw() {
+        let mut server = Server::new();
+        let mock = server
+            .mock("POST", "/sapi/v1/capital/withdraw/apply")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^address=rEb8TK3gBgk5auZkwc6sHnwrGVJH8DuaLh&addressTag=101764890&amount=25.5&coin=XRP&network=XRP&recvWindow=1234&timestamp=\\d+&withdrawOrderId=payout-1&signature=.*"
+                    .into(),
+            ))
+            .with_body(r#"{"id":"7213fea8e94b4a5593d507237e5a555b"}"#)
+            .create();
+
+        let savings: Savings = Binance::new_with_config(None, None, &config(&server));
+        let withdrawal = savings
+            .withdraw(WithdrawRequest {
+                coin: "XRP".into(),
+                address: "rEb8TK3gBgk5auZkwc6sHnwrGVJH8DuaLh".into(),
+                amount: 25.5.into_number(),
+                network: Some("XRP".into()),
+                address_tag: Some("101764890".into()),
+                withdraw_order_id: Some("payout-1".into()),
+                name: None,
+            })
+            .unwrap();
+
+        mock.assert();
+
+        assert_eq!(withdrawal.id, "7213fea8e94b4a5593d507237e5a555b");
+    }
+
+    #[test]
+    fn withdraw_encodes_values() {
+        let mut server = Server::new();
+        let mock = server
+            .mock("POST", "/sapi/v1/capital/withdraw/apply")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^address=rEb8TK3gBgk5auZkwc6sHnwrGVJH8DuaLh&addressTag=101%26764890&amount=25.5&coin=XRP&name=Cold\\+wallet\\+%26\\+co&recvWindow=1234&timestamp=\\d+&signature=.*"
+                    .into(),
+            ))
+            .with_body(r#"{"id":"7213fea8e94b4a5593d507237e5a555b"}"#)
+            .create();
+
+        let savings: Savings = Binance::new_with_config(None, None, &config(&server));
+        savings
+            .withdraw(WithdrawRequest {
+                coin: "XRP".into(),
+                address: "rEb8TK3gBgk5auZkwc6sHnwrGVJH8DuaLh".into(),
+                amount: 25.5.into_number(),
+                network: None,
+                address_tag: Some("101&764890".into()),
+                withdraw_order_id: None,
+                name: Some("Cold wallet & co".into()),
+            })
+            .unwrap();
+
+        // `&` and spaces cannot split or end the parameters
+        mock.assert();
+    }
+
+    #[test]
+    fn universal_transfer() {
+        let mut server = Server::new();
+        let mock_transfer = server
+            .mock("POST", "/sapi/v1/asset/transfer")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^amount=100&asset=USDT&recvWindow=1234&timestamp=\\d+&toSymbol=BTCUSDT&type=MARGIN_ISOLATEDMARGIN&signature=.*"
+                    .into(),
+            ))
+            .with_body(r#"{"tranId":13526853623}"#)
+            .create();
+        let mock_history = server
+            .mock("GET", "/sapi/v1/asset/transfer")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^recvWindow=1234&size=100&timestamp=\\d+&type=MAIN_UMFUTURE&signature=.*".into(),
+            ))
+            .with_body(
+                r#"{"total":1,"rows":[{"asset":"USDT","amount":"1","type":"MAIN_UMFUTURE","status":"CONFIRMED","tranId":11415955596,"timestamp":1544433328000}]}"#,
+            )
+            .create();
+        let mock_empty_history = server
+            .mock("GET", "/sapi/v1/asset/transfer")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("type=FUNDING_MAIN".into()))
+            .with_body(r#"{"total":0}"#)
+            .create();
+
+        let savings: Savings = Binance::new_with_config(None, None, &config(&server));
+
+        let transfer = savings
+            .universal_transfer(
+                UniversalTransferType::MarginIsolatedMargin,
+                "USDT",
+                100,
+                None,
+                Some("BTCUSDT".into()),
+            )
+            .unwrap();
+        assert_eq!(transfer.tran_id, 13526853623);
+
+        let history = savings
+            .universal_transfer_history(
+                UniversalTransferType::MainUmFuture,
+                None,
+                None,
+                None,
+                Some(100),
+            )
+            .unwrap();
+        assert_eq!(history.total, 1);
+        assert_eq!(history.rows[0].transfer_type, "MAIN_UMFUTURE");
+        assert_eq!(history.rows[0].amount, 1.into_number());
+
+        let empty = savings
+            .universal_transfer_history(UniversalTransferType::FundingMain, None, None, None, None)
+            .unwrap();
+        assert!(empty.rows.is_empty());
+
+        mock_transfer.assert();
+        mock_history.assert();
+        mock_empty_history.assert();
+    }
+
+    #[test]
+    fn deposit_history() {
+        let mut server = Server::new();
+        let mock = server
+            .mock("GET", "/sapi/v1/capital/deposit/hisrec")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^recvWindow=1234&startTime=1599620000000&status=1&timestamp=\\d+&signature=.*"
+                    .into(),
+            ))
+            .with_body_from_file("tests/mocks/savings/deposit_history.json")
+            .create();
+
+        let savings: Savings = Binance::new_with_config(None, None, &config(&server));
+        let deposits = savings
+            .deposit_history(
+                None,
+                Some(DepositStatus::Success),
+                Some(1599620000000),
+                None,
+            )
+            .unwrap();
+
+        mock.assert();
+
+        assert_eq!(deposits.len(), 2);
+        assert_eq!(deposits[0].status, DepositStatus::Success);
+        assert_eq!(deposits[0].amount, 0.001.into_number());
+        assert_eq!(deposits[0].address_tag, "101764890");
+        assert_eq!(deposits[1].status, DepositStatus::CreditedCannotWithdraw);
+    }
+
+    #[test]
+    fn withdraw_history() {
+        let mut server = Server::new();
+        let mock = server
+            .mock("GET", "/sapi/v1/capital/withdraw/history")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^coin=USDT&recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/savings/withdraw_history.json")
+            .create();
+
+        let savings: Savings = Binance::new_with_config(None, None, &config(&server));
+        let withdrawals = savings
+            .withdraw_history(Some("USDT".into()), None, None, None)
+            .unwrap();
+
+        mock.assert();
+
+        assert_eq!(withdrawals[0].status, WithdrawStatus::Completed);
+        assert_eq!(withdrawals[0].transaction_fee, 0.004.into_number());
+        assert_eq!(
+            withdrawals[0].withdraw_order_id,
+            Some("WITHDRAWtest123".into())
+        );
+        // Statuses added by the exchange later are kept as is
+        assert_eq!(withdrawals[1].status, WithdrawStatus::Other(9));
+        assert_eq!(withdrawals[1].withdraw_order_id, None);
+    }
+
+    #[test]
+    fn statuses() {
+        let mut server = Server::new();
+        let mock_system = server
+            .mock("GET", "/sapi/v1/system/status")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body(r#"{"status":0,"msg":"normal"}"#)
+            .create();
+        let mock_account = server
+            .mock("GET", "/sapi/v1/account/status")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body(r#"{"data":"Normal"}"#)
+            .create();
+        let mock_api_trading = server
+            .mock("GET", "/sapi/v1/account/apiTradingStatus")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Any)
+            .with_body(
+                r#"{"data":{"isLocked":false,"plannedRecoverTime":0,"triggerCondition":{"GCR":150,"IFER":150,"UFR":300},"updateTime":1547630471725}}"#,
+            )
+            .create();
+
+        let savings: Savings = Binance::new_with_config(None, None, &config(&server));
+
+        let system = savings.system_status().unwrap();
+        assert_eq!(system.status, 0);
+        assert_eq!(system.msg, "normal");
+
+        let account = savings.account_status().unwrap();
+        assert_eq!(account.data, "Normal");
+
+        let api_trading = savings.api_trading_status().unwrap();
+        assert!(!api_trading.data.is_locked);
+        assert_eq!(api_trading.data.trigger_condition.ufr, 300);
+
+        mock_system.assert();
+        mock_account.assert();
+        mock_api_trading.assert();
+    }
+
+    #[test]
+    fn account_snapshot() {
+        let mut server = Server::new();
+        let mock_spot = server
+            .mock("GET", "/sapi/v1/accountSnapshot")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^limit=7&recvWindow=1234&timestamp=\\d+&type=SPOT&signature=.*".into(),
+            ))
+            .with_body_from_file("tests/mocks/savings/account_snapshot_spot.json")
+            .create();
+        let mock_futures = server
+            .mock("GET", "/sapi/v1/accountSnapshot")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("type=FUTURES".into()))
+            .with_body_from_file("tests/mocks/savings/account_snapshot_futures.json")
+            .create();
+
+        let savings: Savings = Binance::new_with_config(None, None, &config(&server));
+
+        let spot = savings
+            .account_snapshot(AccountSnapshotType::Spot, None, None, Some(7))
+            .unwrap();
+        match &spot.snapshot_vos[0].data {
+            SnapshotData::Spot(data) => {
+                assert_eq!(data.balances[0].asset, "BTC");
+                assert_eq!(data.total_asset_of_btc, 0.099427.into_number());
+            }
+            other => panic!("Unexpected snapshot {:?}", other),
+        }
+
+        let futures = savings
+            .account_snapshot(AccountSnapshotType::Futures, None, None, None)
+            .unwrap();
+        assert_eq!(futures.snapshot_vos[0].snapshot_type, "futures");
+        match &futures.snapshot_vos[0].data {
+            SnapshotData::Futures(data) => {
+                assert_eq!(data.assets[0].wallet_balance, 120.23811389.into_number());
+                assert_eq!(
+                    data.position[0].un_realized_profit,
+                    1.24029054.into_number()
+                );
+            }
+            other => panic!("Unexpected snapshot {:?}", other),
+        }
+
+        mock_spot.assert();
+        mock_futures.assert();
+    }
+}
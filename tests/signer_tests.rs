@@ -0,0 +1,90 @@
+use binance::api::*;
+use binance::account::*;
+use binance::config::*;
+use binance::signer::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{Matcher, Server};
+
+    // Example of the Binance API documentation for SIGNED endpoints
+    const SECRET_KEY: &str = "NhqPtmdSJYdKjVHjA7PZj4Mge3R5YNiP1e3UZjInClVN65XAbvqqM6A7H5fATj0j";
+    const PAYLOAD: &str = "symbol=LTCBTC&side=BUY&type=LIMIT&timeInForce=GTC&quantity=1&price=0.1&recvWindow=5000&timestamp=1499827319559";
+
+    fn read_key(name: &str) -> String {
+        std::fs::read_to_string(format!("tests/mocks/keys/{}", name)).unwrap()
+    }
+
+    #[test]
+    fn hmac() {
+        let signature = Signer::default().sign(SECRET_KEY, PAYLOAD);
+
+        assert_eq!(
+            signature,
+            "c8db56825ae71d6d79447849e617115f4a920fa2acdcab2b053c4b2838bd6b71"
+        );
+    }
+
+    #[test]
+    fn ed25519() {
+        // RFC 8032 test vector 2: the key is stored as PKCS#8 and the message is 0x72
+        let signer = Signer::ed25519_from_pem(&read_key("ed25519.pem")).unwrap();
+        let signature = signer.sign("", "r");
+
+        assert_eq!(
+            signature,
+            "kqAJqfDUyrhyDoILX2QlQKKye1QWUD+Ps3YiI+vbadoIWsHkPhWZbkWPNhPQ8R2MOHsurrQwKu6wDSkWErsMAA=="
+        );
+
+        // Same signature as `openssl pkeyutl -sign -inkey ed25519.pem -rawin | base64`, left
+        // base64 encoded with its `+` and `/` until the request is built
+        assert_eq!(
+            signer.sign("", PAYLOAD),
+            "lFDGHBVP+dB0GtSkCpB3pYr9MpXhFRYvPqjq6EaqXq23KZxPF3u+HH0AAcB+CyRfAFWkUmrZLEIF9irkiuI8BA=="
+        );
+    }
+
+    #[test]
+    fn rsa() {
+        // Same signature as `openssl dgst -sha256 -sign rsa.pem | base64`
+        let signer = Signer::rsa_from_pem(&read_key("rsa.pem")).unwrap();
+        let signature = signer.sign("", PAYLOAD);
+
+        assert_eq!(
+            signature,
+            "IAFTFkC7gRkHsyRwpXD8jYNolkNTH+ZGKzi/aQYo/vZ1uva6/KukfXu9Hz9movA2uBsm/+CGml9ca/hMhDek2UXNt3xxi1qRvVowzJ2LM9tNTEEe0AkUqP6nM1dpqP3iHyHqvAKf01UDDxRf19WPxvvYqkdDO4JLfPsyI/UpxPUOfO+7jUBQJdlKkq42/M/rbreTq2pDonCYj9ZUdNJYbyQYpIwmEXcAXN6Efzxow2zovpvFZHLwnd1s8ubSrWZF51AQ2ZC04YPmnqyUZuPVvDGCyVfWnqh91Xtbbg8s/Shv2cvfqJ1Nw6m6ECyjhyv0T/KKugRVSifyEbk9u4NrFA=="
+        );
+    }
+
+    #[test]
+    fn invalid_keys() {
+        assert!(Signer::ed25519_from_pem(&read_key("rsa.pem")).is_err());
+        assert!(Signer::rsa_from_pem(&read_key("ed25519.pem")).is_err());
+        assert!(Signer::rsa_from_pem("not a key").is_err());
+    }
+
+    #[test]
+    fn signed_request_with_ed25519() {
+        let mut server = Server::new();
+        let mock_get_account = server
+            .mock("GET", "/api/v3/account")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "recvWindow=1234&timestamp=\\d+&signature=[A-Za-z0-9%]+%3D%3D$".into(),
+            ))
+            .match_header("x-mbx-apikey", "api_key")
+            .with_body_from_file("tests/mocks/account/get_account.json")
+            .create();
+
+        let signer = Signer::ed25519_from_pem(&read_key("ed25519.pem")).unwrap();
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234)
+            .set_signer(signer);
+        let account: Account = Binance::new_with_config(Some("api_key".into()), None, &config);
+        account.get_account().unwrap();
+
+        mock_get_account.assert();
+    }
+}
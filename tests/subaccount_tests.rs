@@ -0,0 +1,117 @@
+use binance::api::*;
+use binance::config::*;
+use binance::model::*;
+use binance::subaccount::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{Matcher, Server};
+
+    fn config(server: &Server) -> Config {
+        Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234)
+    }
+
+    #[test]
+    fn sub_accounts_and_balances() {
+        let mut server = Server::new();
+        let mock_list = server
+            .mock("GET", "/sapi/v1/sub-account/list")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^limit=200&page=1&recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body(
+                r#"{"subAccounts":[{"email":"desk1@test.com","isFreeze":false,"createTime":1544433328000,"isManagedSubAccount":false,"isAssetManagementSubAccount":false},{"email":"desk2@test.com","isFreeze":true,"createTime":1544433328000,"isManagedSubAccount":false,"isAssetManagementSubAccount":false}]}"#,
+            )
+            .create();
+        let mock_assets = server
+            .mock("GET", "/sapi/v3/sub-account/assets")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^email=desk1%40test.com&recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .with_body(
+                r#"{"balances":[{"asset":"ADA","free":10000,"locked":0},{"asset":"BTC","free":11.5,"locked":0.25}]}"#,
+            )
+            .create();
+
+        let sub_account: SubAccount = Binance::new_with_config(None, None, &config(&server));
+
+        let sub_accounts = sub_account
+            .get_sub_accounts(None, Some(1), Some(200))
+            .unwrap();
+        assert_eq!(sub_accounts.len(), 2);
+        assert_eq!(sub_accounts[0].email, "desk1@test.com");
+        assert!(sub_accounts[1].is_freeze);
+
+        let balances = sub_account.get_balances("desk1@test.com").unwrap();
+        assert_eq!(balances[1].asset, "BTC");
+        assert_eq!(balances[1].free, 11.5.into_number());
+        assert_eq!(balances[1].locked, 0.25.into_number());
+
+        mock_list.assert();
+        mock_assets.assert();
+    }
+
+    #[test]
+    fn master_to_sub_transfer() {
+        let mut server = Server::new();
+        let mock_transfer = server
+            .mock("POST", "/sapi/v1/sub-account/universalTransfer")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^amount=250&asset=USDT&clientTranId=desk1-funding&fromAccountType=SPOT&recvWindow=1234&timestamp=\\d+&toAccountType=USDT_FUTURE&toEmail=desk1%2Bfutures%40test.com&signature=.*"
+                    .into(),
+            ))
+            .with_body(r#"{"tranId":11945860693,"clientTranId":"desk1-funding"}"#)
+            .create();
+        let mock_history = server
+            .mock("GET", "/sapi/v1/sub-account/universalTransfer")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "^recvWindow=1234&timestamp=\\d+&toEmail=desk1%2Bfutures%40test.com&signature=.*".into(),
+            ))
+            .with_body(
+                r#"{"result":[{"tranId":11945860693,"fromEmail":"master@test.com","toEmail":"desk1+futures@test.com","asset":"USDT","amount":"250","createTimeStamp":1614664395000,"fromAccountType":"SPOT","toAccountType":"USDT_FUTURE","status":"SUCCESS","clientTranId":"desk1-funding"}],"totalCount":1}"#,
+            )
+            .create();
+
+        let sub_account: SubAccount = Binance::new_with_config(None, None, &config(&server));
+
+        let transfer = sub_account
+            .transfer(SubAccountTransferRequest {
+                from_email: None,
+                to_email: Some("desk1+futures@test.com".into()),
+                from_account_type: SubAccountType::Spot,
+                to_account_type: SubAccountType::UsdtFuture,
+                asset: "USDT".into(),
+                amount: 250.into_number(),
+                symbol: None,
+                client_tran_id: Some("desk1-funding".into()),
+            })
+            .unwrap();
+        assert_eq!(transfer.tran_id, 11945860693);
+        assert_eq!(transfer.client_tran_id, Some("desk1-funding".into()));
+
+        let history = sub_account
+            .transfer_history(
+                None,
+                Some("desk1+futures@test.com".into()),
+                None,
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+        assert_eq!(history.total_count, 1);
+        assert_eq!(history.result[0].from_email, "master@test.com");
+        assert_eq!(history.result[0].amount, 250.into_number());
+        assert_eq!(history.result[0].to_account_type, "USDT_FUTURE");
+
+        mock_transfer.assert();
+        mock_history.assert();
+    }
+}
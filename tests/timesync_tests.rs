@@ -0,0 +1,118 @@
+use binance::api::*;
+use binance::account::*;
+use binance::config::*;
+use binance::futures::account::*;
+use binance::general::*;
+use binance::timesync::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{Matcher, Request, Server};
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    const OFFSET: i64 = 3_600_000;
+
+    fn now() -> i64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_millis() as i64
+    }
+
+    fn server_time() -> String {
+        format!(r#"{{"serverTime":{}}}"#, now() + OFFSET)
+    }
+
+    // The timestamp parameter is within a few seconds of the server time
+    fn synced_timestamp(request: &Request) -> bool {
+        request
+            .path_and_query()
+            .split(&['?', '&'][..])
+            .filter_map(|param| param.strip_prefix("timestamp="))
+            .filter_map(|timestamp| timestamp.parse::<i64>().ok())
+            .any(|timestamp| (timestamp - now() - OFFSET).abs() < 5_000)
+    }
+
+    #[test]
+    fn sync() {
+        let mut server = Server::new();
+        let mock_time = server
+            .mock("GET", "/api/v3/time")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body(server_time())
+            .create();
+
+        let config = Config::default().set_rest_api_endpoint(server.url());
+        let general: General = Binance::new_with_config(None, None, &config);
+        let time_sync = TimeSync::default();
+        assert!(!time_sync.is_enabled());
+        assert_eq!(time_sync.offset(), None);
+
+        let offset = time_sync.sync(|| general.get_server_time()).unwrap();
+        mock_time.assert();
+        assert!((offset - OFFSET).abs() < 5_000);
+        assert_eq!(time_sync.offset(), Some(offset));
+    }
+
+    #[test]
+    fn signed_requests_use_server_time() {
+        let mut server = Server::new();
+        let mock_time = server
+            .mock("GET", "/api/v3/time")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body(server_time())
+            .expect(1)
+            .create();
+        let mock_get_account = server
+            .mock("GET", "/api/v3/account")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex(
+                "recvWindow=1234&timestamp=\\d+&signature=.*".into(),
+            ))
+            .match_request(synced_timestamp)
+            .with_body_from_file("tests/mocks/account/get_account.json")
+            .expect(2)
+            .create();
+
+        let time_sync = TimeSync::new(Duration::from_secs(600));
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234)
+            .set_time_sync(time_sync.clone());
+        let account: Account = Binance::new_with_config(None, None, &config);
+        account.get_account().unwrap();
+        account.get_account().unwrap();
+
+        // Synced once, until the refresh interval has passed
+        mock_time.assert();
+        mock_get_account.assert();
+        assert!((time_sync.offset().unwrap() - OFFSET).abs() < 5_000);
+    }
+
+    #[test]
+    fn futures_signed_requests_use_futures_server_time() {
+        let mut server = Server::new();
+        let mock_time = server
+            .mock("GET", "/fapi/v1/time")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body(server_time())
+            .create();
+        let mock_change_leverage = server
+            .mock("POST", "/fapi/v1/leverage")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Any)
+            .match_request(synced_timestamp)
+            .with_body(r#"{"leverage":2,"maxNotionalValue":"100000","symbol":"LTCUSDT"}"#)
+            .create();
+
+        let config = Config::default()
+            .set_futures_rest_api_endpoint(server.url())
+            .set_time_sync(TimeSync::new(Duration::from_secs(600)));
+        let account: FuturesAccount = Binance::new_with_config(None, None, &config);
+        account.change_initial_leverage("LTCUSDT", 2).unwrap();
+
+        mock_time.assert();
+        mock_change_leverage.assert();
+    }
+}
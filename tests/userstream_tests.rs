@@ -0,0 +1,176 @@
+use binance::api::*;
+use binance::config::*;
+use binance::futures::userstream::*;
+use binance::futures::websockets::*;
+use binance::userstream::*;
+use binance::websockets::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{Matcher, Server};
+    use std::net::TcpListener;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+    use std::time::Duration;
+    use tungstenite::handshake::server::{Request, Response};
+    use tungstenite::{accept_hdr, Message};
+
+    const TRADE_EVENT: &str = r#"{"e":"trade","E":1672515782136,"s":"BNBBTC","t":12345,"p":"0.001","q":"100","b":88,"a":50,"T":1672515782136,"m":true,"M":true}"#;
+    const EXPIRED_EVENT: &str = r#"{"e":"listenKeyExpired","E":1576653824250}"#;
+
+    // Serves one connection per entry after its delay: sends its frames, then closes it when
+    // `close` is set. Returns the endpoint and the paths requested.
+    fn serve(
+        connections: Vec<(Duration, Vec<&'static str>, bool)>,
+    ) -> (String, Arc<Mutex<Vec<String>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let endpoint = format!("ws://{}/ws", listener.local_addr().unwrap());
+        let paths = Arc::new(Mutex::new(vec![]));
+        let requested = paths.clone();
+
+        thread::spawn(move || {
+            for (delay, frames, close) in connections {
+                let (stream, _) = listener.accept().unwrap();
+                let callback = |request: &Request, response: Response| {
+                    requested
+                        .lock()
+                        .unwrap()
+                        .push(request.uri().path().to_string());
+                    Ok(response)
+                };
+                let mut socket = accept_hdr(stream, callback).unwrap();
+                thread::sleep(delay);
+                for frame in frames {
+                    socket.send(Message::Text(frame.into())).unwrap();
+                }
+                if close {
+                    socket.close(None).unwrap();
+                }
+                while socket.read().is_ok() {}
+            }
+        });
+        (endpoint, paths)
+    }
+
+    fn fast_reconnect() -> ReconnectConfig {
+        ReconnectConfig::default()
+            .set_initial_backoff(Duration::from_millis(20))
+            .set_jitter(0.0)
+    }
+
+    #[test]
+    fn renew_expired_listen_key() {
+        let mut server = Server::new();
+        let mock_start = server
+            .mock("POST", "/api/v3/userDataStream")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body(r#"{"listenKey":"pqia91ma19a5s61cv6a81va65sdf19v8a65a1a5s61cv6a81va65sdf19v8a65a1"}"#)
+            .expect(2)
+            .create();
+        let mock_keep_alive = server
+            .mock("PUT", "/api/v3/userDataStream")
+            .match_body(Matcher::Regex("listenKey=pqia91ma19a5s61".into()))
+            .with_body("{}")
+            .expect_at_least(1)
+            .create();
+        let mock_close = server
+            .mock("DELETE", "/api/v3/userDataStream")
+            .with_body("{}")
+            .expect(1)
+            .create();
+
+        let (endpoint, paths) = serve(vec![
+            (Duration::ZERO, vec![TRADE_EVENT, EXPIRED_EVENT], false),
+            (Duration::from_millis(200), vec![TRADE_EVENT], false),
+        ]);
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_ws_endpoint(endpoint);
+        let user_stream: UserStream = Binance::new_with_config(None, None, &config);
+
+        let running = AtomicBool::new(true);
+        let mut events = Vec::new();
+        let mut session =
+            UserDataSession::new_with_config(user_stream, &config, |event: WebsocketEvent| {
+                events.push(event);
+                if events.len() == 4 {
+                    running.store(false, Ordering::Relaxed);
+                }
+                Ok(())
+            })
+            .with_keep_alive_interval(Duration::from_millis(20))
+            .with_reconnect(fast_reconnect());
+        session.event_loop(&running).unwrap();
+
+        assert_eq!(session.stats().connects, 2);
+        assert_eq!(session.stats().reconnects, 1);
+        assert_eq!(session.listen_key(), None);
+        drop(session);
+
+        mock_start.assert();
+        mock_keep_alive.assert();
+        mock_close.assert();
+        assert_eq!(
+            paths.lock().unwrap()[1],
+            "/ws/pqia91ma19a5s61cv6a81va65sdf19v8a65a1a5s61cv6a81va65sdf19v8a65a1"
+        );
+
+        assert!(matches!(events[0], WebsocketEvent::Trade(_)));
+        assert!(matches!(
+            events[1],
+            WebsocketEvent::UserDataStreamExpiredEvent(_)
+        ));
+        assert!(matches!(events[2], WebsocketEvent::Reconnected(_)));
+        assert!(matches!(events[3], WebsocketEvent::Trade(_)));
+    }
+
+    #[test]
+    fn futures_reconnect_after_close() {
+        let mut server = Server::new();
+        let mock_start = server
+            .mock("POST", "/fapi/v1/listenKey")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body(r#"{"listenKey":"futures_listen_key"}"#)
+            .expect(2)
+            .create();
+        let mock_close = server
+            .mock("DELETE", "/fapi/v1/listenKey")
+            .with_body("{}")
+            .expect(1)
+            .create();
+
+        let (endpoint, paths) = serve(vec![
+            (Duration::ZERO, vec![], true),
+            (Duration::ZERO, vec![TRADE_EVENT], false),
+        ]);
+        let config = Config::default()
+            .set_futures_rest_api_endpoint(server.url())
+            .set_futures_ws_endpoint(endpoint);
+        let user_stream: FuturesUserStream = Binance::new_with_config(None, None, &config);
+
+        let running = AtomicBool::new(true);
+        let mut events = Vec::new();
+        let mut session = UserDataSession::new_with_config(
+            user_stream,
+            &config,
+            |event: FuturesWebsocketEvent| {
+                events.push(event);
+                if events.len() == 2 {
+                    running.store(false, Ordering::Relaxed);
+                }
+                Ok(())
+            },
+        )
+        .with_reconnect(fast_reconnect());
+        session.event_loop(&running).unwrap();
+        drop(session);
+
+        mock_start.assert();
+        mock_close.assert();
+        assert_eq!(paths.lock().unwrap()[0], "/ws/futures_listen_key");
+        assert!(matches!(events[0], FuturesWebsocketEvent::Reconnected(_)));
+        assert!(matches!(events[1], FuturesWebsocketEvent::Trade(_)));
+    }
+}
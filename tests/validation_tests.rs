@@ -0,0 +1,229 @@
+use binance::api::*;
+use binance::config::*;
+use binance::account::*;
+use binance::futures::general::FuturesGeneral;
+use binance::general::*;
+use binance::model::*;
+use binance::validation::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use binance::errors::ErrorKind as BinanceLibErrorKind;
+    use binance::errors::Error;
+    use binance::futures;
+    use mockito::{Server, Matcher};
+
+    fn spot_validator(server: &mut Server) -> OrderValidator {
+        let mock_exchange_info = server
+            .mock("GET", "/api/v3/exchangeInfo")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("tests/mocks/general/exchange_info.json")
+            .create();
+
+        let config = Config::default().set_rest_api_endpoint(server.url());
+        let general: General = Binance::new_with_config(None, None, &config);
+        let validator = OrderValidator::spot(&general.exchange_info().unwrap()).unwrap();
+        mock_exchange_info.assert();
+        validator
+    }
+
+    fn violation(error: Error) -> FilterViolation {
+        match error.0 {
+            BinanceLibErrorKind::FilterError(violation) => violation,
+            other => panic!("Unexpected error {:?}", other),
+        }
+    }
+
+    #[test]
+    fn spot_filters() {
+        let mut server = Server::new();
+        let validator = spot_validator(&mut server);
+
+        let price = validator
+            .check_price("LTCBTC", 0.012345.into_number())
+            .unwrap();
+        assert_eq!(price, 0.012345.into_number());
+
+        let error = validator
+            .check_price("LTCBTC", 0.0123456.into_number())
+            .unwrap_err();
+        assert_eq!(
+            violation(error),
+            FilterViolation::PriceFilter {
+                price: 0.0123456.into_number(),
+                min_price: 0.000001.into_number(),
+                max_price: 100000.0.into_number(),
+                tick_size: 0.000001.into_number(),
+            }
+        );
+
+        let error = validator
+            .check_qty("LTCBTC", 0.005.into_number())
+            .unwrap_err();
+        assert_eq!(violation(error).filter_type(), "LOT_SIZE");
+
+        let error = validator
+            .check_market_qty("LTCBTC", 20000.0.into_number())
+            .unwrap_err();
+        assert!(matches!(
+            violation(error),
+            FilterViolation::MarketLotSize { .. }
+        ));
+
+        let error = validator
+            .check_notional("LTCBTC", 0.001.into_number(), 0.05.into_number())
+            .unwrap_err();
+        assert_eq!(
+            violation(error),
+            FilterViolation::MinNotional {
+                notional: 0.00005.into_number(),
+                min_notional: 0.0001.into_number(),
+            }
+        );
+
+        let error = validator
+            .check_price("XRPBTC", 0.1.into_number())
+            .unwrap_err();
+        assert_eq!(
+            violation(error),
+            FilterViolation::UnknownSymbol("XRPBTC".into())
+        );
+    }
+
+    #[test]
+    fn spot_rounding() {
+        let mut server = Server::new();
+        let validator = spot_validator(&mut server).set_rounding(true);
+
+        let price = validator
+            .check_price("LTCBTC", 0.0123456.into_number())
+            .unwrap();
+        assert_eq!(price.to_string(), "0.012345");
+        let qty = validator.check_qty("ltcbtc", 1.237.into_number()).unwrap();
+        assert_eq!(qty.to_string(), "1.23");
+
+        // Rounding down can still leave the quantity below the minimum
+        let error = validator
+            .check_qty("LTCBTC", 0.009.into_number())
+            .unwrap_err();
+        assert_eq!(violation(error).filter_type(), "LOT_SIZE");
+    }
+
+    #[test]
+    fn custom_order_rejected_by_filter() {
+        let mut server = Server::new();
+        let validator = spot_validator(&mut server);
+        let mock_custom_order = server
+            .mock("POST", "/api/v3/order")
+            .match_query(Matcher::Any)
+            .expect(0)
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: Account = Binance::new_with_config(None, None, &config);
+        let account = account.with_validator(validator);
+        let error = account
+            .custom_order(
+                "LTCBTC",
+                1.237,
+                0.1,
+                None,
+                OrderSide::Buy,
+                OrderType::Limit,
+                TimeInForce::GTC,
+                None,
+            )
+            .unwrap_err();
+
+        mock_custom_order.assert();
+        assert!(matches!(violation(error), FilterViolation::LotSize { .. }));
+    }
+
+    #[test]
+    fn custom_order_rounded() {
+        let mut server = Server::new();
+        let validator = spot_validator(&mut server).set_rounding(true);
+        let mock_custom_order = server
+            .mock("POST", "/api/v3/order")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .match_query(Matcher::Regex("price=0.1&quantity=1.23&recvWindow=1234&side=BUY&symbol=LTCBTC&timeInForce=GTC&timestamp=\\d+&type=LIMIT".into()))
+            .with_body_from_file("tests/mocks/account/limit_buy.json")
+            .create();
+
+        let config = Config::default()
+            .set_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let account: Account = Binance::new_with_config(None, None, &config);
+        let account = account.with_validator(validator);
+        let transaction = account
+            .custom_order(
+                "LTCBTC",
+                1.237,
+                0.1000004,
+                None,
+                OrderSide::Buy,
+                OrderType::Limit,
+                TimeInForce::GTC,
+                None,
+            )
+            .unwrap();
+
+        mock_custom_order.assert();
+        assert_eq!(transaction.symbol, "LTCBTC");
+    }
+
+    #[test]
+    fn usdm_custom_order_rejected_by_filter() {
+        let mut server = Server::new();
+        let mock_exchange_info = server
+            .mock("GET", "/fapi/v1/exchangeInfo")
+            .with_header("content-type", "application/json;charset=UTF-8")
+            .with_body_from_file("tests/mocks/futures/general/exchange_info.json")
+            .create();
+        let mock_custom_order = server
+            .mock("POST", "/fapi/v1/order")
+            .match_query(Matcher::Any)
+            .expect(0)
+            .create();
+
+        let config = Config::default()
+            .set_futures_rest_api_endpoint(server.url())
+            .set_recv_window(1234);
+        let general: FuturesGeneral = Binance::new_with_config(None, None, &config);
+        let validator = OrderValidator::usdm(&general.exchange_info().unwrap()).unwrap();
+        mock_exchange_info.assert();
+
+        let account: futures::account::FuturesAccount =
+            Binance::new_with_config(None, None, &config);
+        let account = account.with_validator(validator);
+        let order = futures::account::CustomOrderRequest {
+            symbol: "BTCUSDT".into(),
+            side: OrderSide::Buy,
+            position_side: None,
+            order_type: futures::account::OrderType::Limit,
+            time_in_force: Some(futures::account::TimeInForce::GTC),
+            qty: Some(0.001.into_number()),
+            reduce_only: None,
+            price: Some(20000.0.into_number()),
+            stop_price: None,
+            close_position: None,
+            activation_price: None,
+            callback_rate: None,
+            working_type: None,
+            price_protect: None,
+        };
+        let error = account.custom_order(order).unwrap_err();
+
+        mock_custom_order.assert();
+        assert_eq!(
+            violation(error),
+            FilterViolation::MinNotional {
+                notional: 20.0.into_number(),
+                min_notional: 100.0.into_number(),
+            }
+        );
+    }
+}
@@ -0,0 +1,157 @@
+use binance::config::*;
+use binance::futures::websockets::*;
+use binance::websockets::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::TcpListener;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::thread;
+    use std::time::Duration;
+    use tungstenite::{accept, Message};
+
+    const TRADE_EVENT: &str = r#"{"e":"trade","E":1672515782136,"s":"BNBBTC","t":12345,"p":"0.001","q":"100","b":88,"a":50,"T":1672515782136,"m":true,"M":true}"#;
+
+    // Serves one connection per entry: sends its frames, then closes it unless it is the last one
+    // and `keep_last_open` is set.
+    fn serve(connections: Vec<Vec<&'static str>>, keep_last_open: bool) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let endpoint = format!("ws://{}", listener.local_addr().unwrap());
+        thread::spawn(move || {
+            let last = connections.len() - 1;
+            for (index, frames) in connections.into_iter().enumerate() {
+                let (stream, _) = listener.accept().unwrap();
+                let mut socket = accept(stream).unwrap();
+                for frame in frames {
+                    socket.send(Message::Text(frame.into())).unwrap();
+                }
+                if index != last || !keep_last_open {
+                    socket.close(None).unwrap();
+                }
+                while socket.read().is_ok() {}
+            }
+        });
+        endpoint
+    }
+
+    fn fast_reconnect() -> ReconnectConfig {
+        ReconnectConfig::default()
+            .set_initial_backoff(Duration::from_millis(20))
+            .set_jitter(0.0)
+    }
+
+    #[test]
+    fn backoff() {
+        let reconnect = ReconnectConfig::default()
+            .set_initial_backoff(Duration::from_millis(100))
+            .set_max_backoff(Duration::from_millis(1000))
+            .set_jitter(0.0);
+
+        assert_eq!(reconnect.backoff(1), Duration::from_millis(100));
+        assert_eq!(reconnect.backoff(2), Duration::from_millis(200));
+        assert_eq!(reconnect.backoff(4), Duration::from_millis(800));
+        assert_eq!(reconnect.backoff(5), Duration::from_millis(1000));
+        assert_eq!(reconnect.backoff(u32::MAX), Duration::from_millis(1000));
+
+        let reconnect = reconnect.set_jitter(0.5);
+        for _ in 0..20 {
+            let delay = reconnect.backoff(2);
+            assert!(delay >= Duration::from_millis(100) && delay <= Duration::from_millis(200));
+        }
+
+        // Invalid settings fall back to the maximum instead of panicking
+        let reconnect = reconnect.set_multiplier(-2.0).set_jitter(f64::NAN);
+        assert_eq!(reconnect.backoff(1), Duration::from_millis(100));
+        assert_eq!(reconnect.backoff(2), Duration::from_millis(1000));
+        let reconnect = reconnect.set_multiplier(f64::NAN);
+        assert_eq!(reconnect.backoff(2), Duration::from_millis(1000));
+        let reconnect = reconnect.set_multiplier(f64::INFINITY);
+        assert_eq!(reconnect.backoff(3), Duration::from_millis(1000));
+    }
+
+    #[test]
+    fn reconnect_after_close() {
+        let endpoint = serve(vec![vec![TRADE_EVENT], vec![TRADE_EVENT]], true);
+        let config = Config::default().set_ws_endpoint(endpoint);
+        let running = AtomicBool::new(true);
+        let mut events = Vec::new();
+
+        let mut web_socket = WebSockets::new(|event: WebsocketEvent| {
+            events.push(event);
+            if events.len() == 3 {
+                running.store(false, Ordering::Relaxed);
+            }
+            Ok(())
+        })
+        .with_reconnect(fast_reconnect());
+        web_socket
+            .connect_with_config("bnbbtc@trade", &config)
+            .unwrap();
+        web_socket.event_loop(&running).unwrap();
+
+        let stats = web_socket.stats().clone();
+        assert_eq!(stats.connects, 2);
+        assert_eq!(stats.reconnects, 1);
+        assert_eq!(stats.failed_attempts, 0);
+        assert_eq!(stats.messages_received, 2);
+        assert!(stats.last_disconnected_at.is_some());
+        drop(web_socket);
+
+        assert!(matches!(events[0], WebsocketEvent::Trade(_)));
+        match &events[1] {
+            WebsocketEvent::Reconnected(reconnected) => {
+                assert_eq!(reconnected.attempts, 1);
+                assert_eq!(reconnected.reconnects, 1);
+            }
+            other => panic!("Unexpected event {:?}", other),
+        }
+        assert!(matches!(events[2], WebsocketEvent::Trade(_)));
+    }
+
+    #[test]
+    fn reconnect_gives_up_after_max_attempts() {
+        let endpoint = serve(vec![vec![]], false);
+        let config = Config::default().set_ws_endpoint(endpoint);
+        let running = AtomicBool::new(true);
+
+        let mut web_socket = WebSockets::new(|_event: WebsocketEvent| Ok(()))
+            .with_reconnect(fast_reconnect().set_max_attempts(Some(2)));
+        web_socket
+            .connect_with_config("bnbbtc@trade", &config)
+            .unwrap();
+        assert!(web_socket.event_loop(&running).is_err());
+
+        let stats = web_socket.stats();
+        assert_eq!(stats.connects, 1);
+        assert_eq!(stats.reconnects, 0);
+        assert_eq!(stats.failed_attempts, 2);
+    }
+
+    #[test]
+    fn futures_reconnect_after_close() {
+        let endpoint = serve(vec![vec![], vec![TRADE_EVENT]], true);
+        let config = Config::default().set_ws_endpoint(endpoint);
+        let running = AtomicBool::new(true);
+        let mut events = Vec::new();
+
+        let mut web_socket = FuturesWebSockets::new(|event: FuturesWebsocketEvent| {
+            events.push(event);
+            if events.len() == 2 {
+                running.store(false, Ordering::Relaxed);
+            }
+            Ok(())
+        })
+        .with_reconnect(fast_reconnect());
+        web_socket
+            .connect_with_config(&FuturesMarket::USDM, "bnbbtc@trade", &config)
+            .unwrap();
+        // The futures event loop reports being stopped as an error
+        assert!(web_socket.event_loop(&running).is_err());
+        assert_eq!(web_socket.stats().reconnects, 1);
+        drop(web_socket);
+
+        assert!(matches!(events[0], FuturesWebsocketEvent::Reconnected(_)));
+        assert!(matches!(events[1], FuturesWebsocketEvent::Trade(_)));
+    }
+}
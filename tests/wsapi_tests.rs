@@ -0,0 +1,288 @@
+use binance::account::*;
+use binance::config::*;
+use binance::signer::*;
+use binance::timesync::*;
+use binance::wsapi::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use binance::errors::ErrorKind as BinanceLibErrorKind;
+    use binance::model::IntoNumber;
+    use serde_json::{json, Value};
+    use std::collections::BTreeMap;
+    use std::net::TcpListener;
+    use std::thread::{self, JoinHandle};
+    use std::time::Duration;
+    use tungstenite::{accept, Message};
+
+    fn mock(name: &str) -> Value {
+        let body = std::fs::read_to_string(format!("tests/mocks/{}", name)).unwrap();
+        serde_json::from_str(&body).unwrap()
+    }
+
+    fn ok(result: Value) -> Value {
+        json!({"status": 200, "result": result})
+    }
+
+    // Answers requests `batch` at a time, in reverse order, with the response `respond` gives
+    // for their method and params. Returns the requests received once the client disconnects.
+    fn serve(batch: usize, respond: fn(&str, &Value) -> Value) -> (Config, JoinHandle<Vec<Value>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let endpoint = format!("ws://{}", listener.local_addr().unwrap());
+
+        let server = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut socket = accept(stream).unwrap();
+            let mut received = vec![];
+            let mut pending = vec![];
+            loop {
+                let request: Value = match socket.read() {
+                    Ok(Message::Text(msg)) => serde_json::from_str(&msg).unwrap(),
+                    Ok(_) => continue,
+                    Err(_) => return received,
+                };
+                received.push(request.clone());
+                pending.push(request);
+                if pending.len() < batch {
+                    continue;
+                }
+                while let Some(request) = pending.pop() {
+                    let params = request.get("params").cloned().unwrap_or(Value::Null);
+                    let mut response = respond(request["method"].as_str().unwrap(), &params);
+                    response["id"] = request["id"].clone();
+                    socket.send(Message::Text(response.to_string())).unwrap();
+                }
+            }
+        });
+
+        let config = Config::default()
+            .set_ws_api_endpoint(endpoint)
+            .set_recv_window(1234);
+        (config, server)
+    }
+
+    fn respond(method: &str, params: &Value) -> Value {
+        match method {
+            "order.place" => ok(mock("account/limit_buy.json")),
+            "order.status" => ok(mock("account/order_status.json")),
+            "order.cancel" if params["orderId"] == "1" => ok(mock("account/cancel_order.json")),
+            "order.cancel" => json!({
+                "status": 400,
+                "error": {"code": -2011, "msg": "Unknown order sent."}
+            }),
+            "account.status" => ok(mock("account/get_account.json")),
+            "ticker.price" => ok(json!({"symbol": params["symbol"], "price": "0.1"})),
+            "time" => ok(json!({"serverTime": 1656400526260_u64})),
+            "session.logon" => ok(json!({
+                "apiKey": "api_key",
+                "authorizedSince": 1649729878532_u64,
+                "connectedSince": 1649729873021_u64,
+                "returnRateLimits": false,
+                "serverTime": 1649729878630_u64
+            })),
+            _ => json!({
+                "status": 429,
+                "error": {"code": -1003, "msg": "Too many requests.", "data": {"serverTime": 1, "retryAfter": 1}}
+            }),
+        }
+    }
+
+    #[test]
+    fn orders() {
+        let (config, server) = serve(1, respond);
+        let mut ws_api =
+            WsApi::connect_with_config(Some("api_key".into()), Some("secret".into()), &config)
+                .unwrap();
+
+        let transaction = ws_api
+            .place_order(
+                "LTCBTC",
+                1,
+                0.1,
+                None,
+                OrderSide::Buy,
+                OrderType::Limit,
+                TimeInForce::GTC,
+                None,
+            )
+            .unwrap();
+        assert_eq!(transaction.symbol, "LTCBTC");
+        assert_eq!(transaction.price, 0.1.into_number());
+
+        let order = ws_api.order_status("LTCBTC", 1).unwrap();
+        assert_eq!(order.order_id, 1);
+        let canceled = ws_api.cancel_order("LTCBTC", 1).unwrap();
+        assert_eq!(canceled.order_id, Some(4));
+        let account = ws_api.account_status().unwrap();
+        assert_eq!(account.balances.len(), 2);
+
+        ws_api.disconnect().unwrap();
+        let requests = server.join().unwrap();
+        assert_eq!(requests.len(), 4);
+
+        let place = &requests[0];
+        assert_eq!(place["id"], "1");
+        assert_eq!(place["method"], "order.place");
+        let params = &place["params"];
+        assert_eq!(params["symbol"], "LTCBTC");
+        assert_eq!(params["side"], "BUY");
+        assert_eq!(params["type"], "LIMIT");
+        assert_eq!(params["quantity"], "1");
+        assert_eq!(params["price"], "0.1");
+        assert_eq!(params["timeInForce"], "GTC");
+        assert_eq!(params["apiKey"], "api_key");
+        assert_eq!(params["recvWindow"], "1234");
+        assert!(params["timestamp"].as_str().unwrap().parse::<u64>().is_ok());
+
+        // HMAC over the sorted parameters except the signature itself
+        let mut signed: BTreeMap<String, String> = serde_json::from_value(params.clone()).unwrap();
+        let signature = signed.remove("signature").unwrap();
+        let payload = binance::util::build_request(signed);
+        assert_eq!(signature, Signer::Hmac.sign("secret", &payload));
+    }
+
+    #[test]
+    fn time_sync() {
+        let (config, server) = serve(1, respond);
+        let time_sync = TimeSync::new(Duration::from_secs(600));
+        let config = config.set_time_sync(time_sync.clone());
+        let mut ws_api =
+            WsApi::connect_with_config(Some("api_key".into()), Some("secret".into()), &config)
+                .unwrap();
+
+        ws_api.order_status("LTCBTC", 1).unwrap();
+        ws_api.order_status("LTCBTC", 1).unwrap();
+        assert!(time_sync.offset().unwrap() < 0);
+
+        ws_api.disconnect().unwrap();
+        let requests = server.join().unwrap();
+        // Synced once, before stamping the first signed request
+        assert_eq!(requests.len(), 3);
+        assert_eq!(requests[0]["method"], "time");
+        let timestamp: i64 = requests[1]["params"]["timestamp"]
+            .as_str()
+            .unwrap()
+            .parse()
+            .unwrap();
+        assert!((timestamp - 1656400526260).abs() < 60000);
+    }
+
+    #[test]
+    fn market_data_is_not_signed() {
+        let (config, server) = serve(1, respond);
+        let mut ws_api = WsApi::connect_with_config(None, None, &config).unwrap();
+
+        let price = ws_api.ticker_price("BNBBTC").unwrap();
+        assert_eq!(price.symbol, "BNBBTC");
+        assert_eq!(ws_api.server_time().unwrap().server_time, 1656400526260);
+
+        ws_api.disconnect().unwrap();
+        let requests = server.join().unwrap();
+        assert_eq!(requests[0]["params"], json!({"symbol": "BNBBTC"}));
+        assert!(requests[1].get("params").is_none());
+    }
+
+    #[test]
+    fn responses_matched_by_id() {
+        let (config, server) = serve(2, respond);
+        let mut ws_api = WsApi::connect_with_config(None, None, &config).unwrap();
+
+        let mut parameters = BTreeMap::new();
+        parameters.insert("symbol".to_string(), "LTCBTC".to_string());
+        let ltc = ws_api.send("ticker.price", parameters, false).unwrap();
+        let mut parameters = BTreeMap::new();
+        parameters.insert("symbol".to_string(), "BNBBTC".to_string());
+        let bnb = ws_api.send("ticker.price", parameters, false).unwrap();
+
+        // Answered in reverse order
+        let price: binance::model::SymbolPrice = ws_api.response(&ltc).unwrap();
+        assert_eq!(price.symbol, "LTCBTC");
+        let price: binance::model::SymbolPrice = ws_api.response(&bnb).unwrap();
+        assert_eq!(price.symbol, "BNBBTC");
+
+        ws_api.disconnect().unwrap();
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn errors() {
+        let (config, server) = serve(1, respond);
+        let mut ws_api =
+            WsApi::connect_with_config(Some("api_key".into()), Some("secret".into()), &config)
+                .unwrap();
+
+        match ws_api.cancel_order("LTCBTC", 2).unwrap_err().0 {
+            BinanceLibErrorKind::BinanceError(response) => assert_eq!(response.code, -2011),
+            other => panic!("Unexpected error {:?}", other),
+        }
+        let error = ws_api.ping().unwrap_err();
+        assert!(error.is_retryable());
+        assert!(matches!(
+            error.0,
+            BinanceLibErrorKind::RateLimited(429, None, _)
+        ));
+
+        ws_api.disconnect().unwrap();
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn response_timeout() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let config = Config::default()
+            .set_ws_api_endpoint(format!("ws://{}", listener.local_addr().unwrap()))
+            .set_ws_api_timeout(Duration::from_millis(200));
+        // Reads the requests without ever answering
+        let server = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut socket = accept(stream).unwrap();
+            while socket.read().is_ok() {}
+        });
+        let mut ws_api = WsApi::connect_with_config(None, None, &config).unwrap();
+
+        let started = std::time::Instant::now();
+        assert!(ws_api.ping().is_err());
+        assert!(started.elapsed() >= Duration::from_millis(200));
+        assert!(started.elapsed() < Duration::from_secs(5));
+
+        ws_api.disconnect().unwrap();
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn logon() {
+        let (config, server) = serve(1, respond);
+        let pem = std::fs::read_to_string("tests/mocks/keys/ed25519.pem").unwrap();
+        let config = config.set_signer(Signer::ed25519_from_pem(&pem).unwrap());
+        let mut ws_api = WsApi::connect_with_config(Some("api_key".into()), None, &config).unwrap();
+
+        let status = ws_api.logon().unwrap();
+        assert_eq!(status.api_key, Some("api_key".into()));
+        ws_api.order_status("LTCBTC", 1).unwrap();
+
+        ws_api.disconnect().unwrap();
+        let requests = server.join().unwrap();
+        let logon = &requests[0]["params"];
+        assert_eq!(logon["apiKey"], "api_key");
+        assert!(logon["signature"].as_str().unwrap().ends_with("=="));
+
+        // Authenticated by the session
+        let status = &requests[1]["params"];
+        assert!(status.get("apiKey").is_none());
+        assert!(status.get("signature").is_none());
+        assert!(status.get("timestamp").is_some());
+    }
+
+    #[test]
+    fn logon_requires_ed25519() {
+        let (config, server) = serve(1, respond);
+        let mut ws_api =
+            WsApi::connect_with_config(Some("api_key".into()), Some("secret".into()), &config)
+                .unwrap();
+
+        assert!(ws_api.logon().is_err());
+        ws_api.disconnect().unwrap();
+        assert!(server.join().unwrap().is_empty());
+    }
+}